@@ -3,7 +3,11 @@
 //! This module defines the error types returned by extraction operations.
 
 /// Error type for extraction operations.
+///
+/// Marked `#[non_exhaustive]`: new failure modes may be added, so matches
+/// need a wildcard arm.
 #[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
 pub enum Error {
     /// HTML parsing failed.
     #[error("HTML parsing failed: {0}")]
@@ -20,6 +24,30 @@ pub enum Error {
     /// General extraction failure.
     #[error("Extraction failed: {0}")]
     ExtractionError(String),
+
+    /// Extracted content fell short of `Options::min_output_size` or
+    /// `Options::min_extracted_len` with `Options::strict` set.
+    #[error("Insufficient content: {words} words, {chars} chars")]
+    InsufficientContent {
+        /// Words in the extracted content.
+        words: usize,
+        /// Length of the extracted content in characters, as compared with
+        /// `Options::min_extracted_len`.
+        chars: usize,
+    },
+
+    /// The fetcher passed to `extract_url` failed.
+    #[error("Fetching {url} failed: {message}")]
+    FetchError {
+        /// URL that was being fetched.
+        url: String,
+        /// The fetcher's error, formatted with `Display`.
+        message: String,
+    },
+
+    /// Extraction exceeded `Options::max_duration`.
+    #[error("Extraction timed out")]
+    Timeout,
 }
 
 /// Result type alias for extraction operations.
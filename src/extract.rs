@@ -8,19 +8,24 @@ use crate::dom::{self, Document, Selection};
 use crate::error::{Error, Result};
 use crate::etree;
 use crate::extractor::fallback;
+use crate::extractor::handlers::is_code_block_element;
 use crate::html_processing;
 use crate::link_density::{link_density_test, link_density_test_tables};
+use crate::math;
 use crate::metadata;
-use crate::options::Options;
+use crate::options::{LineEnding, Options};
 use crate::page_type;
 use crate::patterns::{
     ADVERTISEMENT_CLASS, ARTICLE_SELECTOR, BOILERPLATE_CLASS,
     BOILERPLATE_CLASS_NO_COMMENTS, COMMENT_CLASS,
-    COMMENT_ID, LINE_WHITESPACE, MAIN_SELECTOR, MULTIPLE_NEWLINES,
+    COMMENT_ID, LINE_WHITESPACE, MAIN_SELECTOR, MEDIA_SENTINEL, MEDIA_SENTINEL_SPAN, MULTIPLE_NEWLINES,
     NAVIGATION_CLASS, WHITESPACE_NORMALIZE,
 };
 
+use chrono::{Datelike, Utc};
 use std::cell::Cell;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
 
 // Thread-local flag: when true, "comment" class names are NOT treated as boilerplate.
 // Set during forum extraction where comments ARE the content.
@@ -28,20 +33,48 @@ thread_local! {
     static COMMENTS_ARE_CONTENT: Cell<bool> = const { Cell::new(false) };
 }
 use crate::selector;
-use crate::result::{ExtractResult, ImageData};
-use crate::url_utils::{extract_filename, filenames_match};
+use crate::result::{ExtractResult, ExtractionStatus, ImageData, ImageSource, Metadata};
+use crate::scorer::{ContentScorer, DefaultScorer, ScoringContext};
+use crate::url_utils::{self, extract_filename, filenames_match, parse_url};
 
 /// Main entry point for content extraction.
-#[allow(clippy::unnecessary_wraps)]
 pub(crate) fn extract_content(html: &str, options: &Options) -> Result<ExtractResult> {
-    if cfg!(debug_assertions) {
-        eprintln!("DEBUG: Starting content extraction (HTML length: {} chars)", html.len());
-    }
+    extract_content_inner(html, options, false).map(|(result, _)| result)
+}
+
+/// Extraction that also returns the document as it was after boilerplate
+/// cleaning and tag conversion, before main content selection.
+pub(crate) fn extract_content_with_cleaned_doc(html: &str, options: &Options) -> Result<(ExtractResult, Document)> {
+    extract_content_inner(html, options, true).map(|(result, cleaned)| (result, cleaned.unwrap_or_default()))
+}
+
+#[allow(clippy::unnecessary_wraps)]
+fn extract_content_inner(html: &str, options: &Options, keep_cleaned_doc: bool) -> Result<(ExtractResult, Option<Document>)> {
+    // Cooperative time budget, checked between pipeline stages
+    let deadline = options.max_duration.and_then(|limit| Instant::now().checked_add(limit));
+    let mut timer = StageTimer::new(options.collect_timings);
 
     // Parse HTML document
-    let document = Document::from(html);
+    let mut document = Document::from(html);
 
     let mut warnings = Vec::new();
+    if options.report_parse_errors {
+        warnings.extend(parse_error_warnings(&document, html));
+    }
+
+    // Bare fragments get a <body> from the parser; frameset pages don't, so
+    // build one from the document root rather than finding no content
+    if document.select("body").length() == 0 {
+        if let Some(synthesized) = html_processing::synthesize_body(&document) {
+            warnings.push("Document has no <body>; extracted from the document root".to_string());
+            document = synthesized;
+        }
+    }
+    // Shadow-root content is otherwise an inert template the parser keeps out of the tree
+    if options.parse_shadow_dom {
+        html_processing::expand_shadow_roots(&document);
+    }
+    timer.lap("parse");
 
     // Extract metadata first (works on full document before cleaning)
     // Uses the metadata module which provides:
@@ -50,6 +83,7 @@ pub(crate) fn extract_content(html: &str, options: &Options) -> Result<ExtractRe
     // - DOM fallback extraction
     // - Author blacklist filtering
     let mut metadata = metadata::extract_metadata(&document, options);
+    timer.lap("metadata");
 
     // --- Page type classification (before doc_cleaning removes signals) ---
     let (detected_page_type, classification_confidence) = if let Some(pt) = options.page_type {
@@ -57,65 +91,90 @@ pub(crate) fn extract_content(html: &str, options: &Options) -> Result<ExtractRe
         (pt, None)
     } else {
         let url = options.url.as_deref().unwrap_or("");
-
-        // 3-stage classification: URL heuristics → HTML signals → ML classifier
-        // Stage 1: URL heuristics (fast, high-precision for forum/docs)
-        let url_type = page_type::classify_url(url);
-
-        // Stage 2: HTML signal refinement (for ambiguous URLs)
-        let html_signals = page_type::extract_html_signals(&document, &metadata);
-        let refined = page_type::refine_with_html_signals(url_type, &html_signals);
-
-        // Stage 3: ML classifier (final authority for remaining ambiguous pages)
-        // The ML sees URL and HTML features too (f[0]-f[13], f[34]-f[39]),
-        // so it can confirm or override the heuristic stages.
-        let ml_features = page_type::ml::extract_ml_features(&document, &metadata, url);
-        let title_meta = format!(
-            "{} {}",
-            metadata.title.as_deref().unwrap_or(""),
-            metadata.description.as_deref().unwrap_or("")
-        );
-        let (ml_type, ml_conf) = page_type::ml::classify_ml(&ml_features, &title_meta);
-
-        // Use heuristic result when it's high-confidence (non-Article from URL)
-        // and the ML doesn't strongly disagree. Otherwise use ML.
-        if url_type != page_type::PageType::Article && ml_type == url_type {
-            // URL heuristics and ML agree — high confidence
-            (url_type, Some(1.0))
-        } else if refined != page_type::PageType::Article && ml_type == refined {
-            // HTML signals and ML agree
-            (refined, Some(0.95))
-        } else {
-            // Stages disagree — trust the ML (it sees all features)
-            (ml_type, Some(ml_conf))
-        }
+        page_type::classify_document(&document, &metadata, url)
     };
 
     // Store detected page type in metadata
     metadata.page_type = Some(detected_page_type.as_str().to_string());
-
-    if cfg!(debug_assertions) {
-        eprintln!("DEBUG: Page type: {} (confidence: {:?})", detected_page_type, classification_confidence);
-    }
-
-    if cfg!(debug_assertions) {
-        if let Some(ref title) = metadata.title {
-            eprintln!("DEBUG: Extracted metadata - Title: {} chars", title.len());
-        } else {
-            eprintln!("DEBUG: No title found in metadata");
-        }
-    }
+    timer.lap("classify");
 
     // Create document backup BEFORE cleaning for fallback extraction
     // Go-trafilatura pattern: docBackup is used by baseline() and recoverWildText()
     // when main extraction fails. Without this, content inside <form> tags
     // (common in legacy pages) would be lost after doc_cleaning removes them.
+    // Legacy `<br><br>` paragraph breaks become real paragraphs (before the
+    // backup, so fallback extraction sees them too)
+    html_processing::split_br_paragraphs(&document);
+    html_processing::hoist_table_captions(&document);
+    // Block-editor markup, while its wp-block-* classes are intact
+    if options.wordpress_profile && html_processing::is_wordpress(&document) {
+        html_processing::normalize_gutenberg_blocks(&document);
+    }
+    html_processing::collapse_wrapper_divs(&document);
+    // Icon-font and emoji link rows carry no words for the filters to catch
+    if options.remove_icon_navigation {
+        html_processing::remove_icon_navigation(&document);
+    }
+    // Table-of-contents targets keep their ids for `#fragment` links
+    if options.include_links {
+        html_processing::mark_link_targets(&document);
+    }
+    // Side-by-side translations would otherwise merge into one mixed-language text
+    if options.deduplicate_translations {
+        let primary_lang = options
+            .target_language
+            .as_deref()
+            .map(normalize_language)
+            .or_else(|| extract_document_language(&document));
+        remove_parallel_translations(&document, primary_lang.as_deref());
+    }
+    // Lazy-load placeholders give way to the real <img> in <noscript>, which
+    // doc cleaning would otherwise remove before image extraction
+    if options.include_images {
+        html_processing::promote_noscript_images(&document);
+    }
+    // Icon labels, before doc cleaning drops the <svg> elements
+    if options.svg_titles_as_text {
+        html_processing::svg_titles_to_text(&document);
+    }
+    // Infographic narratives, before doc cleaning drops the images
+    if options.include_long_alt {
+        html_processing::long_alt_to_text(&document);
+    }
+    // KaTeX/MathJax/raw LaTeX become plain <math>, which cleaning then keeps
+    if options.preserve_math {
+        crate::math::normalize_math(&document);
+    }
+    // "Tags: ..." closing lines; the tags themselves are already in the metadata
+    if options.strip_taxonomy_lines {
+        html_processing::remove_taxonomy_lines(&document);
+    }
+    // Repeated boilerplate paragraphs (per-block CTAs, syndication notes)
+    if options.deduplicate {
+        html_processing::remove_duplicate_blocks(&document, options);
+    }
+    // User exclusions, before the backup so every extraction path honours them
+    if !options.exclude_selectors.is_empty() {
+        remove_excluded_subtrees(&document.select("body"), &options.exclude_selectors);
+    }
     let doc_backup = dom::clone_document(&document);
 
+    // Live-blog entries, while their timestamps are still in the tree
+    let live_updates = crate::liveblog::extract_live_updates(&document);
+
+    // <meter>/<progress> are cleaned away and <data> stripped to its text
+    let data_values = if options.extract_data_values {
+        crate::tables::extract_data_values(&document)
+    } else {
+        Vec::new()
+    };
+
     // Fix 9: Try JSON-LD articleBody extraction FIRST (before cleaning removes scripts)
     // Many modern sites include full article content in JSON-LD structured data.
     // This is more reliable than DOM-based extraction for sites that use it.
     const MIN_STRUCTURED_BODY_LEN: usize = 500; // Require substantial content
+    // DOM text shorter than this is worth checking SPA state for
+    const SPARSE_DOM_TEXT_LEN: usize = 1000;
     let json_ld_body = fallback::extract_json_ld_article_body(&document);
     let use_json_ld = json_ld_body.as_ref().is_some_and(|body| body.chars().count() >= MIN_STRUCTURED_BODY_LEN);
 
@@ -131,6 +190,11 @@ pub(crate) fn extract_content(html: &str, options: &Options) -> Result<ExtractRe
     let discourse_body = fallback::extract_discourse_content(&document);
     let use_discourse = discourse_body.as_ref().is_some_and(|body| body.chars().count() >= MIN_STRUCTURED_BODY_LEN);
 
+    // Next.js pages embed pre-rendered page props in <script id="__NEXT_DATA__">.
+    // Client-rendered pages can have a near-empty DOM while this holds the body.
+    let next_data_body = fallback::extract_next_data_content(&document);
+    let use_next_data = next_data_body.as_ref().is_some_and(|body| body.chars().count() >= MIN_STRUCTURED_BODY_LEN);
+
     // Get extraction profile for detected page type
     let profile = detected_page_type.extraction_profile();
 
@@ -149,9 +213,79 @@ pub(crate) fn extract_content(html: &str, options: &Options) -> Result<ExtractRe
         COMMENTS_ARE_CONTENT.with(|c| c.set(true));
     }
 
+    // Caption proximity needs the <img> tags that doc cleaning strips
+    if options.separate_captions {
+        html_processing::mark_image_captions(&document);
+    }
+
     // Clean document before content extraction (go-trafilatura: docCleaning)
     // Uses page-type-specific boilerplate selectors and preserve_tags.
     html_processing::doc_cleaning_with_profile(&document, options, &profile);
+    // Snapshot for callers doing their own analysis; extraction below keeps
+    // mutating `document`
+    let cleaned_doc = keep_cleaned_doc.then(|| {
+        let cleaned = dom::clone_document(&document);
+        html_processing::convert_tags(&cleaned.select("body"), options);
+        html_processing::strip_internal_attributes(&cleaned);
+        cleaned
+    });
+    timer.lap("clean");
+    check_deadline(deadline)?;
+
+    // Extract images if requested (before caption separation so figcaptions
+    // still populate ImageData.caption)
+    let images = if options.include_images {
+        extract_images(&document, metadata.image.as_deref(), options)
+    } else {
+        Vec::new()
+    };
+
+    // Leave a marker after each image so content_text keeps its position.
+    // The backup gets them too, for when fallback extraction wins.
+    if options.inline_media_markers && !images.is_empty() {
+        insert_media_markers(&document, &images);
+        insert_media_markers(&doc_backup, &images);
+    }
+
+    // Hero alt text and caption for the metadata image. Without
+    // include_images, cleaning has dropped the <img> tags, so the matching
+    // one is looked up in the backup (before caption separation edits it)
+    if let Some(og_url) = metadata.image.clone() {
+        let hero = if options.include_images {
+            images.iter().find(|img| image_matches_og(img, &og_url)).cloned()
+        } else {
+            find_og_image(&doc_backup, &og_url)
+        };
+        if let Some(hero) = hero {
+            metadata.image_alt = hero.alt;
+            metadata.image_caption = hero.caption;
+        }
+    }
+
+    // The emitter drops <hr>, so section breaks travel through extraction as
+    // marker paragraphs
+    if options.split_on_hr {
+        insert_section_markers(&document);
+    }
+    timer.lap("images");
+
+    // Route caption/credit text out of the body. The backup is cleaned too so
+    // fallback extraction doesn't bring the captions back.
+    let captions = if options.separate_captions {
+        html_processing::mark_image_captions(&doc_backup);
+        html_processing::separate_captions(&doc_backup);
+        html_processing::separate_captions(&document)
+    } else {
+        Vec::new()
+    };
+
+    // Pair footnote references with their definitions (backup too, as above)
+    let footnotes = if options.extract_footnotes {
+        html_processing::separate_footnotes(&doc_backup, options.inline_footnote_markers);
+        html_processing::separate_footnotes(&document, options.inline_footnote_markers)
+    } else {
+        Vec::new()
+    };
 
     // Find and extract main content (graceful degradation on failure)
     // If we have substantial JSON-LD content, still run DOM extraction but compare results
@@ -167,6 +301,11 @@ pub(crate) fn extract_content(html: &str, options: &Options) -> Result<ExtractRe
             (String::new(), None)
         }
     };
+    timer.lap("main_extraction");
+    check_deadline(deadline)?;
+
+    // Set whenever a fallback or alternative source replaces the DOM extraction
+    let mut fallback_used = false;
 
     // Try fallback extraction when main extraction may be insufficient
     // Only trigger when content is potentially under-extracted, following original RS logic.
@@ -193,15 +332,11 @@ pub(crate) fn extract_content(html: &str, options: &Options) -> Result<ExtractRe
 
     // Detect navigation-like content: starts with common nav links or has repeated text
     // Navigation often starts with "Home About Contact..." pattern
-    let looks_like_navigation = {
-        let lower = content_text.to_lowercase();
-        // Get first ~100 chars safely (on char boundary)
-        let first_100: String = lower.chars().take(100).collect();
-        // Count navigation keywords in first 100 chars
-        let nav_keywords = ["home", "about", "contact", "links", "menu", "search", "login"];
-        let nav_count = nav_keywords.iter().filter(|k| first_100.contains(*k)).count();
-        nav_count >= 3 // 3+ nav keywords at start suggests wrong content
-    };
+    let document_lang = extract_document_language(&doc_backup);
+    let looks_like_navigation = looks_like_navigation(&content_text, document_lang.as_deref(), options);
+    if looks_like_navigation {
+        warnings.push("Main extraction starts like a navigation block".to_string());
+    }
 
     if options.use_fallback_extraction && (content_len < min_extracted_len || under_extracted || insufficient_words || looks_like_navigation) {
         // Use doc_backup (pre-cleaning) for fallback - critical for pages where
@@ -222,8 +357,10 @@ pub(crate) fn extract_content(html: &str, options: &Options) -> Result<ExtractRe
             ));
             content_text = fallback_text;
             content_html = Some(html.clone());
+            fallback_used = true;
         }
     }
+    check_deadline(deadline)?;
 
     // Step 7: Multi-candidate merge for service pages.
     // When single-node extraction captures only one section of a multi-section page,
@@ -241,6 +378,7 @@ pub(crate) fn extract_content(html: &str, options: &Options) -> Result<ExtractRe
                     ));
                     content_text = merged;
                     content_html = None;
+                    fallback_used = true;
                 }
             }
         }
@@ -260,6 +398,7 @@ pub(crate) fn extract_content(html: &str, options: &Options) -> Result<ExtractRe
                     ));
                     content_text = collected;
                     content_html = None;
+                    fallback_used = true;
                 }
             }
         }
@@ -314,19 +453,34 @@ pub(crate) fn extract_content(html: &str, options: &Options) -> Result<ExtractRe
             ));
             content_text.clone_from(product_desc);
             content_html = None;
+            fallback_used = true;
         }
     }
 
-    // Fix 9 & 10: Prefer structured data (JSON-LD or Discourse) when substantially better
+    // Other SPAs serialize their store (Apollo, Redux) into JSON scripts.
+    // Parsing those is costly, so it's only tried when the DOM came up
+    // short; the backup still has the scripts cleaning removed.
+    let json_state_body = if content_text.chars().count() < SPARSE_DOM_TEXT_LEN {
+        fallback::extract_json_state_content(&doc_backup)
+    } else {
+        None
+    };
+    let use_json_state = json_state_body.as_ref().is_some_and(|body| body.chars().count() >= MIN_STRUCTURED_BODY_LEN);
+
+    // Fix 9 & 10: Prefer structured data (JSON-LD, Discourse, Next.js, or SPA
+    // state) when substantially better
     // Compare structured content with DOM extraction result
-    let structured_body = if use_discourse {
-        discourse_body.as_ref()
+    let (structured_body, structured_source) = if use_discourse {
+        (discourse_body.as_ref(), "Discourse")
     } else if use_json_ld {
-        json_ld_body.as_ref()
+        (json_ld_body.as_ref(), "JSON-LD")
+    } else if use_next_data {
+        (next_data_body.as_ref(), "Next.js")
+    } else if use_json_state {
+        (json_state_body.as_ref(), "JSON state")
     } else {
-        None
+        (None, "")
     };
-    let structured_source = if use_discourse { "Discourse" } else { "JSON-LD" };
 
     if let Some(structured_text) = structured_body {
         let structured_len = structured_text.chars().count();
@@ -351,6 +505,7 @@ pub(crate) fn extract_content(html: &str, options: &Options) -> Result<ExtractRe
             warnings.push(format!(
                 "Using {structured_source} content: {structured_len} chars (DOM was {dom_len} chars)"
             ));
+            fallback_used = true;
 
             if use_discourse {
                 // Discourse content is decoded HTML — keep tags for markdown conversion
@@ -360,7 +515,7 @@ pub(crate) fn extract_content(html: &str, options: &Options) -> Result<ExtractRe
                 content_text = crate::dom::text_content(&temp_root).trim().to_string();
                 content_html = Some(structured_text.clone());
             } else {
-                // JSON-LD articleBody is plain text
+                // JSON-LD articleBody, Next.js, and JSON state bodies are plain text
                 content_text.clone_from(structured_text);
                 let escaped = structured_text
                     .replace('&', "&amp;")
@@ -375,27 +530,37 @@ pub(crate) fn extract_content(html: &str, options: &Options) -> Result<ExtractRe
     // (Disabled - testing showed marginal impact, may cause edge case regressions)
     // content_text = strip_navigation_boundaries(&content_text);
 
+    // Fallback, structured-data, and merge passes since main extraction
+    timer.lap("fallback");
+
     // Extract comments if requested
     let (comments_text, comments_html) = if options.include_comments {
         extract_comments(&document, options)
     } else {
         (None, None)
     };
+    timer.lap("comments");
 
-    // Extract images if requested
-    let images = if options.include_images {
-        extract_images(&document, metadata.image.as_deref())
+    // Markers are only meant for the main text output (where they become
+    // `{{image:N}}` once the text is final)
+    let (mut comments_text, mut comments_html) = if options.inline_media_markers {
+        (
+            comments_text.map(|text| MEDIA_SENTINEL.replace_all(&text, "").into_owned()),
+            comments_html.map(|html| MEDIA_SENTINEL_SPAN.replace_all(&html, "").into_owned()),
+        )
     } else {
-        Vec::new()
+        (comments_text, comments_html)
     };
 
-    if cfg!(debug_assertions) {
-        eprintln!("DEBUG: Extraction summary:");
-        eprintln!("  Content text: {} chars", content_text.len());
-        eprintln!("  Comments: {} chars", comments_text.as_ref().map_or(0, std::string::String::len));
-        eprintln!("  Images: {}", images.len());
-        eprintln!("  Warnings: {}", warnings.len());
-    }
+    let sections = if options.split_on_hr {
+        // Comments get their markers removed too, but aren't split
+        if let Some(text) = comments_text.as_mut() {
+            split_sections(text, &mut comments_html);
+        }
+        split_sections(&mut content_text, &mut content_html)
+    } else {
+        Vec::new()
+    };
 
     // Compute extraction quality confidence
     let extraction_quality = compute_extraction_quality_heuristic(
@@ -405,6 +570,31 @@ pub(crate) fn extract_content(html: &str, options: &Options) -> Result<ExtractRe
         detected_page_type,
     );
 
+    // Summary mode: keep only the opening paragraphs
+    if let Some(limit) = options.max_paragraphs {
+        if let Some(html) = content_html.as_deref() {
+            if let Some(truncated) = truncate_html_paragraphs(html, limit) {
+                let doc = Document::from(truncated.as_str());
+                content_text = extract_filtered_text_inner(&doc.select("body"), options, false, page_title);
+                content_html = Some(truncated);
+            }
+        } else {
+            content_text = truncate_text_paragraphs(&content_text, limit);
+        }
+    }
+
+    // The content HTML keeps its markers until after the summary cut, which
+    // rebuilds the text from it
+    if options.inline_media_markers {
+        content_html = content_html.map(|html| MEDIA_SENTINEL_SPAN.replace_all(&html, "").into_owned());
+    }
+
+    if options.dedup_links && options.include_links {
+        if let Some(deduped) = content_html.as_deref().and_then(dedup_repeated_links) {
+            content_html = Some(deduped);
+        }
+    }
+
     // Build initial result
     let mut result = ExtractResult {
         content_text,
@@ -414,11 +604,30 @@ pub(crate) fn extract_content(html: &str, options: &Options) -> Result<ExtractRe
         comments_text,
         comments_html,
         images,
+        captions,
+        footnotes,
         metadata,
         classification_confidence,
         extraction_quality,
+        // Computed in apply_final_validations, after truncation
+        link_density: 0.0,
+        content_hash: 0,
+        quality_signals: None,
+        tables: Vec::new(),
+        definitions: Vec::new(),
+        data_values,
+        language_spans: Vec::new(),
+        sections,
+        live_updates,
+        // Filled in after apply_final_validations
+        source_spans: Vec::new(),
+        // Insufficient/truncated/failed states are set in apply_final_validations
+        status: if fallback_used { ExtractionStatus::FallbackUsed } else { ExtractionStatus::Ok },
         warnings,
+        // Filled in after apply_final_validations
+        timings: None,
     };
+    timer.lap("postprocess");
 
     // EPIC-02: Generate Markdown output if enabled
     // Uses quick_html2md for HTML→Markdown conversion with GFM support
@@ -434,26 +643,282 @@ pub(crate) fn extract_content(html: &str, options: &Options) -> Result<ExtractRe
                 .preserve_tables(options.include_tables)
                 .escape_special_chars(true);
 
-            // Convert HTML to Markdown (quick_html2md handles tables and escaping natively)
-            let markdown = html_to_markdown_with_options(html, &md_options);
+            // Convert HTML to Markdown (quick_html2md handles tables and escaping natively;
+            // collapsible sections are rewritten first since it has no rule for them)
+            let html = crate::markdown::details_to_blockquotes(html);
+            let html = crate::markdown::table_captions_to_titles(&html);
+            // Formulas are swapped for tokens so Markdown escaping can't mangle the LaTeX
+            let (html, formulas) = if options.preserve_math {
+                math::math_to_placeholders(&html)
+            } else {
+                (html, Vec::new())
+            };
+            // The converter drops link titles; they ride along in the href
+            let (html, link_titles) = if options.preserve_link_titles && options.include_links {
+                crate::markdown::link_titles_to_placeholders(&html)
+            } else {
+                (html, Vec::new())
+            };
+            let markdown = html_to_markdown_with_options(&html, &md_options);
+            let markdown = math::restore_placeholders(&markdown, &formulas);
+            let markdown = crate::markdown::restore_link_titles(&markdown, &link_titles);
+            let markdown = if options.preserve_quote_nesting {
+                crate::markdown::compact_quote_markers(&markdown)
+            } else {
+                markdown
+            };
 
             result.content_markdown = Some(markdown);
         }
     }
 
+    // Last, so every producer of content_html above can stay plain HTML
+    if options.xhtml_output {
+        result.content_html = result.content_html.map(|html| crate::xhtml::to_xhtml(&html));
+    }
+    timer.lap("markdown");
+
     // Apply final validations and return
     // Reset thread-local flag
     COMMENTS_ARE_CONTENT.with(|c| c.set(false));
 
-    let final_result = apply_final_validations(result, &document, options);
+    let mut final_result = apply_final_validations(result, &document, options)?;
+    timer.lap("validate");
 
-    if cfg!(debug_assertions) {
-        if let Ok(ref res) = final_result {
-            eprintln!("DEBUG: Extraction complete! Final content: {} chars", res.content_text.len());
+    if options.track_offsets {
+        final_result.source_spans = crate::offsets::source_spans(html, &final_result.content_text);
+    }
+
+    final_result.timings = timer.finish();
+    Ok((final_result, cleaned_doc))
+}
+
+/// Per-stage wall-clock timings for `Options::collect_timings`.
+///
+/// Each [`lap`](Self::lap) charges the time since the previous lap to the
+/// named stage, so the stages add up to the `"total"` entry.
+struct StageTimer {
+    start: Instant,
+    last: Instant,
+    timings: Option<HashMap<String, Duration>>,
+}
+
+impl StageTimer {
+    fn new(enabled: bool) -> Self {
+        let now = Instant::now();
+        Self {
+            start: now,
+            last: now,
+            timings: enabled.then(HashMap::new),
+        }
+    }
+
+    fn lap(&mut self, stage: &str) {
+        if let Some(timings) = self.timings.as_mut() {
+            let now = Instant::now();
+            *timings.entry(stage.to_string()).or_default() += now - self.last;
+            self.last = now;
         }
     }
 
-    final_result
+    fn finish(self) -> Option<HashMap<String, Duration>> {
+        let mut timings = self.timings?;
+        timings.insert("total".to_string(), self.start.elapsed());
+        Some(timings)
+    }
+}
+
+/// Unwraps every `<a>` whose href already appeared earlier in the content,
+/// leaving its text in place.
+///
+/// Returns `None` when no link is repeated.
+fn dedup_repeated_links(html: &str) -> Option<String> {
+    let doc = Document::from(html);
+    let mut seen = std::collections::HashSet::new();
+    let mut changed = false;
+    for link in doc.select("a[href]").iter() {
+        let href = link.attr("href").unwrap_or_default().trim().to_string();
+        if !seen.insert(href) {
+            let text = dom::inner_html(&link).to_string();
+            dom::replace_with_html(&link, &text);
+            changed = true;
+        }
+    }
+    changed.then(|| doc.select("body").inner_html().to_string())
+}
+
+/// Puts `title` at the top of the content as an `<h1>`, a `# ` heading and
+/// a text line.
+///
+/// Each output is checked separately: the text output already drops an
+/// `<h1>` that duplicates the page title, while the HTML keeps it.
+fn prepend_title_heading(title: &str, result: &mut ExtractResult) {
+    let title = title.trim();
+    if title.is_empty() {
+        return;
+    }
+
+    if let Some(html) = result.content_html.as_mut() {
+        let doc = Document::from(html.as_str());
+        let first = doc.select("body").children().first();
+        let titled = first.nodes().first().and_then(dom_query::NodeRef::node_name).is_some_and(|tag| {
+            matches!(tag.to_ascii_lowercase().as_str(), "h1" | "h2" | "h3" | "h4" | "h5" | "h6")
+                && titles_match(&dom::text_content(&first), title)
+        });
+        if !titled {
+            html.insert_str(0, &format!("<h1>{}</h1>", escape_html(title)));
+        }
+    }
+
+    if let Some(markdown) = result.content_markdown.as_mut() {
+        let first_line = markdown.lines().find(|line| !line.trim().is_empty());
+        let titled = first_line.is_some_and(|line| {
+            line.starts_with('#') && titles_match(line.trim_start_matches('#').trim(), title)
+        });
+        if !titled {
+            markdown.insert_str(0, &format!("# {}\n\n", crate::markdown::escape_markdown(title, false)));
+        }
+    }
+
+    let content_text = &mut result.content_text;
+    let first_line = content_text.lines().find(|line| !line.trim().is_empty());
+    if !first_line.is_some_and(|line| titles_match(line, title)) {
+        *content_text = if content_text.is_empty() { title.to_string() } else { format!("{title}\n\n{content_text}") };
+    }
+}
+
+/// Cuts content HTML after its `limit`-th non-empty `<p>`.
+///
+/// Returns `None` when there are no more than `limit` paragraphs. Headings
+/// and other blocks before the cut are kept.
+fn truncate_html_paragraphs(html: &str, limit: usize) -> Option<String> {
+    let doc = Document::from(html);
+    let body = doc.select("body");
+    let paragraphs: Vec<_> = body
+        .select("p")
+        .nodes()
+        .iter()
+        .copied()
+        .filter(|node| !Selection::from(*node).text().trim().is_empty())
+        .collect();
+    if paragraphs.len() <= limit {
+        return None;
+    }
+
+    // Drop everything after the last kept paragraph (or from the first one
+    // when nothing is kept), walking up to the body
+    let (boundary, remove_boundary) = match limit.checked_sub(1) {
+        Some(last) => (paragraphs[last], false),
+        None => (paragraphs[0], true),
+    };
+    let mut current = Some(boundary);
+    while let Some(node) = current {
+        if node.node_name().is_some_and(|tag| tag.eq_ignore_ascii_case("body")) {
+            break;
+        }
+        while let Some(following) = node.next_sibling() {
+            following.remove_from_parent();
+        }
+        current = node.parent();
+    }
+    if remove_boundary {
+        boundary.remove_from_parent();
+    }
+
+    Some(dom::inner_html(&body).to_string())
+}
+
+/// Keeps the first `limit` non-empty lines of text-only content.
+fn truncate_text_paragraphs(text: &str, limit: usize) -> String {
+    let mut kept = 0;
+    let mut end = 0;
+    for line in text.split_inclusive('\n') {
+        if !line.trim().is_empty() {
+            if kept == limit {
+                break;
+            }
+            kept += 1;
+        }
+        end += line.len();
+    }
+    text[..end].trim_end().to_string()
+}
+
+/// Prefixes of html5ever tree-construction errors that mean the document
+/// structure was repaired (misnested or unclosed tags, stray content), as
+/// opposed to tokenizer noise such as unterminated character references.
+const STRUCTURAL_PARSE_ERRORS: &[&str] = &[
+    "Unexpected token",
+    "Unexpected open",
+    "Unexpected characters in table",
+    "Non-space table text",
+    "Found special tag while closing",
+    "No matching tag to close",
+    "No <p> tag to close",
+    "No heading tag to close",
+    "Closing wrong heading tag",
+    "Formatting element not",
+    "expected to close",
+    "</body> with no <body>",
+    "</html> with no <body>",
+    "Bad open element",
+    "Form element not in scope",
+    "nested ",
+    "Nested ",
+];
+
+/// Builds warnings describing malformed input for `report_parse_errors`.
+fn parse_error_warnings(doc: &Document, html: &str) -> Vec<String> {
+    let errors = doc.errors.borrow();
+    let mut structural: Vec<&str> = errors
+        .iter()
+        .map(AsRef::as_ref)
+        .filter(|error: &&str| STRUCTURAL_PARSE_ERRORS.iter().any(|prefix| error.starts_with(prefix)))
+        .collect();
+
+    // A missing DOCTYPE is reported as an unexpected token too
+    let has_doctype = html
+        .trim_start_matches(|c: char| c.is_whitespace() || c == '\u{feff}')
+        .get(..9)
+        .is_some_and(|start| start.eq_ignore_ascii_case("<!doctype"));
+    if !has_doctype {
+        if let Some(pos) = structural.iter().position(|error| *error == "Unexpected token") {
+            structural.remove(pos);
+        }
+    }
+
+    let mut warnings = Vec::new();
+    if let Some(first) = structural.first() {
+        warnings.push(format!(
+            "Malformed HTML: parser repaired {} structural error(s) (first: {first})",
+            structural.len()
+        ));
+    }
+
+    let noscript_count = doc
+        .select("noscript")
+        .nodes()
+        .iter()
+        .filter(|node| html_processing::is_content_noscript(&Selection::from(**node)))
+        .count();
+    if noscript_count > 0 {
+        warnings.push(format!(
+            "Malformed HTML: page content found inside {noscript_count} <noscript> element(s)"
+        ));
+    }
+
+    warnings
+}
+
+/// Returns `Error::Timeout` once `deadline` has passed.
+///
+/// Resets the forum thread-local so an aborted extraction doesn't leak it.
+fn check_deadline(deadline: Option<Instant>) -> Result<()> {
+    if deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+        COMMENTS_ARE_CONTENT.with(|c| c.set(false));
+        return Err(Error::Timeout);
+    }
+    Ok(())
 }
 
 /// Counts words in text that meet minimum length requirement.
@@ -700,7 +1165,7 @@ fn try_multi_candidate_merge(doc: &Document, options: &Options) -> Option<String
                 current = current.parent();
             }
 
-            let score = score_content_node(&el, &cleaned, text_len_i64, doc, depth);
+            let score = score_candidate(options, &el, &cleaned, text_len_i64, doc, depth);
 
             candidates.push(Candidate {
                 score,
@@ -1073,6 +1538,44 @@ fn try_fallback_extraction(
     (current_text.to_string(), None)
 }
 
+/// Dates before this year are treated as extraction errors (the web page
+/// dates they would describe barely exist).
+const MIN_PLAUSIBLE_YEAR: i32 = 1995;
+
+/// Warnings for metadata dates that can't be right: before
+/// [`MIN_PLAUSIBLE_YEAR`], more than a day in the future, or a modified date
+/// earlier than the published date.
+fn implausible_date_warnings(metadata: &Metadata) -> Vec<String> {
+    let latest_plausible = Utc::now() + chrono::Duration::days(1);
+    let mut warnings = Vec::new();
+
+    for (label, date) in [("published", metadata.date), ("modified", metadata.date_modified)] {
+        let Some(date) = date else {
+            continue;
+        };
+        if date.year() < MIN_PLAUSIBLE_YEAR {
+            warnings.push(format!(
+                "Implausible {label} date: {} is before {MIN_PLAUSIBLE_YEAR}",
+                date.format("%Y-%m-%d")
+            ));
+        } else if date > latest_plausible {
+            warnings.push(format!("Implausible {label} date: {} is in the future", date.format("%Y-%m-%d")));
+        }
+    }
+
+    if let (Some(published), Some(modified)) = (metadata.date, metadata.date_modified) {
+        if modified < published {
+            warnings.push(format!(
+                "Modified date {} precedes published date {}",
+                modified.format("%Y-%m-%d"),
+                published.format("%Y-%m-%d")
+            ));
+        }
+    }
+
+    warnings
+}
+
 /// Applies final validations and transformations to extraction result.
 ///
 /// Checks content length and word count thresholds, applies max length limits,
@@ -1091,28 +1594,53 @@ fn apply_final_validations(
     // is richer. The multi-signal scoring (nav_score >= 5/8) correctly identifies
     // nav sections but the DOM traversal catches too many false positives.
 
-    // Count words in main content
+    if !options.boilerplate_phrases.is_empty() {
+        result.content_text = strip_boilerplate_phrases(&result.content_text, &options.boilerplate_phrases);
+    }
+
+    // Count words and chars in main content
     let word_count = count_words(&result.content_text, options.min_word_length);
+    let char_count = result.content_text.chars().count();
 
     // Check if content meets minimum thresholds
     let insufficient_content = word_count < options.min_output_size
-        || result.content_text.len() < options.min_extracted_len;
+        || char_count < options.min_extracted_len;
+
+    if insufficient_content && options.strict {
+        return Err(Error::InsufficientContent {
+            words: word_count,
+            chars: char_count,
+        });
+    }
 
     if insufficient_content {
+        result.status = if result.content_text.trim().is_empty() {
+            ExtractionStatus::Failed
+        } else {
+            ExtractionStatus::Insufficient
+        };
+
         // Fallback was already attempted in extract_content if enabled
         // This warning indicates content is still insufficient after all attempts
         result.warnings.push(format!(
             "Insufficient content after extraction: {} words (min: {}), {} chars (min: {})",
             word_count,
             options.min_output_size,
-            result.content_text.len(),
+            char_count,
             options.min_extracted_len
         ));
     }
 
-    // Apply maximum length limit
-    if result.content_text.len() > options.max_extracted_len {
-        result.content_text.truncate(options.max_extracted_len);
+    // Flag dates that can't be right (bad markup or a date-extraction bug)
+    let date_warnings = implausible_date_warnings(&result.metadata);
+    result.warnings.extend(date_warnings);
+
+    // Apply maximum length limit (in chars, cut on a char boundary)
+    if let Some((cut, _)) = result.content_text.char_indices().nth(options.max_extracted_len) {
+        result.content_text.truncate(cut);
+        if !matches!(result.status, ExtractionStatus::Insufficient | ExtractionStatus::Failed) {
+            result.status = ExtractionStatus::Truncated;
+        }
         result.warnings.push(format!(
             "Content truncated to max length: {}",
             options.max_extracted_len
@@ -1132,6 +1660,68 @@ fn apply_final_validations(
         }
     }
 
+    if options.auto_description && result.metadata.description.is_none() {
+        result.metadata.description = auto_description(&MEDIA_SENTINEL.replace_all(&result.content_text, ""));
+        result.metadata.description_generated = result.metadata.description.is_some();
+    }
+
+    // Link-heavy results are likely navigation or listing regions
+    if options.compute_link_density {
+        if let Some(ref html) = result.content_html {
+            result.link_density = crate::link_density::link_density(&Document::from(html.as_str()).select("body"));
+        }
+    }
+
+    // Fingerprint the final text for deduplication across snapshots (image
+    // markers depend on the image options, not the text)
+    result.content_hash = crate::content_hash(&MEDIA_SENTINEL.replace_all(&result.content_text, ""));
+    result.metadata.fingerprint = Some(format!("{:016x}", result.content_hash));
+
+    if options.inline_media_markers {
+        for text in std::iter::once(&mut result.content_text).chain(result.sections.iter_mut()) {
+            *text = MEDIA_SENTINEL.replace_all(text, "${1}{{image:${2}}}").into_owned();
+        }
+    }
+
+    if options.compute_quality_signals {
+        result.quality_signals = crate::quality::compute_quality_signals(&result.content_text);
+    }
+
+    if options.tables_structured {
+        if let Some(ref html) = result.content_html {
+            result.tables = crate::tables::extract_structured_tables(html);
+        }
+    }
+
+    if options.extract_definition_pairs {
+        if let Some(ref html) = result.content_html {
+            result.definitions = crate::tables::extract_definition_pairs(html);
+        }
+    }
+
+    if options.detect_paragraph_languages {
+        result.language_spans = crate::language::detect_language_spans(&result.content_text);
+    }
+
+    // After validation, hashing and language detection, which should only
+    // see the extracted content
+    if options.prepend_title {
+        if let Some(title) = result.metadata.title.clone() {
+            prepend_title_heading(&title, &mut result);
+        }
+    }
+
+    // Line endings are converted last so all processing above sees `\n`
+    if options.line_ending == LineEnding::Crlf {
+        result.content_text = apply_line_ending(&result.content_text, options.line_ending);
+        result.comments_text = result
+            .comments_text
+            .map(|text| apply_line_ending(&text, options.line_ending));
+        result.content_markdown = result
+            .content_markdown
+            .map(|text| apply_line_ending(&text, options.line_ending));
+    }
+
     Ok(result)
 }
 
@@ -1258,10 +1848,6 @@ fn try_length_based_fallback(
         return None;
     }
 
-    if cfg!(debug_assertions) {
-        eprintln!("rs-trafilatura: primary extraction too short ({primary_text_len} chars); trying fallback");
-    }
-
     // Try alternative selectors with relaxed filtering
     let fallback_selectors = [
         "article",
@@ -1281,10 +1867,6 @@ fn try_length_based_fallback(
     let mut best_len = primary_text_len;
 
     for selector in &fallback_selectors {
-        if cfg!(debug_assertions) {
-            eprintln!("rs-trafilatura: fallback trying selector '{selector}'");
-        }
-
         // Try to find content with this selector
         let selection = doc.select(selector);
         let fallback_nodes: Vec<_> = selection.nodes().iter().collect();
@@ -1324,10 +1906,6 @@ fn try_length_based_fallback(
             let text_len = text.trim().len();
 
             if text_len > best_len && text_len >= 200 {
-                if cfg!(debug_assertions) {
-                    eprintln!("rs-trafilatura: fallback selector '{selector}' found {text_len} chars (better than {best_len})");
-                }
-
                 best_text = text;
                 best_len = text_len;
                 best_html = extract_filtered_html_allow_boilerplate(&sel, options);
@@ -1336,54 +1914,26 @@ fn try_length_based_fallback(
                 if text_len >= primary_text_len * 2 {
                     break;
                 }
-            } else if cfg!(debug_assertions) {
-                eprintln!("rs-trafilatura: fallback selector '{selector}' only found {text_len} chars (not better)");
             }
         }
     }
 
     if best_len > primary_text_len {
-        if cfg!(debug_assertions) {
-            eprintln!("rs-trafilatura: fallback successful! Improved from {primary_text_len} to {best_len} chars");
-        }
         Some((best_text, if best_html.is_empty() { None } else { Some(best_html) }))
     } else {
-        if cfg!(debug_assertions) {
-            eprintln!("rs-trafilatura: fallback did not improve results (best was {best_len} chars)");
-        }
         None
     }
 }
 
 fn extract_main_content_with_profile(doc: &Document, options: &Options, page_title: Option<&str>, profile_selectors: &[&str]) -> Result<(String, Option<String>)> {
-    if cfg!(debug_assertions) {
-        eprintln!("DEBUG: Starting main content extraction");
-    }
-
     // Try semantic selectors first (including profile-specific ones)
     let mut content_node = find_main_content_node_with_profile(doc, options, profile_selectors);
 
-    if cfg!(debug_assertions) {
-        if let Some(node) = &content_node {
-            if let Some(tag) = dom::tag_name(node) {
-                eprintln!("DEBUG: Found content node with tag: {tag}");
-            }
-        } else {
-            eprintln!("DEBUG: No semantic content node found, will use body extraction");
-        }
-    }
-
     let (mut text, mut html) = if let Some(node) = &content_node {
         let text = extract_filtered_text_with_title(node, options, page_title);
         let html = extract_filtered_html(node, options);
-        if cfg!(debug_assertions) {
-            eprintln!("DEBUG: Extracted from content node: {} chars", text.len());
-        }
         (text, html)
     } else {
-        if cfg!(debug_assertions) {
-            eprintln!("DEBUG: Using body extraction fallback");
-        }
         (
             extract_body_content(doc, options)?,
             extract_body_content_html(doc, options)?,
@@ -1443,9 +1993,6 @@ fn extract_main_content_with_profile(doc: &Document, options: &Options, page_tit
     }
 
     if text.is_empty() {
-        if cfg!(debug_assertions) {
-            eprintln!("rs-trafilatura: selected content node produced empty text; falling back to body extraction");
-        }
         text = extract_body_content(doc, options)?;
         html = extract_body_content_html(doc, options)?;
         extracted_from_content_node = false;
@@ -1455,9 +2002,6 @@ fn extract_main_content_with_profile(doc: &Document, options: &Options, page_tit
     // with less aggressive filtering (allow some boilerplate classes)
     if text.is_empty() {
         if let Some(node) = find_main_content_node_with_options(doc, options) {
-            if cfg!(debug_assertions) {
-                eprintln!("rs-trafilatura: body extraction empty; trying content node with relaxed filtering");
-            }
             text = extract_filtered_text_allow_boilerplate(&node, options);
             if !text.is_empty() {
                 html = extract_filtered_html_allow_boilerplate(&node, options);
@@ -1479,6 +2023,17 @@ fn extract_main_content_with_profile(doc: &Document, options: &Options, page_tit
         }
     }
 
+    if options.merge_articles && extracted_from_content_node {
+        if let Some(node) = &content_node {
+            if let Some((merged_text, merged_html)) =
+                maybe_merge_sibling_articles(node, options, page_title, &text, used_relaxed_filtering)
+            {
+                text = merged_text;
+                html = merged_html;
+            }
+        }
+    }
+
     // Length-based fallback: if extraction is very short, try alternative selectors
     // DISABLED - causing significant regressions by replacing good partial content with bad content
     // let _text_len = text.trim().len();
@@ -1493,19 +2048,12 @@ fn extract_main_content_with_profile(doc: &Document, options: &Options, page_tit
     // }
 
     if text.is_empty() {
-        if cfg!(debug_assertions) {
-            eprintln!("DEBUG: Extraction failed - no content found");
-        }
         return Err(Error::NoContent);
     }
 
     // TODO: Generate content_html when needed
     let content_html = if html.is_empty() { None } else { Some(html) };
 
-    if cfg!(debug_assertions) {
-        eprintln!("DEBUG: Extraction complete! Final text length: {} chars", text.len());
-    }
-
     Ok((text, content_html))
 }
 
@@ -1636,6 +2184,68 @@ fn is_viable_split_body_chunk(chunk: &Selection) -> bool {
     false
 }
 
+/// Minimum filtered text length for a sibling `<article>` to count as a content section.
+const MIN_MERGED_ARTICLE_LEN: usize = 150;
+
+/// Concatenates sibling `<article>` elements (e.g. live-blog updates) in document order.
+///
+/// Only applies when the content node sits inside an `<article>` that has at least
+/// one sibling `<article>` with substantial text. Returns `None` if merging would
+/// not add content.
+fn maybe_merge_sibling_articles(
+    content_node: &Selection,
+    options: &Options,
+    page_title: Option<&str>,
+    baseline_text: &str,
+    use_relaxed_filtering: bool,
+) -> Option<(String, String)> {
+    let article = find_nearest_article_ancestor(content_node)?;
+    let parent = article.parent();
+    let parent_node = parent.nodes().first().copied()?;
+
+    let mut merged_text_parts: Vec<String> = Vec::new();
+    let mut merged_html_parts: Vec<String> = Vec::new();
+
+    for child in parent_node.children() {
+        if !child.is_element() || !child.node_name().is_some_and(|t| t.eq_ignore_ascii_case("article")) {
+            continue;
+        }
+        let sibling = Selection::from(child);
+
+        let part_text = if use_relaxed_filtering {
+            extract_filtered_text_allow_boilerplate(&sibling, options)
+        } else {
+            extract_filtered_text_with_title(&sibling, options, page_title)
+        };
+        if part_text.trim().chars().count() < MIN_MERGED_ARTICLE_LEN {
+            continue;
+        }
+        merged_text_parts.push(part_text);
+
+        let part_html = if use_relaxed_filtering {
+            extract_filtered_html_allow_boilerplate(&sibling, options)
+        } else {
+            extract_filtered_html(&sibling, options)
+        };
+        if !part_html.trim().is_empty() {
+            merged_html_parts.push(part_html);
+        }
+    }
+
+    if merged_text_parts.len() < 2 {
+        return None;
+    }
+
+    let merged_text = merged_text_parts.join("\n\n");
+    if merged_text.trim().chars().count() <= baseline_text.trim().chars().count()
+        || merged_text.chars().count() > options.max_extracted_len
+    {
+        return None;
+    }
+
+    Some((merged_text, merged_html_parts.join("\n")))
+}
+
 fn maybe_merge_split_article_bodies(
     content_node: &Selection,
     options: &Options,
@@ -1726,6 +2336,36 @@ fn maybe_merge_split_article_bodies(
     Some((merged_text, merged_html))
 }
 
+/// Built-in navigation keywords per language, matched against lowercased text.
+const NAVIGATION_KEYWORDS: &[(&str, &[&str])] = &[
+    ("en", &["home", "about", "contact", "links", "menu", "search", "login"]),
+    ("de", &["startseite", "über uns", "kontakt", "impressum", "datenschutz", "suche", "anmelden", "menü"]),
+    ("fr", &["accueil", "à propos", "contact", "recherche", "connexion", "mentions légales", "menu"]),
+    ("es", &["inicio", "acerca de", "contacto", "buscar", "iniciar sesión", "aviso legal", "menú"]),
+    ("it", &["home", "chi siamo", "contatti", "cerca", "accedi", "note legali", "menu"]),
+    ("pt", &["início", "sobre", "contato", "contacto", "pesquisar", "entrar", "menu"]),
+    ("nl", &["home", "over ons", "contact", "zoeken", "inloggen", "menu"]),
+];
+
+/// Whether extracted text starts like a navigation block: 3+ navigation
+/// keywords in its first 100 characters suggest the wrong element was picked.
+///
+/// Uses `options.navigation_keywords` when set, otherwise the built-in set
+/// for `lang` (English when there is none).
+fn looks_like_navigation(text: &str, lang: Option<&str>, options: &Options) -> bool {
+    let first_100: String = text.to_lowercase().chars().take(100).collect();
+    let nav_count = if let Some(ref keywords) = options.navigation_keywords {
+        keywords.iter().filter(|k| !k.is_empty() && first_100.contains(&k.to_lowercase())).count()
+    } else {
+        let keywords = lang
+            .and_then(|lang| NAVIGATION_KEYWORDS.iter().find(|(code, _)| *code == lang))
+            .unwrap_or(&NAVIGATION_KEYWORDS[0])
+            .1;
+        keywords.iter().filter(|k| first_100.contains(*k)).count()
+    };
+    nav_count >= 3
+}
+
 /// Normalizes a language code to its primary component.
 ///
 /// Converts `en-US` to `en`, `zh_TW` to `zh`, etc.
@@ -1817,12 +2457,91 @@ fn matches_target_language(doc: &Document, el: &Selection, target_lang: Option<&
     true
 }
 
+/// Block elements compared when deciding whether two siblings are
+/// translations of each other.
+const TRANSLATION_STRUCTURE_TAGS: &str = "h1, h2, h3, h4, h5, h6, p, li, tr, blockquote, figure";
+
+/// A candidate translation block and its (normalized) language.
+type TranslationBlock<'a> = (dom_query::NodeRef<'a>, Option<String>);
+
+/// Removes parallel translations of the same content, keeping one language.
+///
+/// Bilingual pages often carry the whole article twice in sibling blocks
+/// that differ only in `lang`. Siblings with the same tag and the same
+/// sequence of headings, paragraphs, and list items count as translations
+/// when their languages differ (a sibling without `lang` inherits it). The
+/// version in `primary_lang` is kept, or the first one when none matches.
+fn remove_parallel_translations(doc: &Document, primary_lang: Option<&str>) {
+    let mut parents = Vec::new();
+    for node in doc.select("body [lang]").nodes() {
+        if let Some(parent) = node.parent() {
+            if !parents.iter().any(|p: &dom_query::NodeRef| p.id == parent.id) {
+                parents.push(parent);
+            }
+        }
+    }
+
+    for parent in parents {
+        let inherited = std::iter::once(parent)
+            .chain(parent.ancestors_it(None))
+            .find_map(|n| n.attr("lang"))
+            .map(|lang| normalize_language(&lang))
+            .or_else(|| primary_lang.map(normalize_language));
+
+        // Structure signature (the child's own tag first) and its members
+        let mut groups: Vec<(Vec<String>, Vec<TranslationBlock>)> = Vec::new();
+        for child in parent.element_children() {
+            let Some(tag) = child.node_name() else {
+                continue;
+            };
+            let mut signature = vec![tag.to_string()];
+            signature.extend(
+                Selection::from(child)
+                    .select(TRANSLATION_STRUCTURE_TAGS)
+                    .nodes()
+                    .iter()
+                    .filter_map(dom_query::NodeRef::node_name)
+                    .map(|name| name.to_string()),
+            );
+            if signature.len() == 1 {
+                continue;
+            }
+            let lang = child.attr("lang").map(|l| normalize_language(&l)).or_else(|| inherited.clone());
+            match groups.iter_mut().find(|(s, _)| *s == signature) {
+                Some((_, members)) => members.push((child, lang)),
+                None => groups.push((signature, vec![(child, lang)])),
+            }
+        }
+
+        for (_, members) in groups {
+            if members.iter().all(|(_, lang)| *lang == members[0].1) {
+                continue;
+            }
+            let keep = primary_lang
+                .map(normalize_language)
+                .and_then(|primary| members.iter().position(|(_, lang)| lang.as_deref() == Some(primary.as_str())))
+                .unwrap_or(0);
+            let keep_lang = members[keep].1.clone();
+            for (node, lang) in &members {
+                if *lang != keep_lang {
+                    node.remove_from_parent();
+                }
+            }
+        }
+    }
+}
+
 /// Finds the main content node using semantic selectors.
 #[allow(dead_code)]  // Used for backward compatibility
 fn find_main_content_node(doc: &Document) -> Option<Selection<'_>> {
     find_main_content_node_with_options(doc, &Options::default())
 }
 
+/// Image count behind [`crate::count_content_images`].
+pub(crate) fn count_content_images(doc: &Document) -> usize {
+    find_main_content_node_with_options(doc, &Options::default()).map_or(0, |node| node.select("img").length())
+}
+
 /// Finds the main content node using semantic selectors with options.
 fn find_main_content_node_with_options<'a>(doc: &'a Document, options: &Options) -> Option<Selection<'a>> {
     find_main_content_node_with_profile(doc, options, &[])
@@ -1841,9 +2560,6 @@ fn find_main_content_node_with_profile<'a>(doc: &'a Document, options: &Options,
             // Verify it has meaningful text content (not just boilerplate containers)
             let text_len = sel.text().trim().len();
             if text_len > 100 {
-                if cfg!(debug_assertions) {
-                    eprintln!("DEBUG: Profile selector matched: {sel_str} ({text_len} chars)");
-                }
                 return Some(sel);
             }
         }
@@ -1863,19 +2579,20 @@ fn find_main_content_node_with_profile<'a>(doc: &'a Document, options: &Options,
     // Fall back to simple article selector (for pages without specific content markers)
     let article_sel = doc.select(ARTICLE_SELECTOR);
     if article_sel.length() > 0 {
-        // If language filtering is active, try to find matching article
-        if options.target_language.is_some() {
-            for node in article_sel.nodes() {
-                let el = Selection::from(*node);
-                if matches_target_language(doc, &el, options.target_language.as_ref()) {
-                    return Some(el);
-                }
-            }
-            // No language-matching article found, continue to other strategies
-        } else {
-            // No language filtering, use first article
-            return Some(article_sel);
+        // If language filtering is active, only language-matching articles qualify
+        let articles: Vec<Selection> = article_sel
+            .nodes()
+            .iter()
+            .map(|node| Selection::from(*node))
+            .filter(|el| {
+                options.target_language.is_none()
+                    || matches_target_language(doc, el, options.target_language.as_ref())
+            })
+            .collect();
+        if let Some(article) = pick_primary_article(doc, articles, options) {
+            return Some(article);
         }
+        // No language-matching article found, continue to other strategies
     }
 
     // Try main content area
@@ -1896,6 +2613,38 @@ fn find_main_content_node_with_profile<'a>(doc: &'a Document, options: &Options,
     find_heuristic_content_node_with_options(doc, options)
 }
 
+/// Picks the main article among several sibling `ARTICLE_SELECTOR` matches.
+///
+/// Articles whose accessible label (`aria-label`/`aria-labelledby`) marks them
+/// as comments or teasers are passed over unless nothing else is left; of the
+/// rest, the one with the best content score wins, earliest first on ties.
+/// Articles that aren't siblings (e.g. comments nested in the story) keep
+/// the document-order pick.
+fn pick_primary_article<'a>(doc: &'a Document, articles: Vec<Selection<'a>>, options: &Options) -> Option<Selection<'a>> {
+    let parent_id = |el: &Selection| el.nodes().first().and_then(dom_query::NodeRef::parent).map(|parent| parent.id);
+    let first_parent = articles.first().map(parent_id)?;
+    if articles.len() == 1 || articles.iter().any(|el| parent_id(el) != first_parent) {
+        return articles.into_iter().next();
+    }
+
+    let body = doc.select("body");
+    let (secondary, primary): (Vec<_>, Vec<_>) = articles
+        .into_iter()
+        .partition(|el| selector::content::has_secondary_label(&body, el));
+    let candidates = if primary.is_empty() { secondary } else { primary };
+
+    let mut best: Option<(i64, Selection<'a>)> = None;
+    for el in candidates {
+        let cleaned = clean_text(&dom::text_content(&el));
+        let text_len = i64::try_from(cleaned.len()).unwrap_or(i64::MAX);
+        let score = score_candidate(options, &el, &cleaned, text_len, doc, 0);
+        if best.as_ref().is_none_or(|(best_score, _)| score > *best_score) {
+            best = Some((score, el));
+        }
+    }
+    best.map(|(_, el)| el)
+}
+
 /// Bottom-up paragraph scorer inspired by Mozilla Readability.
 ///
 /// Scores every paragraph-like element and propagates scores upward to
@@ -2085,14 +2834,19 @@ fn find_heuristic_content_node_with_options<'a>(doc: &'a Document, options: &Opt
     let mut best: Option<Selection> = None;
 
     if allow_body_candidate {
-        let score = score_content_node(&body, &body_cleaned, body_text_len, doc, 0);
+        let score = score_candidate(options, &body, &body_cleaned, body_text_len, doc, 0);
         best_score = score;
         best = Some(body.clone());
     }
 
-    // Iterate through all divs, sections, articles, and main elements
-    for tag in ["div", "section", "article", "main"] {
-        let elements = doc.select(tag);
+    // Iterate through all divs, sections, articles, and main elements, plus
+    // any custom container tags from the options (names that don't parse
+    // as a selector are skipped)
+    let extra_tags = options.content_candidate_tags.iter().map(String::as_str);
+    for tag in ["div", "section", "article", "main"].into_iter().chain(extra_tags) {
+        let Some(elements) = doc.try_select(tag) else {
+            continue;
+        };
         for node in elements.nodes() {
             let el = Selection::from(*node);
 
@@ -2135,7 +2889,7 @@ fn find_heuristic_content_node_with_options<'a>(doc: &'a Document, options: &Opt
                 current = current.parent();
             }
 
-            let score = score_content_node(&el, &cleaned, text_len, doc, depth);
+            let score = score_candidate(options, &el, &cleaned, text_len, doc, depth);
             if score > best_score {
                 best_score = score;
                 best = Some(el);
@@ -2158,11 +2912,15 @@ fn find_heuristic_content_node_with_options<'a>(doc: &'a Document, options: &Opt
         // Coverage check: if the best element covers less than 30% of body text,
         // it's likely a sibling among many (like tutorialblock divs in documentation).
         // In that case, reject it so body extraction can be used instead.
+        // Footers and other boilerplate blocks don't compete with the content,
+        // so their text is left out of the body total.
         if let Some(ref best_sel) = best {
             let best_text = dom::text_content(best_sel);
             let best_len = clean_text(&best_text).len();
-            let coverage = if body_text_len > 0 {
-                (best_len as f64) / (body_text_len as f64)
+            let boilerplate_len = i64::try_from(boilerplate_text_len(&body, best_sel)).unwrap_or(i64::MAX);
+            let competing_len = body_text_len.saturating_sub(boilerplate_len);
+            let coverage = if competing_len > 0 {
+                (best_len as f64) / (competing_len as f64)
             } else {
                 1.0
             };
@@ -2177,9 +2935,61 @@ fn find_heuristic_content_node_with_options<'a>(doc: &'a Document, options: &Opt
     }
 }
 
+/// Cleaned text length of the outermost boilerplate blocks in `body`
+/// (`<footer>`/`<aside>`/`<nav>` or boilerplate class/id) that neither
+/// contain nor sit inside `candidate`.
+fn boilerplate_text_len(body: &Selection, candidate: &Selection) -> usize {
+    let Some(candidate_node) = candidate.nodes().first() else {
+        return 0;
+    };
+    let mut counted: Vec<dom_query::NodeRef> = Vec::new();
+    let mut total = 0;
+
+    for node in body.select("footer, aside, nav, [class], [id]").nodes() {
+        let el = Selection::from(*node);
+        let is_boilerplate_block = matches!(node.node_name().as_deref(), Some("footer" | "aside" | "nav"))
+            || el.attr("class").is_some_and(|class| is_boilerplate(&class))
+            || el.attr("id").is_some_and(|id| is_boilerplate(&id));
+        if !is_boilerplate_block || node.id == candidate_node.id {
+            continue;
+        }
+        let ancestors = node.ancestors(None);
+        if ancestors.iter().any(|anc| anc.id == candidate_node.id || counted.iter().any(|c| c.id == anc.id))
+            || candidate_node.ancestors(None).iter().any(|anc| anc.id == node.id)
+        {
+            continue;
+        }
+        total += clean_text(&dom::text_content(&el)).len();
+        counted.push(*node);
+    }
+
+    total
+}
+
+/// Scores a candidate with `options.content_scorer`, or [`DefaultScorer`] when unset.
+fn score_candidate(
+    options: &Options,
+    el: &Selection,
+    cleaned_text: &str,
+    text_len: i64,
+    doc: &Document,
+    depth: i64,
+) -> i64 {
+    let ctx = ScoringContext {
+        document: doc,
+        text: cleaned_text,
+        text_len,
+        depth,
+    };
+    match options.content_scorer.as_deref() {
+        Some(scorer) => scorer.score(el, &ctx),
+        None => DefaultScorer.score(el, &ctx),
+    }
+}
+
 /// Scores a content node based on text density, structure, and quality signals.
 #[allow(clippy::cast_precision_loss)]
-fn score_content_node(
+pub(crate) fn score_content_node(
     el: &Selection,
     cleaned_text: &str,
     text_len: i64,
@@ -2320,6 +3130,31 @@ fn tendril_tag_matches(tag_name: &tendril::StrTendril, targets: &[&str]) -> bool
     targets.iter().any(|t| tag_name.eq_ignore_ascii_case(t))
 }
 
+/// Inline elements whose text joins its neighbours without added whitespace.
+const INLINE_TEXT_TAGS: &[&str] = &[
+    "a", "abbr", "b", "bdi", "bdo", "cite", "code", "data", "del", "dfn", "em", "font",
+    "i", "img", "ins", "kbd", "label", "mark", "math", "q", "rb", "rp", "rt", "ruby", "s", "samp",
+    "small", "span", "strike", "strong", "sub", "sup", "time", "tt", "u", "var", "wbr",
+];
+
+/// Marker wrapped around emphasized text with `Options::text_emphasis_markers`.
+#[inline]
+fn emphasis_marker(tag_name: &tendril::StrTendril) -> Option<char> {
+    if tendril_tag_matches(tag_name, &["strong", "b"]) {
+        Some('*')
+    } else if tendril_tag_matches(tag_name, &["em", "i"]) {
+        Some('_')
+    } else {
+        None
+    }
+}
+
+/// Whether a tag starts a new paragraph (`\n\n`) in text output.
+#[inline]
+fn is_paragraph_break_tag(tag_name: &tendril::StrTendril) -> bool {
+    tendril_tag_matches(tag_name, &["p", "div", "section", "article", "h1", "h2", "h3", "h4", "h5", "h6"])
+}
+
 /// Build a static slice of excluded tag names for fast lookup.
 /// Using a slice is faster than HashSet for small, fixed tag lists.
 #[inline]
@@ -2630,6 +3465,12 @@ fn extract_filtered_text_inner(
                         skip_depths.push(depth);
                         continue;
                     }
+
+                    // Decorative dividers: only symbols, emoji, or punctuation
+                    if is_symbol_only_paragraph(p_text_trimmed, options) {
+                        skip_depths.push(depth);
+                        continue;
+                    }
                 }
 
                 // Filter divs that consist entirely of boilerplate text (bylines, timestamps, etc.)
@@ -2646,23 +3487,74 @@ fn extract_filtered_text_inner(
                     }
                 }
 
-                if tag_name.eq_ignore_ascii_case("p")
-                    || tag_name.eq_ignore_ascii_case("div")
-                    || tag_name.eq_ignore_ascii_case("section")
-                    || tag_name.eq_ignore_ascii_case("article")
-                    || is_heading
-                {
+                // Ruby annotations (furigana) would run into their base text
+                if tag_name.eq_ignore_ascii_case("rt") || tag_name.eq_ignore_ascii_case("rp") {
+                    if options.keep_ruby_annotations && tag_name.eq_ignore_ascii_case("rt") {
+                        let annotation = node.text();
+                        let annotation = annotation.trim();
+                        if !annotation.is_empty() {
+                            out.push('(');
+                            out.push_str(annotation);
+                            out.push(')');
+                        }
+                    }
+                    skip_depths.push(depth);
+                    continue;
+                }
+
+                if options.preserve_math && tag_name.eq_ignore_ascii_case("math") {
+                    let math = Selection::from(node);
+                    if math::is_display_math(&math) {
+                        out.push_str("\n\n");
+                        out.push_str(&math::math_to_text(&math));
+                        out.push_str("\n\n");
+                    } else {
+                        out.push_str(&math::math_to_text(&math));
+                    }
+                    skip_depths.push(depth);
+                    continue;
+                }
+
+                // Collapsible sections open on their own paragraph, summary first
+                if is_paragraph_break_tag(&tag_name) || tendril_tag_matches(&tag_name, &["details", "summary"]) {
                     out.push_str("\n\n");
                 } else if tag_name.eq_ignore_ascii_case("br") || tag_name.eq_ignore_ascii_case("li") {
                     out.push('\n');
+                } else if !tendril_tag_matches(&tag_name, INLINE_TEXT_TAGS) {
+                    // Other blocks (cells, quotes, figures, ...) still separate words
+                    out.push(' ');
+                }
+
+                // Emphasis is written whole, so nested markup inside it is flattened
+                if options.text_emphasis_markers {
+                    if let Some(marker) = emphasis_marker(&tag_name) {
+                        let text = node.text();
+                        let trimmed = text.trim();
+                        if !trimmed.is_empty() {
+                            out.push_str(&text[..text.len() - text.trim_start().len()]);
+                            out.push(marker);
+                            out.push_str(trimmed);
+                            out.push(marker);
+                            out.push_str(&text[text.trim_end().len()..]);
+                            skip_depths.push(depth);
+                            continue;
+                        }
+                    }
                 }
             }
         }
 
         if node.is_text() {
-            let text = node.text();
-            out.push_str(&text);
-            out.push(' ');
+            // Text right after a block element starts a new line; inline
+            // siblings keep the source whitespace (`<b>A</b><b>B</b>` -> "AB")
+            if let Some(prev_tag) = node.prev_sibling().and_then(|prev| prev.node_name()) {
+                if is_paragraph_break_tag(&prev_tag) {
+                    out.push('\n');
+                } else if !tendril_tag_matches(&prev_tag, INLINE_TEXT_TAGS) {
+                    out.push(' ');
+                }
+            }
+            out.push_str(&node.text());
         }
     }
 
@@ -2696,6 +3588,30 @@ fn extract_filtered_html_inner(
     out.trim().to_string()
 }
 
+/// Removes subtrees of `scope` matching any of `selectors`; invalid
+/// selectors are ignored.
+fn remove_excluded_subtrees(scope: &Selection, selectors: &[String]) {
+    for selector in selectors {
+        if let Some(matches) = scope.try_select(selector) {
+            matches.remove();
+        }
+    }
+}
+
+/// Structural label for `annotate_structure`, for block-level tags only.
+fn structural_block_type(tag: &str) -> Option<&'static str> {
+    match tag {
+        "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => Some("heading"),
+        "p" => Some("body"),
+        "blockquote" => Some("quote"),
+        "pre" => Some("code"),
+        "ul" | "ol" | "dl" => Some("list"),
+        "table" => Some("table"),
+        "figure" => Some("figure"),
+        _ => None,
+    }
+}
+
 #[allow(clippy::too_many_lines)]
 fn push_filtered_html_children(
     root: &Selection,
@@ -2754,6 +3670,15 @@ fn push_filtered_html_children(
                 }
             }
 
+            if tag == "p" && is_symbol_only_paragraph(dom::text_content(&el).trim(), options) {
+                continue;
+            }
+
+            if tag == "math" && options.preserve_math {
+                math::push_math_html(&el, out);
+                continue;
+            }
+
             let next_inside_article_or_main = inside_article_or_main || matches!(tag.as_str(), "article" | "main");
 
             if inside_layout_table
@@ -2794,7 +3719,8 @@ fn push_filtered_html_children(
                 continue;
             }
 
-            if matches!(
+            let keep_figure = tag == "figure" && options.annotate_structure;
+            if keep_figure || matches!(
                 tag.as_str(),
                 "p"
                     | "div"
@@ -2808,6 +3734,8 @@ fn push_filtered_html_children(
                     | "h5"
                     | "h6"
                     | "blockquote"
+                    | "details"
+                    | "summary"
                     | "pre"
                     | "code"
                     | "strong"
@@ -2835,19 +3763,45 @@ fn push_filtered_html_children(
                 out.push('<');
                 out.push_str(&tag);
                 if tag == "a" && options.include_links {
-                    if let Some(href) = el.attr("href") {
+                    let href = el
+                        .attr("href")
+                        .filter(|href| url_utils::scheme_allowed(href, &options.allowed_link_schemes));
+                    if let Some(href) = href {
+                        let href = url_utils::apply_trailing_slash(&href, options.link_trailing_slash);
                         out.push_str(" href=\"");
                         out.push_str(&escape_html(&href));
                         out.push('"');
+                        let title = el.attr("title").filter(|_| options.preserve_link_titles);
+                        let title = title.map(|t| t.split_whitespace().collect::<Vec<_>>().join(" "));
+                        if let Some(title) = title.filter(|t| !t.is_empty()) {
+                            out.push_str(" title=\"");
+                            out.push_str(&escape_html(&title));
+                            out.push('"');
+                        }
                     }
                 }
-                if tag == "code" {
+                if tag == "code" || options.preserve_content_attributes {
                     if let Some(class) = el.attr("class") {
                         out.push_str(" class=\"");
                         out.push_str(&escape_html(&class));
                         out.push('"');
                     }
                 }
+                let link_target = options.include_links && el.attr(html_processing::LINK_TARGET_ATTR).is_some();
+                if options.preserve_content_attributes || link_target {
+                    if let Some(id) = el.attr("id") {
+                        out.push_str(" id=\"");
+                        out.push_str(&escape_html(&id));
+                        out.push('"');
+                    }
+                }
+                if options.annotate_structure {
+                    if let Some(block_type) = structural_block_type(&tag) {
+                        out.push_str(" data-block-type=\"");
+                        out.push_str(block_type);
+                        out.push('"');
+                    }
+                }
                 if matches!(tag.as_str(), "td" | "th") {
                     if let Some(colspan) = el.attr("colspan") {
                         out.push_str(" colspan=\"");
@@ -2862,6 +3816,19 @@ fn push_filtered_html_children(
                 }
                 out.push('>');
 
+                // Highlighted code without its own <code> becomes <pre><code>;
+                // text inside is emitted as-is, so whitespace survives
+                let wrap_code = tag == "pre" && el.select("code").length() == 0 && is_code_block_element(&el);
+                if wrap_code {
+                    out.push_str("<code");
+                    if let Some(language) = code_language(&el) {
+                        out.push_str(" class=\"language-");
+                        out.push_str(&escape_html(&language));
+                        out.push('"');
+                    }
+                    out.push('>');
+                }
+
                 push_filtered_html_children(
                     &el,
                     out,
@@ -2871,6 +3838,9 @@ fn push_filtered_html_children(
                     filter_named_boilerplate,
                 );
 
+                if wrap_code {
+                    out.push_str("</code>");
+                }
                 out.push_str("</");
                 out.push_str(&tag);
                 out.push('>');
@@ -2893,6 +3863,36 @@ fn push_filtered_html_children(
     }
 }
 
+/// Language of a code `<pre>`, from `language-x`/`lang-x` classes,
+/// SyntaxHighlighter's `brush: x`, or a `lang`/`data-lang` attribute.
+fn code_language(pre: &Selection) -> Option<String> {
+    let class = pre.attr("class").unwrap_or_default().to_ascii_lowercase();
+    let from_class = class.split_whitespace().find_map(|token| {
+        token
+            .strip_prefix("language-")
+            .or_else(|| token.strip_prefix("lang-"))
+            .map(str::to_string)
+    });
+    let from_brush = class
+        .split_once("brush:")
+        .and_then(|(_, rest)| rest.split(|c: char| c == ';' || c.is_whitespace()).find(|t| !t.is_empty()))
+        .map(str::to_string);
+    let from_attr = ["lang", "data-lang", "data-language"]
+        .iter()
+        .find_map(|attr| pre.attr(attr))
+        .map(|language| language.trim().to_ascii_lowercase());
+    from_class.or(from_brush).or(from_attr).filter(|language| {
+        !language.is_empty()
+            && language.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '#' | '-' | '_'))
+    })
+}
+
+/// Whether a paragraph's text has no letters or digits in any script
+/// (e.g. `✦ ✦ ✦`, `═════`, emoji rows) and should be dropped.
+fn is_symbol_only_paragraph(text: &str, options: &Options) -> bool {
+    !options.keep_symbol_paragraphs && !text.is_empty() && !html_processing::text_chars_test(text)
+}
+
 fn is_layout_table(table: &Selection) -> bool {
     if let Some(role) = table.attr("role") {
         if role.eq_ignore_ascii_case("presentation") {
@@ -2968,6 +3968,9 @@ fn parse_usize_attr(value: Option<&str>, default: usize) -> usize {
 
 const MAX_TABLE_CELLS: usize = 20_000;
 const MAX_TABLE_TEXT_LEN: usize = 200_000;
+/// Upper bound for a single `colspan`/`rowspan`, so adversarial values
+/// can't force huge span buffers (HTML itself caps them at 1000/65534).
+const MAX_TABLE_SPAN: usize = 1000;
 
 fn push_rowspan_cells(
     rowspan: &mut [Option<(usize, String)>],
@@ -2994,6 +3997,11 @@ fn extract_table_text(table: &Selection) -> String {
     let mut rowspan: Vec<Option<(usize, String)>> = Vec::new();
     let mut total_cells: usize = 0;
 
+    // The caption is the table's title line
+    if let Some(caption) = table.children().iter().find(|child| dom::tag_name(child).as_deref() == Some("caption")) {
+        out.push_str(&clean_text(&dom::text_content(&caption)));
+    }
+
     // Select rows directly from the table selection
     let tr_sel = table.select("tr");
     
@@ -3018,8 +4026,8 @@ fn extract_table_text(table: &Selection) -> String {
 
             let colspan_attr = cell.attr("colspan");
             let rowspan_attr = cell.attr("rowspan");
-            let colspan = parse_usize_attr(colspan_attr.as_deref(), 1);
-            let rowspan_n = parse_usize_attr(rowspan_attr.as_deref(), 1);
+            let colspan = parse_usize_attr(colspan_attr.as_deref(), 1).min(MAX_TABLE_SPAN);
+            let rowspan_n = parse_usize_attr(rowspan_attr.as_deref(), 1).min(MAX_TABLE_SPAN);
 
             let need_len = col.saturating_add(colspan);
             if rowspan.len() < need_len {
@@ -3063,7 +4071,7 @@ fn extract_table_text(table: &Selection) -> String {
     out
 }
 
-fn escape_html(input: &str) -> String {
+pub(crate) fn escape_html(input: &str) -> String {
     let mut out = String::with_capacity(input.len());
     for ch in input.chars() {
         match ch {
@@ -3117,6 +4125,86 @@ fn normalize_text_output(input: &str) -> String {
     out.trim().to_string()
 }
 
+/// Drops paragraphs (lines) of `text` matching one of `phrases`.
+///
+/// Comparison ignores case, runs of whitespace, and trailing sentence
+/// punctuation. A phrase ending in `*` matches paragraphs starting with it;
+/// any other phrase must match the whole paragraph.
+fn strip_boilerplate_phrases(text: &str, phrases: &[String]) -> String {
+    fn normalize(s: &str) -> String {
+        let collapsed = s.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase();
+        collapsed.trim_end_matches(['.', '!', '?', ':', '…']).to_string()
+    }
+
+    let patterns: Vec<(String, bool)> = phrases
+        .iter()
+        .map(|phrase| match phrase.trim().strip_suffix('*') {
+            Some(prefix) => (normalize(prefix), true),
+            None => (normalize(phrase), false),
+        })
+        .filter(|(pattern, _)| !pattern.is_empty())
+        .collect();
+
+    let kept: Vec<&str> = text
+        .split('\n')
+        .filter(|line| {
+            let line = normalize(line);
+            !patterns.iter().any(|(pattern, is_prefix)| {
+                if *is_prefix { line.starts_with(pattern.as_str()) } else { line == *pattern }
+            })
+        })
+        .collect();
+    MULTIPLE_NEWLINES.replace_all(&kept.join("\n"), "\n\n").trim().to_string()
+}
+
+/// Target length of a description generated by `auto_description`.
+const AUTO_DESCRIPTION_CHARS: usize = 160;
+
+/// Words a line needs to count as a paragraph rather than a heading.
+const MIN_DESCRIPTION_PARAGRAPH_WORDS: usize = 8;
+
+/// A description built from the opening sentences of the first paragraph of
+/// `text`, up to `AUTO_DESCRIPTION_CHARS`. A first sentence that is already
+/// longer is cut at a word boundary and ends with an ellipsis.
+fn auto_description(text: &str) -> Option<String> {
+    let paragraph = text
+        .lines()
+        .map(str::trim)
+        .find(|line| line.split_whitespace().count() >= MIN_DESCRIPTION_PARAGRAPH_WORDS)?;
+    let paragraph = paragraph.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    // Sentence ends: terminal punctuation followed by a space or the end
+    let mut description = "";
+    for (i, ch) in paragraph.char_indices() {
+        let end = i + ch.len_utf8();
+        let at_boundary = matches!(ch, '.' | '!' | '?') && paragraph[end..].chars().next().is_none_or(char::is_whitespace);
+        if at_boundary {
+            if paragraph[..end].chars().count() > AUTO_DESCRIPTION_CHARS {
+                break;
+            }
+            description = &paragraph[..end];
+        }
+    }
+    if !description.is_empty() {
+        return Some(description.to_string());
+    }
+    if paragraph.chars().count() <= AUTO_DESCRIPTION_CHARS {
+        return Some(paragraph);
+    }
+
+    let cut: String = paragraph.chars().take(AUTO_DESCRIPTION_CHARS - 1).collect();
+    let cut = cut.rsplit_once(' ').map_or(cut.as_str(), |(head, _)| head);
+    Some(format!("{}\u{2026}", cut.trim_end_matches([',', ';', ':'])))
+}
+
+/// Converts `\n`-terminated output to the requested line ending.
+fn apply_line_ending(text: &str, line_ending: LineEnding) -> String {
+    match line_ending {
+        LineEnding::Lf => text.to_string(),
+        LineEnding::Crlf => text.replace("\r\n", "\n").replace('\n', "\r\n"),
+    }
+}
+
 /// Layout/component prefixes used in BEM-style / ITCSS-style CSS naming.
 /// These indicate structural/styling concerns, not content type.
 const LAYOUT_COMPONENT_PREFIXES: &[&str] = &["l-", "c-"];
@@ -3344,39 +4432,171 @@ fn extract_comments(doc: &Document, options: &Options) -> (Option<String>, Optio
     (Some(text), comments_html)
 }
 
+/// Comment-only pipeline behind [`crate::extract_comments_only`].
+///
+/// Parses and cleans the document as [`extract_content`] does, then goes
+/// straight to the comment section: no metadata, page classification, or
+/// main content extraction. Comments below `min_output_comm_size` words are
+/// dropped, as in the full pipeline.
+#[allow(clippy::unnecessary_wraps)]
+pub(crate) fn extract_comments_only(html: &str, options: &Options) -> Result<(Option<String>, Option<String>)> {
+    let mut document = Document::from(html);
+    if document.select("body").length() == 0 {
+        if let Some(synthesized) = html_processing::synthesize_body(&document) {
+            document = synthesized;
+        }
+    }
+    html_processing::split_br_paragraphs(&document);
+
+    // Cleaning must keep the comment section it would otherwise remove
+    let mut options = options.clone();
+    options.include_comments = true;
+    html_processing::doc_cleaning(&document, &options);
+
+    let (text, html) = extract_comments(&document, &options);
+    let Some(text) = text.filter(|text| count_words(text, options.min_word_length) >= options.min_output_comm_size) else {
+        return Ok((None, None));
+    };
+    Ok((Some(apply_line_ending(&text, options.line_ending)), html))
+}
+
 /// Extracts image data from content with hero detection.
 ///
 /// # Arguments
 /// * `doc` - The parsed HTML document
 /// * `og_image` - The og:image URL from metadata (for hero detection)
-fn extract_images(doc: &Document, og_image: Option<&str>) -> Vec<ImageData> {
+/// * `options` - Image URL filters (extensions, hosts, data URIs)
+fn extract_images(doc: &Document, og_image: Option<&str>, options: &Options) -> Vec<ImageData> {
     let mut images = Vec::new();
     let mut seen_urls = std::collections::HashSet::new();
 
     // Try to find images within content regions first
-    if let Some(content_node) = find_main_content_node_with_options(doc, &Options::default()) {
-        extract_images_from_node(&content_node, &mut images, &mut seen_urls);
+    let content_node = find_main_content_node_with_options(doc, &Options::default());
+    if let Some(ref content_node) = content_node {
+        extract_images_from_node(content_node, &mut images, &mut seen_urls, options);
     }
 
     // If no images found in content, try body
     if images.is_empty() {
         let body = doc.select("body");
         if body.length() > 0 {
-            extract_images_from_node(&body, &mut images, &mut seen_urls);
+            extract_images_from_node(&body, &mut images, &mut seen_urls, options);
+        }
+    }
+
+    // The hero often sits in a header or figure just before the content node;
+    // take its <img> (with srcset sources) over the bare og:image URL
+    if let (Some(content_node), Some(og_url)) = (&content_node, og_image) {
+        if !images.iter().any(|img| image_matches_og(img, og_url)) {
+            if let Some(hero) = preceding_hero_image(content_node, og_url, options) {
+                images.insert(0, hero);
+            }
         }
     }
 
     // Story 4: Hero image detection
     mark_hero_image(&mut images, og_image);
 
+    if let Some(max_images) = options.max_images {
+        cap_images(&mut images, max_images);
+    }
+
     images
 }
 
+/// Keeps the first `max_images` images in document order, swapping the last
+/// kept one for the hero when the hero would otherwise be cut.
+fn cap_images(images: &mut Vec<ImageData>, max_images: usize) {
+    if images.len() <= max_images {
+        return;
+    }
+    let hero = images.iter().position(|img| img.is_hero).filter(|&index| index >= max_images);
+    if let (Some(index), Some(last)) = (hero, max_images.checked_sub(1)) {
+        images.swap(last, index);
+    }
+    images.truncate(max_images);
+}
+
+/// How many levels above the content node are searched for a preceding hero.
+const MAX_HERO_ANCESTOR_DEPTH: usize = 3;
+
+/// Finds the `og:image` picture in the header or figure right before the
+/// content node (or before one of its ancestors, when the content node opens
+/// its parent).
+fn preceding_hero_image(content_node: &Selection, og_url: &str, options: &Options) -> Option<ImageData> {
+    let mut node = *content_node.nodes().first()?;
+    for _ in 0..MAX_HERO_ANCESTOR_DEPTH {
+        let mut prev = node.prev_sibling();
+        while let Some(sibling) = prev {
+            if sibling.is_element() {
+                break;
+            }
+            prev = sibling.prev_sibling();
+        }
+
+        match prev {
+            Some(sibling) if sibling.has_name("figure") || sibling.has_name("header") => {
+                let mut candidates = Vec::new();
+                extract_images_from_node(
+                    &Selection::from(sibling),
+                    &mut candidates,
+                    &mut std::collections::HashSet::new(),
+                    options,
+                );
+                return candidates.into_iter().find(|img| image_matches_og(img, og_url));
+            }
+            Some(_) => return None,
+            None => {
+                node = node.parent()?;
+                if node.has_name("body") {
+                    return None;
+                }
+            }
+        }
+    }
+    None
+}
+
+/// The first `<img>` in the document that is the `og:image`, with its alt
+/// text and the caption of its `<figure>`.
+fn find_og_image(doc: &Document, og_url: &str) -> Option<ImageData> {
+    doc.select("img").iter().find_map(|img| {
+        let sources = picture_sources(&img);
+        let src = primary_image_src(&img, &sources)?;
+        let mut image = ImageData {
+            filename: extract_filename(&src),
+            src,
+            alt: None,
+            caption: None,
+            is_hero: true,
+            sources,
+        };
+        if !image_matches_og(&image, og_url) {
+            return None;
+        }
+
+        image.alt = img.attr("alt").map(|s| s.trim().to_string()).filter(|s| !s.is_empty());
+        let figure = img.nodes().first()?.ancestors_it(None).find(|node| node.has_name("figure"));
+        image.caption = figure.and_then(|figure| extract_figcaption(&Selection::from(figure))).filter(|caption| {
+            !image.alt.as_deref().is_some_and(|alt| caption_matches_alt(caption, alt))
+        });
+        Some(image)
+    })
+}
+
+/// Whether an image, or one of its `<picture>` sources, is the `og:image`.
+fn image_matches_og(img: &ImageData, og_url: &str) -> bool {
+    img.src == og_url
+        || filenames_match(&img.src, og_url)
+        || img.sources.iter().any(|source| filenames_match(&source.src, og_url))
+}
+
 /// Extracts image data from a specific node, including figcaptions.
 fn extract_images_from_node(
     node: &Selection,
     images: &mut Vec<ImageData>,
     seen_urls: &mut std::collections::HashSet<String>,
+    options: &Options,
 ) {
     // Create temporary document from node HTML to select within it
     let node_html = dom::outer_html(node);
@@ -3386,7 +4606,7 @@ fn extract_images_from_node(
     let figure_sel = doc.select("figure");
     for figure_node in figure_sel.nodes() {
         let figure = Selection::from(*figure_node);
-        extract_image_from_figure(&figure, images, seen_urls);
+        extract_image_from_figure(&figure, images, seen_urls, options);
     }
 
     // Then process standalone <img> elements (not inside figures)
@@ -3394,20 +4614,13 @@ fn extract_images_from_node(
     for img_node in img_sel.nodes() {
         let img = Selection::from(*img_node);
 
-        // Get src URL (try src first, then data-src for lazy loading)
-        let src = img
-            .attr("src")
-            .map(|s| s.trim().to_string())
-            .filter(|s| !s.is_empty())
-            .or_else(|| {
-                img.attr("data-src")
-                    .map(|s| s.trim().to_string())
-                    .filter(|s| !s.is_empty())
-            });
-
-        let Some(src) = src else {
+        let sources = picture_sources(&img);
+        let Some(src) = primary_image_src(&img, &sources) else {
             continue;
         };
+        if !image_url_allowed(&src, options) {
+            continue;
+        }
 
         // Skip duplicates (already processed in figures)
         if seen_urls.contains(&src) {
@@ -3430,10 +4643,41 @@ fn extract_images_from_node(
             alt,
             caption: None, // No caption for standalone images
             is_hero: false, // Will be set by mark_hero_image
+            sources,
+        });
+    }
+
+    // Finally <picture> elements with no <img> to carry the URL
+    for picture_node in doc.select("picture").nodes() {
+        let picture = Selection::from(*picture_node);
+        let Some((src, sources)) = fallbackless_picture_image(&picture) else {
+            continue;
+        };
+        if !image_url_allowed(&src, options) || !seen_urls.insert(src.clone()) {
+            continue;
+        }
+
+        let filename = extract_filename(&src);
+        images.push(ImageData {
+            src,
+            filename,
+            alt: picture_label(&picture),
+            caption: None,
+            is_hero: false,
+            sources,
         });
     }
 }
 
+/// Accessible name of an `<img>`-less `<picture>` (`aria-label` or `title`).
+fn picture_label(picture: &Selection) -> Option<String> {
+    picture
+        .attr("aria-label")
+        .or_else(|| picture.attr("title"))
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+}
+
 /// Story 3: Extracts image data from a <figure> element, including figcaption.
 ///
 /// HTML pattern handled:
@@ -3447,33 +4691,32 @@ fn extract_image_from_figure(
     figure: &Selection,
     images: &mut Vec<ImageData>,
     seen_urls: &mut std::collections::HashSet<String>,
+    options: &Options,
 ) {
-    // Find img inside the figure
-    let img_sel = figure.select("img");
-    if img_sel.length() == 0 {
-        return;
-    }
+    // Find the first img inside the figure, or an img-less <picture>
+    let (src, sources, alt) = if let Some(img_node) = figure.select("img").nodes().first() {
+        let img = Selection::from(*img_node);
+        let sources = picture_sources(&img);
+        let Some(src) = primary_image_src(&img, &sources) else {
+            return;
+        };
 
-    // Get the first image in the figure
-    let Some(img_node) = img_sel.nodes().first() else {
-        return;
+        // Extract alt text
+        let alt = img
+            .attr("alt")
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty());
+        (src, sources, alt)
+    } else {
+        let picture = figure.select("picture").first();
+        let Some((src, sources)) = fallbackless_picture_image(&picture) else {
+            return;
+        };
+        (src, sources, picture_label(&picture))
     };
-    let img = Selection::from(*img_node);
-
-    // Get src URL
-    let src = img
-        .attr("src")
-        .map(|s| s.trim().to_string())
-        .filter(|s| !s.is_empty())
-        .or_else(|| {
-            img.attr("data-src")
-                .map(|s| s.trim().to_string())
-                .filter(|s| !s.is_empty())
-        });
-
-    let Some(src) = src else {
+    if !image_url_allowed(&src, options) {
         return;
-    };
+    }
 
     // Skip duplicates
     if seen_urls.contains(&src) {
@@ -3481,17 +4724,13 @@ fn extract_image_from_figure(
     }
     seen_urls.insert(src.clone());
 
-    // Extract alt text
-    let alt = img
-        .attr("alt")
-        .map(|s| s.trim().to_string())
-        .filter(|s| !s.is_empty());
-
     // Extract filename from URL
     let filename = extract_filename(&src);
 
-    // Story 3: Extract caption from figcaption
-    let caption = extract_figcaption(figure);
+    // Story 3: Extract caption from figcaption (dropped when it just repeats
+    // the alt text, so consumers don't render it twice)
+    let caption = extract_figcaption(figure)
+        .filter(|caption| !alt.as_deref().is_some_and(|alt| caption_matches_alt(caption, alt)));
 
     images.push(ImageData {
         src,
@@ -3499,9 +4738,206 @@ fn extract_image_from_figure(
         alt,
         caption,
         is_hero: false, // Will be set by mark_hero_image
+        sources,
     });
 }
 
+/// Applies the image URL filters from `options` (data URIs, blocked hosts,
+/// allowed extensions).
+fn image_url_allowed(src: &str, options: &Options) -> bool {
+    if src.get(..5).is_some_and(|scheme| scheme.eq_ignore_ascii_case("data:")) {
+        return !options.drop_data_uri_images;
+    }
+
+    if !options.blocked_image_hosts.is_empty() {
+        if let Some(host) = parse_url(src).and_then(|url| url.host_str().map(str::to_ascii_lowercase)) {
+            let blocked = options.blocked_image_hosts.iter().any(|blocked| {
+                let blocked = blocked.trim().to_ascii_lowercase();
+                host == blocked || host.strip_suffix(blocked.as_str()).is_some_and(|rest| rest.ends_with('.'))
+            });
+            if blocked {
+                return false;
+            }
+        }
+    }
+
+    if let Some(ref allowed) = options.allowed_image_extensions {
+        let filename = extract_filename(src);
+        let Some((_, extension)) = filename.rsplit_once('.') else {
+            return false;
+        };
+        return allowed
+            .iter()
+            .any(|ext| ext.trim().trim_start_matches('.').eq_ignore_ascii_case(extension));
+    }
+
+    true
+}
+
+/// Inserts an image marker ([`MEDIA_SENTINEL`], which becomes `{{image:N}}`
+/// in `content_text`) after each `<img>` whose URL is `images[N].src`.
+fn insert_media_markers(doc: &Document, images: &[ImageData]) {
+    for node in doc.select("img").nodes() {
+        let img = Selection::from(*node);
+        let sources = picture_sources(&img);
+        let Some(src) = primary_image_src(&img, &sources) else {
+            continue;
+        };
+        let Some(index) = images.iter().position(|image| image.src == src) else {
+            continue;
+        };
+        let html = format!("{} \u{E000}image:{index}\u{E001} ", dom::outer_html(&img));
+        dom::replace_with_html(&img, &html);
+    }
+}
+
+/// Text of the paragraphs standing in for `<hr>` with `Options::split_on_hr`.
+const SECTION_BREAK_MARKER: &str = "{{section-break}}";
+
+/// Replaces each `<hr>` with a [`SECTION_BREAK_MARKER`] paragraph.
+fn insert_section_markers(doc: &Document) {
+    for hr in doc.select("hr").iter() {
+        dom::replace_with_html(&hr, &format!("<p>{SECTION_BREAK_MARKER}</p>"));
+    }
+}
+
+/// Splits the content at the section markers, returning the text of each
+/// non-empty section.
+///
+/// The markers are removed from `content_text` and turned back into `<hr>`
+/// in `content_html`.
+fn split_sections(content_text: &mut String, content_html: &mut Option<String>) -> Vec<String> {
+    let sections: Vec<String> = content_text
+        .split(SECTION_BREAK_MARKER)
+        .map(str::trim)
+        .filter(|section| !section.is_empty())
+        .map(str::to_string)
+        .collect();
+    if content_text.contains(SECTION_BREAK_MARKER) {
+        *content_text = sections.join("\n\n");
+    }
+    if let Some(html) = content_html.as_mut() {
+        *html = html.replace(&format!("<p>{SECTION_BREAK_MARKER}</p>"), "<hr>").replace(SECTION_BREAK_MARKER, "");
+    }
+    sections
+}
+
+/// Picks the URL that represents an image: the desktop `<source>` of an
+/// enclosing `<picture>` if there is one, else `src` / `data-src`.
+fn primary_image_src(img: &Selection, sources: &[ImageSource]) -> Option<String> {
+    primary_picture_source(sources)
+        .map(|source| source.src.clone())
+        .or_else(|| {
+            img.attr("src")
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+        })
+        .or_else(|| {
+            img.attr("data-src")
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+        })
+}
+
+/// Collects the `<source>` siblings of an `<img>` inside a `<picture>`.
+fn picture_sources(img: &Selection) -> Vec<ImageSource> {
+    let parent = img.parent();
+    if dom::tag_name(&parent).as_deref() != Some("picture") {
+        return Vec::new();
+    }
+    picture_element_sources(&parent)
+}
+
+/// Image for a `<picture>` that has `<source>`s but no `<img>` fallback
+/// (left for JavaScript to fill in): the desktop source, else the first.
+fn fallbackless_picture_image(picture: &Selection) -> Option<(String, Vec<ImageSource>)> {
+    if picture.select("img").length() > 0 {
+        return None;
+    }
+    let sources = picture_element_sources(picture);
+    let src = primary_picture_source(&sources).or_else(|| sources.first())?.src.clone();
+    Some((src, sources))
+}
+
+/// Parses the `<source>` children of a `<picture>`.
+fn picture_element_sources(picture: &Selection) -> Vec<ImageSource> {
+    let mut sources = Vec::new();
+    for node in picture.select("source").nodes() {
+        let source = Selection::from(*node);
+        let srcset = source.attr("srcset").or_else(|| source.attr("data-srcset"));
+        let Some(src) = srcset.as_deref().and_then(widest_srcset_candidate) else {
+            continue;
+        };
+        let attr = |name: &str| {
+            source
+                .attr(name)
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+        };
+        sources.push(ImageSource {
+            src,
+            media: attr("media"),
+            mime_type: attr("type"),
+        });
+    }
+    sources
+}
+
+/// Chooses the desktop rendition among art-direction sources.
+///
+/// A source without a media query wins; otherwise the one with the widest
+/// `min-width` query. Sources limited only by `max-width` are mobile crops
+/// and never chosen, leaving the `<img>` fallback in charge.
+fn primary_picture_source(sources: &[ImageSource]) -> Option<&ImageSource> {
+    if let Some(unconstrained) = sources.iter().find(|source| source.media.is_none()) {
+        return Some(unconstrained);
+    }
+    sources
+        .iter()
+        .filter_map(|source| source.media.as_deref().and_then(media_min_width).map(|w| (w, source)))
+        .fold(None, |best: Option<(u32, &ImageSource)>, (width, source)| match best {
+            Some((best_width, _)) if best_width >= width => best,
+            _ => Some((width, source)),
+        })
+        .map(|(_, source)| source)
+}
+
+/// Parses the whole-number `min-width` of a media query in CSS pixels
+/// (`em`/`rem` count as 16px).
+fn media_min_width(media: &str) -> Option<u32> {
+    let lower = media.to_ascii_lowercase();
+    let rest = &lower[lower.find("min-width")? + "min-width".len()..];
+    let rest = rest.trim_start().strip_prefix(':')?.trim_start();
+    let digits_len = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+    let value: u32 = rest[..digits_len].parse().ok()?;
+    let unit = rest[digits_len..].trim_start_matches(|c: char| c == '.' || c.is_ascii_digit());
+    if unit.starts_with("em") || unit.starts_with("rem") {
+        Some(value.saturating_mul(16))
+    } else {
+        Some(value)
+    }
+}
+
+/// Returns the URL of the widest candidate in a `srcset` (first one if no `w` descriptors).
+fn widest_srcset_candidate(srcset: &str) -> Option<String> {
+    let mut best: Option<(u32, &str)> = None;
+    for candidate in srcset.split(',') {
+        let mut parts = candidate.split_whitespace();
+        let Some(url) = parts.next() else {
+            continue;
+        };
+        let width = parts
+            .next()
+            .and_then(|d| d.strip_suffix('w'))
+            .and_then(|w| w.parse::<u32>().ok())
+            .unwrap_or(0);
+        if best.is_none_or(|(best_width, _)| width > best_width) {
+            best = Some((width, url));
+        }
+    }
+    best.map(|(_, url)| url.to_string())
+}
+
 /// Story 3: Extracts and cleans caption text from a figcaption element.
 fn extract_figcaption(figure: &Selection) -> Option<String> {
     let figcaption_sel = figure.select("figcaption");
@@ -3520,6 +4956,11 @@ fn extract_figcaption(figure: &Selection) -> Option<String> {
     }
 }
 
+/// Whether a caption repeats the alt text, ignoring whitespace and case.
+fn caption_matches_alt(caption: &str, alt: &str) -> bool {
+    clean_caption_text(caption).to_lowercase() == clean_caption_text(alt).to_lowercase()
+}
+
 /// Cleans and normalizes caption text.
 fn clean_caption_text(text: &str) -> String {
     // Normalize whitespace: collapse multiple spaces/newlines to single space
@@ -3931,6 +5372,22 @@ mod tests {
         assert_eq!(clean_text("\n\n\n\ntest\n\n\n\n"), "test");
     }
 
+    #[test]
+    fn heuristic_coverage_ignores_boilerplate_siblings() {
+        let story: String = (0..6)
+            .map(|i| format!("<p>Paragraph {i} of the essay explains how the village rebuilt its mill after the flood, step by step.</p>"))
+            .collect();
+        let footer: String = (0..20)
+            .map(|i| format!("<p>Footer column {i}: office hours, mailing address, press contacts, and careers information.</p>"))
+            .collect();
+        let doc = Document::from(format!(
+            r#"<html><body><section>{story}</section><div class="site-footer">{footer}</div></body></html>"#
+        ));
+
+        let best = find_heuristic_content_node(&doc).expect("section should be selected");
+        assert_eq!(dom::tag_name(&best).as_deref(), Some("section"));
+    }
+
     #[test]
     fn is_boilerplate_detects_navigation() {
         assert!(is_boilerplate("main-nav"));
@@ -4189,6 +5646,107 @@ mod tests {
             Err(err) => panic!("expected Ok(_), got Err({err:?})"),
         }
     }
+
+    #[test]
+    fn picture_helpers_parse_media_and_srcset() {
+        assert_eq!(media_min_width("(min-width: 1024px)"), Some(1024));
+        assert_eq!(media_min_width("screen and (min-width:48em)"), Some(768));
+        assert_eq!(media_min_width("(max-width: 599px)"), None);
+
+        assert_eq!(
+            widest_srcset_candidate("a.jpg 480w, b.jpg 1600w, c.jpg 960w").as_deref(),
+            Some("b.jpg")
+        );
+        assert_eq!(widest_srcset_candidate("a.jpg, b.jpg 2x").as_deref(), Some("a.jpg"));
+        assert_eq!(widest_srcset_candidate("  "), None);
+    }
+
+    #[test]
+    fn paragraph_truncation_cuts_at_boundaries() {
+        let html = "<div><h2>Intro</h2><p>One</p><p> </p><section><p>Two</p>tail<p>Three</p></section><p>Four</p></div>";
+        let truncated = truncate_html_paragraphs(html, 2).expect("more than two paragraphs");
+        assert_eq!(truncated, "<div><h2>Intro</h2><p>One</p><p> </p><section><p>Two</p></section></div>");
+        assert!(truncate_html_paragraphs(html, 4).is_none());
+
+        assert_eq!(truncate_text_paragraphs("One\n\nTwo\n\nThree", 2), "One\n\nTwo");
+        assert_eq!(truncate_text_paragraphs("One\nTwo", 5), "One\nTwo");
+    }
+
+    #[test]
+    fn primary_article_skips_labelled_comments_and_prefers_substance() {
+        let para = "A substantive paragraph of body text that easily clears the one hundred character bar used for scoring candidates.";
+        let html = format!(
+            r#"<body>
+                <article id="teaser"><p>{para}</p></article>
+                <article id="main"><p>{para}</p><p>{para}</p></article>
+                <article id="comment" aria-label="Reply from a reader"><p>{para}</p><p>{para}</p><p>{para}</p></article>
+            </body>"#
+        );
+        let doc = Document::from(html.as_str());
+        let articles: Vec<Selection> = doc.select("article").nodes().iter().map(|n| Selection::from(*n)).collect();
+
+        let picked = pick_primary_article(&doc, articles, &Options::default()).expect("an article");
+        assert_eq!(picked.attr("id").as_deref(), Some("main"));
+    }
+
+    #[test]
+    fn primary_article_keeps_first_of_nested_articles() {
+        let para = "A substantive paragraph of body text that easily clears the one hundred character bar used for scoring candidates.";
+        let html = format!(
+            r#"<body>
+                <article id="story"><p>{para}</p>
+                    <section><article id="reply"><p>{para}</p><p>{para}</p><p>{para}</p></article></section>
+                </article>
+            </body>"#
+        );
+        let doc = Document::from(html.as_str());
+        let articles: Vec<Selection> = doc.select("article").nodes().iter().map(|n| Selection::from(*n)).collect();
+
+        let picked = pick_primary_article(&doc, articles, &Options::default()).expect("an article");
+        assert_eq!(picked.attr("id").as_deref(), Some("story"));
+    }
+
+    #[test]
+    fn test_code_language_from_pre_markers() {
+        let doc = Document::from(
+            r#"<pre class="line-numbers language-python">a</pre>
+            <pre class="brush: js; gutter: false">b</pre>
+            <pre data-lang="Go">c</pre>
+            <pre class="ascii-art">d</pre>"#,
+        );
+        let languages: Vec<Option<String>> = doc.select("pre").iter().map(|pre| code_language(&pre)).collect();
+
+        assert_eq!(
+            languages,
+            [Some("python".to_string()), Some("js".to_string()), Some("go".to_string()), None]
+        );
+    }
+
+    #[test]
+    fn test_auto_description_cuts_long_first_sentence() {
+        let sentence = "word ".repeat(50);
+        let description = auto_description(&format!("Short heading\n{sentence}")).expect("description");
+
+        assert!(description.ends_with("word\u{2026}"), "{description}");
+        assert!(description.chars().count() <= AUTO_DESCRIPTION_CHARS);
+        assert_eq!(auto_description("Too short"), None);
+    }
+
+    #[test]
+    fn test_looks_like_navigation_uses_document_language() {
+        let german_nav = "Startseite | Über uns | Produkte | Kontakt | Impressum | Datenschutz";
+        let options = Options::default();
+
+        assert!(looks_like_navigation(german_nav, Some("de"), &options));
+        assert!(!looks_like_navigation(german_nav, Some("en"), &options));
+        assert!(!looks_like_navigation(german_nav, None, &options));
+        assert!(looks_like_navigation("Home About Contact Blog", None, &options));
+        assert!(!looks_like_navigation("Der Kontakt mit dem Startseite-Team war kurz.", Some("de"), &options));
+
+        let custom = Options { navigation_keywords: Some(vec!["Produkte".into(), "Kontakt".into(), "Impressum".into()]), ..Options::default() };
+        assert!(looks_like_navigation(german_nav, Some("en"), &custom));
+        assert!(!looks_like_navigation("Home About Contact Blog", None, &custom));
+    }
 }
 
 #[cfg(test)]
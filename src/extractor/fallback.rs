@@ -109,6 +109,145 @@ pub fn extract_discourse_content(doc: &Document) -> Option<String> {
     Some(content_parts.join("\n\n"))
 }
 
+/// Minimum length (chars) for an embedded JSON string (`__NEXT_DATA__`, SPA
+/// state) to be considered article body.
+const MIN_JSON_BODY_LEN: usize = 200;
+
+/// Extract pre-rendered article content from a Next.js `__NEXT_DATA__` script.
+///
+/// Next.js pages embed the full page props as JSON in
+/// `<script id="__NEXT_DATA__">`. Client-rendered pages often ship an almost
+/// empty DOM while this payload holds the article body. The JSON is walked and
+/// the longest string that reads like prose (enough words and sentences) is
+/// returned as plain text. HTML fragments are flattened to paragraphs.
+#[must_use]
+pub fn extract_next_data_content(doc: &Document) -> Option<String> {
+    let script_selection = doc.select("script#__NEXT_DATA__");
+    let script = Selection::from(*script_selection.nodes().first()?);
+    let json_text = dom::text_content(&script).trim().to_string();
+    if json_text.is_empty() {
+        return None;
+    }
+
+    let data: Value = serde_json::from_str(&json_text).ok()?;
+
+    let mut best: Option<&str> = None;
+    find_prose_string(&data, &mut best);
+    Some(prose_to_text(best?))
+}
+
+/// Global variables SPAs assign their serialized store to in inline scripts.
+const STATE_GLOBALS: &[&str] = &[
+    "__APOLLO_STATE__",
+    "__PRELOADED_STATE__",
+    "__INITIAL_STATE__",
+    "__REDUX_STATE__",
+];
+
+/// Extract article content from serialized single-page-app state.
+///
+/// Generalizes the `__NEXT_DATA__` approach to other stores: each
+/// `<script type="application/json">` (except `__NEXT_DATA__`, handled
+/// separately), then each inline `window.__APOLLO_STATE__ = {...}`-style
+/// assignment, is parsed in turn, and the longest prose-like string of the
+/// first one holding any is returned as plain text.
+#[must_use]
+pub fn extract_json_state_content(doc: &Document) -> Option<String> {
+    let json_scripts = doc
+        .select(r#"script[type="application/json"]"#)
+        .iter()
+        .filter(|script| dom::id(script).as_deref() != Some("__NEXT_DATA__"))
+        .filter_map(|script| serde_json::from_str::<Value>(dom::text_content(&script).trim()).ok());
+    let assignments = doc
+        .select("script:not([type]), script[type='text/javascript']")
+        .iter()
+        .filter_map(|script| parse_state_assignment(&dom::text_content(&script)));
+
+    json_scripts.chain(assignments).find_map(|state| {
+        let mut best: Option<&str> = None;
+        find_prose_string(&state, &mut best);
+        best.map(prose_to_text)
+    })
+}
+
+/// Parse the object literal in `window.__APOLLO_STATE__ = {...};`.
+fn parse_state_assignment(script: &str) -> Option<Value> {
+    let global = STATE_GLOBALS.iter().find_map(|name| script.find(name).map(|pos| pos + name.len()))?;
+    let rest = &script[global..];
+    let start = rest.find('=').map(|eq| eq + 1)?;
+    let rest = &rest[start..];
+    let open = rest.find('{')?;
+    let close = rest.rfind('}')?;
+    if close <= open {
+        return None;
+    }
+    serde_json::from_str(&rest[open..=close]).ok()
+}
+
+/// Turn an embedded body string into plain text; HTML fragments are
+/// flattened to paragraphs.
+fn prose_to_text(body: &str) -> String {
+    let body = body.trim();
+
+    if body.contains('<') {
+        let temp_doc = Document::from(format!("<div>{body}</div>"));
+        let blocks: Vec<String> = temp_doc
+            .select("p, h1, h2, h3, h4, h5, h6, li, blockquote, pre")
+            .iter()
+            .map(|el| dom::text_content(&el).trim().to_string())
+            .filter(|t| !t.is_empty())
+            .collect();
+        if blocks.is_empty() {
+            return dom::text_content(&temp_doc.select("div")).trim().to_string();
+        }
+        return blocks.join("\n\n");
+    }
+
+    body.to_string()
+}
+
+/// Recursively find the longest prose-like string in a JSON value.
+fn find_prose_string<'a>(value: &'a Value, best: &mut Option<&'a str>) {
+    match value {
+        Value::String(s) if best.is_none_or(|b| s.len() > b.len()) && looks_like_prose(s) => {
+            *best = Some(s);
+        }
+        Value::Object(map) => {
+            for val in map.values() {
+                find_prose_string(val, best);
+            }
+        }
+        Value::Array(arr) => {
+            for item in arr {
+                find_prose_string(item, best);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Heuristic check that a string is natural-language body text rather than
+/// an identifier, URL, serialized blob, or CSS.
+fn looks_like_prose(s: &str) -> bool {
+    let len = s.chars().count();
+    if len < MIN_JSON_BODY_LEN {
+        return false;
+    }
+
+    // Long tokens without spaces (base64, hashes, minified data) are not prose
+    let word_count = s.split_whitespace().count();
+    if word_count == 0 || len / word_count > 12 {
+        return false;
+    }
+
+    // Require roughly one sentence terminator per 300 chars (min 2)
+    let sentence_count = s
+        .split(['.', '!', '?'])
+        .filter(|part| part.split_whitespace().count() >= 3)
+        .count();
+    sentence_count >= (len / 300).max(2)
+}
+
 /// Recursively find articleBody in JSON-LD data.
 fn find_article_body(value: &Value) -> Option<String> {
     match value {
@@ -709,6 +848,81 @@ mod tests {
         assert!(!text.contains("<p>"));
     }
 
+    #[test]
+    fn test_extract_next_data_picks_longest_prose() {
+        let body = "The committee met on Monday to review the budget. \
+            Members debated the proposal for several hours before reaching a decision. \
+            The final vote was unanimous, and the plan will take effect next year. \
+            Residents will be able to comment on the details at a public hearing.";
+        let html = format!(r#"<html><head></head><body><div id="__next"></div>
+            <script id="__NEXT_DATA__" type="application/json">
+            {{"props": {{"pageProps": {{
+                "title": "Budget approved",
+                "slug": "budget-approved-2024",
+                "thumbnail": "aGVsbG8td29ybGQtdGhpcy1pcy1hLWxvbmctYmFzZTY0LXN0cmluZy10aGF0LXNob3VsZC1iZS1pZ25vcmVk",
+                "article": {{"body": "{body}"}}
+            }}}}}}
+            </script></body></html>"#);
+
+        let doc = Document::from(html.as_str());
+        let result = extract_next_data_content(&doc);
+
+        assert_eq!(result.as_deref(), Some(body));
+    }
+
+    #[test]
+    fn test_extract_next_data_flattens_html() {
+        let html = r#"<html><body>
+            <script id="__NEXT_DATA__" type="application/json">
+            {"props": {"pageProps": {"content": "<p>The first paragraph explains what happened in the town on Friday night.</p><p>The second paragraph describes how the emergency services responded to the call.</p><p>The third paragraph quotes a local resident who saw the whole thing unfold.</p>"}}}
+            </script></body></html>"#;
+
+        let doc = Document::from(html);
+        let result = extract_next_data_content(&doc).expect("should find body");
+
+        assert!(result.starts_with("The first paragraph"));
+        assert!(result.contains("\n\nThe second paragraph"));
+        assert!(!result.contains("<p>"));
+    }
+
+    #[test]
+    fn test_extract_next_data_ignores_short_strings() {
+        let html = r#"<script id="__NEXT_DATA__" type="application/json">
+            {"props": {"pageProps": {"title": "Short title. Nothing else here."}}}
+            </script>"#;
+
+        let doc = Document::from(html);
+        assert!(extract_next_data_content(&doc).is_none());
+    }
+
+    #[test]
+    fn test_extract_json_state_from_script_and_assignment() {
+        let body = "The river burst its banks late on Sunday evening after days of rain. \
+            Emergency crews evacuated more than two hundred homes in the lower town. \
+            Officials said the water level had started to fall by Monday afternoon. \
+            A full assessment of the damage is expected later this week.";
+        let json_script = format!(r#"<script type="application/json" id="app-state">
+            {{"ROOT_QUERY": {{"story": {{"__ref": "Story:1"}}}}, "Story:1": {{"headline": "Flooding", "bodyText": "{body}"}}}}
+            </script>"#);
+        let doc = Document::from(format!("<html><body><div id=\"root\"></div>{json_script}</body></html>").as_str());
+        assert_eq!(extract_json_state_content(&doc).as_deref(), Some(body));
+
+        let assignment = format!(r#"<script>window.__APOLLO_STATE__ = {{"Story:1": {{"bodyText": "{body}"}}}};</script>"#);
+        let doc = Document::from(format!("<html><body><div id=\"root\"></div>{assignment}</body></html>").as_str());
+        assert_eq!(extract_json_state_content(&doc).as_deref(), Some(body));
+    }
+
+    #[test]
+    fn test_extract_json_state_skips_next_data_and_short_values() {
+        let html = r#"<html><body>
+            <script type="application/json">{"config": {"theme": "dark", "title": "Short title. Nothing else."}}</script>
+            <script id="__NEXT_DATA__" type="application/json">{"props": {"pageProps": {"content": "Handled by the Next.js extractor. It has several sentences. They are long enough to count as prose when the length check passes. But this one should be skipped here regardless of its length."}}}</script>
+            </body></html>"#;
+
+        let doc = Document::from(html);
+        assert!(extract_json_state_content(&doc).is_none());
+    }
+
     #[test]
     fn test_baseline_with_article_tag() {
         let html = r#"<!DOCTYPE html>
@@ -6,6 +6,7 @@ use dom_query::{Selection, Document};
 use crate::dom;
 use crate::etree;
 use crate::html_processing::{handle_text_node, is_share_button_text, process_node, text_chars_test};
+use crate::url_utils;
 use crate::Options;
 use super::state::ExtractionState;
 use super::tags::{
@@ -202,13 +203,28 @@ pub fn handle_formatting(
     formatting
 }
 
+/// Class-name prefixes marking source code (Prism/highlight.js
+/// `language-x`, google-code-prettify `lang-x`, SyntaxHighlighter `brush:x`).
+const CODE_CLASS_PREFIXES: &[&str] = &["language-", "lang-", "brush:"];
+
+/// Whole class names marking source code (highlight.js, Pygments,
+/// google-code-prettify, Pandoc).
+const CODE_CLASS_TOKENS: &[&str] = &["hljs", "highlight", "prettyprint", "sourcecode", "code"];
+
 /// Check if element is a code block according to common structural markers.
 ///
+/// Beyond the Go checks, a highlighter class token or `data-lang` on the
+/// element, or highlight.js token spans inside it, also mark code, so
+/// preformatted prose and ASCII art aren't mistaken for it.
+///
 /// Go equivalent: `isCodeBlockElement(element)` (lines 197-217)
 #[must_use]
 pub fn is_code_block_element(element: &Selection) -> bool {
-    // Check for lang attribute (Pip)
-    if dom::get_attribute(element, "lang").is_some() {
+    // Check for lang attribute (Pip) or its data-* variants
+    if ["lang", "data-lang", "data-language"]
+        .iter()
+        .any(|attr| dom::get_attribute(element, attr).is_some())
+    {
         return true;
     }
 
@@ -217,6 +233,14 @@ pub fn is_code_block_element(element: &Selection) -> bool {
         return true;
     }
 
+    // Check own class for highlighter markers
+    let class = dom::get_attribute(element, "class").unwrap_or_default().to_ascii_lowercase();
+    if class.split_whitespace().any(|token| {
+        CODE_CLASS_TOKENS.contains(&token) || CODE_CLASS_PREFIXES.iter().any(|prefix| token.starts_with(prefix))
+    }) {
+        return true;
+    }
+
     // Check parent for highlight class (GitHub)
     let parent = dom::parent(element);
     if !parent.is_empty() {
@@ -226,6 +250,11 @@ pub fn is_code_block_element(element: &Selection) -> bool {
         }
     }
 
+    // Check for highlight.js token spans
+    if element.select(r#"span[class^="hljs"], span[class*=" hljs"]"#).exists() {
+        return true;
+    }
+
     // Check for single <code> child (Highlight.js)
     let code_children = element.select("code");
     let all_children = dom::children(element);
@@ -656,7 +685,7 @@ pub fn handle_paragraphs(
 
                 dom::clear_all_attributes(&child);
 
-                if !href.is_empty() {
+                if !href.is_empty() && url_utils::scheme_allowed(&href, &opts.allowed_link_schemes) {
                     dom::set_attribute(&child, "href", &href);
                 }
                 if !target.is_empty() {
@@ -942,6 +971,19 @@ mod tests {
         assert!(!is_code_block_element(&pre));
     }
 
+    #[test]
+    fn test_is_code_block_class_tokens() {
+        let doc = dom::parse(
+            r#"<pre class="line-numbers language-rust">a</pre>
+            <pre class="brush: js; gutter: false">b</pre>
+            <pre class="hljs">c</pre>
+            <pre class="decoder-output">d</pre>
+            <pre class="ascii-art">e</pre>"#,
+        );
+        let flags: Vec<bool> = doc.select("pre").iter().map(|pre| is_code_block_element(&pre)).collect();
+        assert_eq!(flags, [true, true, true, false, false]);
+    }
+
     #[test]
     fn test_handle_image_basic() {
         let doc = dom::parse(r#"<img src="test.jpg" alt="Test">"#);
@@ -0,0 +1,79 @@
+//! Content fingerprinting for deduplication.
+//!
+//! Produces a stable 64-bit hash of extracted text so that identical content
+//! can be recognised across crawl snapshots, processes, and platforms.
+
+/// FNV-1a 64-bit offset basis.
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+
+/// FNV-1a 64-bit prime.
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Computes the content hash used for `ExtractResult::content_hash`.
+///
+/// The text is normalized first: leading/trailing whitespace is trimmed and
+/// every run of Unicode whitespace (spaces, tabs, newlines) is collapsed to a
+/// single ASCII space. The UTF-8 bytes of the normalized text are then hashed
+/// with 64-bit FNV-1a (offset basis `0xcbf29ce484222325`, prime `0x100000001b3`).
+///
+/// The result depends only on the input text, so it is identical across runs,
+/// platforms, and crate versions, and can be reproduced by any FNV-1a
+/// implementation.
+///
+/// # Example
+///
+/// ```rust
+/// use rs_trafilatura::content_hash;
+///
+/// assert_eq!(content_hash("Hello   world\n"), content_hash("Hello world"));
+/// assert_ne!(content_hash("Hello world"), content_hash("Hello there"));
+/// ```
+#[must_use]
+pub fn content_hash(text: &str) -> u64 {
+    let mut hash = FNV_OFFSET_BASIS;
+    let mut first = true;
+
+    for word in text.split_whitespace() {
+        if !first {
+            hash = fnv1a_update(hash, b" ");
+        }
+        hash = fnv1a_update(hash, word.as_bytes());
+        first = false;
+    }
+
+    hash
+}
+
+fn fnv1a_update(mut hash: u64, bytes: &[u8]) -> u64 {
+    for &byte in bytes {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_text_is_offset_basis() {
+        assert_eq!(content_hash(""), FNV_OFFSET_BASIS);
+        assert_eq!(content_hash("  \n\t "), FNV_OFFSET_BASIS);
+    }
+
+    #[test]
+    fn test_known_fnv1a_vectors() {
+        // Reference values for 64-bit FNV-1a
+        assert_eq!(content_hash("a"), 0xaf63_dc4c_8601_ec8c);
+        assert_eq!(content_hash("foobar"), 0x8594_4171_f739_67e8);
+    }
+
+    #[test]
+    fn test_whitespace_is_normalized() {
+        assert_eq!(
+            content_hash("First paragraph.\n\nSecond   paragraph."),
+            content_hash("First paragraph. Second paragraph.")
+        );
+    }
+}
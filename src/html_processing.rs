@@ -9,10 +9,11 @@ use crate::extractor::tags::{
     TAGS_TO_CLEAN, TAGS_TO_STRIP, EMPTY_TAGS_TO_REMOVE_SET,
     TABLE_TAGS_TO_STRIP,
 };
-use crate::link_density::link_density_test_with_info;
+use crate::link_density::{link_density_test, link_density_test_with_info};
 use crate::lru::LruCache;
 use crate::options::Options;
 use crate::selector::{self, Rule};
+use crate::url_utils;
 use std::collections::HashSet;
 use std::sync::LazyLock;
 
@@ -95,15 +96,6 @@ static XML_GRAPHIC_TAGS: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
 /// Go source: `listXmlQuoteTags` in tag-converter.go line 5
 static XML_QUOTE_TAGS: [&str; 3] = ["blockquote", "pre", "q"];
 
-// === Deduplication Config Constants ===
-// Default values from go-trafilatura/core-options.go
-
-/// Minimum text length to check for duplicates (default: 100 chars)
-const MIN_DUPLICATE_CHECK_SIZE: usize = 100;
-
-/// Maximum times text can appear before being considered duplicate (default: 2)
-const MAX_DUPLICATE_COUNT: i32 = 2;
-
 // === Document Cleaning Functions ===
 
 /// Clean the document by discarding unwanted elements
@@ -128,6 +120,408 @@ pub fn doc_cleaning_with_profile(doc: &Document, opts: &Options, profile: &Extra
     doc_cleaning_inner(doc, opts, profile.preserve_tags);
 }
 
+/// Whether a `<noscript>` holds real page content (>500 chars that aren't a
+/// consent/GDPR or "enable JavaScript" notice), which doc cleaning rescues.
+pub fn is_content_noscript(noscript: &Selection) -> bool {
+    const NOSCRIPT_CONTENT_THRESHOLD: usize = 500;
+    let text = noscript.text();
+    let text_lower = text.to_lowercase();
+    let text_len = text.trim().len();
+
+    let is_consent = text_lower.contains("cookie")
+        || text_lower.contains("consent")
+        || text_lower.contains("gdpr")
+        || text_lower.contains("privacy")
+        || text_lower.contains("third party partners")
+        || text_lower.contains("personalize content")
+        || text_lower.contains("enable javascript");
+
+    text_len > NOSCRIPT_CONTENT_THRESHOLD && !is_consent
+}
+
+/// Substrings of lazy-load placeholder file names (blurred previews, blank
+/// and spacer GIFs).
+const PLACEHOLDER_SRC_MARKERS: &[&str] = &["placeholder", "blank.", "spacer.", "pixel.", "transparent.", "lazy", "blur"];
+
+/// Swaps lazy-load placeholders for the real image kept in an image-only
+/// `<noscript>` (a blurred `<img>`/`<picture>` followed by
+/// `<noscript><img src=... srcset=...></noscript>`).
+///
+/// The placeholder is the `<noscript>`'s previous element sibling when that
+/// is an `<img>` or `<picture>` pointing at the same image (through
+/// `data-src`/`data-srcset`) or with a known placeholder `src` (a data URI,
+/// blank or blurred file). Otherwise the `<noscript>` is still unwrapped,
+/// so doc cleaning doesn't drop its image. Tracking pixels (1x1 images) in
+/// `<noscript>` are left alone.
+pub fn promote_noscript_images(doc: &Document) {
+    let noscripts: Vec<_> = doc.select("noscript").nodes().to_vec();
+    for node in noscripts {
+        let elements: Vec<_> = node.children().into_iter().filter(dom_query::NodeRef::is_element).collect();
+        let image_only = !elements.is_empty()
+            && elements.iter().all(|child| child.has_name("img") || child.has_name("picture"));
+        if !image_only {
+            continue;
+        }
+        let noscript = Selection::from(node);
+        let real = noscript.select("img").first();
+        let is_pixel = ["width", "height"]
+            .iter()
+            .any(|attr| real.attr(attr).and_then(|v| v.trim().parse::<u32>().ok()).is_some_and(|v| v <= 1));
+        if is_pixel {
+            continue;
+        }
+
+        let mut prev = node.prev_sibling();
+        while let Some(sibling) = prev {
+            if sibling.is_element() {
+                break;
+            }
+            prev = sibling.prev_sibling();
+        }
+        if let Some(placeholder) = prev.filter(|p| p.has_name("img") || p.has_name("picture")) {
+            if is_placeholder_for(&Selection::from(placeholder), &real) {
+                placeholder.remove_from_parent();
+            }
+        }
+        etree::strip(&noscript);
+    }
+}
+
+/// Whether `placeholder` (an `<img>` or `<picture>`) is a lazy-load stand-in
+/// for the `<noscript>` image `real`.
+fn is_placeholder_for(placeholder: &Selection, real: &Selection) -> bool {
+    let img = if placeholder.is("img") { placeholder.clone() } else { placeholder.select("img").first() };
+    let real_src = real.attr("src").map(|src| src.trim().to_string()).unwrap_or_default();
+    if !real_src.is_empty() {
+        let points_at_real = ["data-src", "data-lazy-src", "data-original", "data-srcset", "data-lazy-srcset"]
+            .iter()
+            .filter_map(|attr| img.attr(attr))
+            .any(|value| value.split(',').any(|candidate| candidate.split_whitespace().next() == Some(real_src.as_str())));
+        if points_at_real {
+            return true;
+        }
+    }
+    let src = img.attr("src").map(|src| src.trim().to_ascii_lowercase()).unwrap_or_default();
+    src.is_empty()
+        || src.starts_with("data:")
+        || {
+            let filename = url_utils::extract_filename(&src);
+            PLACEHOLDER_SRC_MARKERS.iter().any(|marker| filename.contains(marker))
+        }
+}
+
+/// Replaces each inline `<svg>` that has a `<title>` with that title as
+/// plain text, so an icon's accessible label survives the removal of SVG
+/// during doc cleaning. Untitled SVGs are left for cleaning to drop.
+pub fn svg_titles_to_text(doc: &Document) {
+    for svg in doc.select("svg").iter() {
+        let Some(title) = svg.children().iter().find(|child| dom::tag_name(child).as_deref() == Some("title")) else {
+            continue;
+        };
+        let text = title.text().split_whitespace().collect::<Vec<_>>().join(" ");
+        if text.is_empty() {
+            continue;
+        }
+        let text = text.replace('&', "&amp;").replace('<', "&lt;");
+        dom::replace_with_html(&svg, &format!("<span>{text}</span>"));
+    }
+}
+
+/// Lists that can hold a row of icon-only navigation links.
+const ICON_NAV_CONTAINERS: &str = "nav, menu, ul, ol";
+
+/// Visible characters an icon link may have (an emoji with modifiers, or a
+/// glyph like `☰`).
+const MAX_ICON_LINK_CHARS: usize = 4;
+
+/// Remove link rows whose links show no words: icon-font links
+/// (`<a><i class="fa fa-home"></i></a>`) or a single emoji or symbol each.
+///
+/// Such rows escape class-based boilerplate filtering and otherwise leave
+/// stray symbols or empty list items in the content. Only navigation lists
+/// are considered: every child must be a link (or an `<li>` holding one)
+/// with at most a symbol of text, and there must be two or more of them.
+/// Linked images are kept.
+pub fn remove_icon_navigation(doc: &Document) {
+    for container in doc.select(ICON_NAV_CONTAINERS).iter() {
+        let items = container.children();
+        if items.length() < 2 {
+            continue;
+        }
+        let icon_only = items.iter().all(|item| {
+            let link = match dom::tag_name(&item).as_deref() {
+                Some("a") => item.clone(),
+                Some("li") => item.children().filter("a"),
+                _ => return false,
+            };
+            if !link.exists() || item.select("img, picture, video").exists() {
+                return false;
+            }
+            let text = item.text();
+            text.chars().filter(|c| !c.is_whitespace()).take(MAX_ICON_LINK_CHARS + 1).count() <= MAX_ICON_LINK_CHARS
+                && !text.contains(char::is_alphanumeric)
+        });
+        if icon_only {
+            container.remove();
+        }
+    }
+}
+
+/// Nesting depth of declarative shadow roots expanded by `expand_shadow_roots`.
+const MAX_SHADOW_ROOT_DEPTH: usize = 8;
+
+/// Inline declarative shadow roots (`<template shadowrootmode="open">`) into
+/// their host element.
+///
+/// The parser keeps a template's content out of the tree, so the shadow
+/// root's text would never be extracted. Each template is replaced by its
+/// content, ahead of the host's light-DOM children. A `<slot>` filled by a
+/// light-DOM child is dropped with its fallback content, since the child
+/// carries the text; unfilled slots are unwrapped to their fallback. Shadow
+/// roots nested inside shadow roots only appear once their parent is
+/// inlined, hence the passes.
+pub fn expand_shadow_roots(doc: &Document) {
+    for _ in 0..MAX_SHADOW_ROOT_DEPTH {
+        let templates = doc.select("template[shadowrootmode], template[shadowroot]");
+        if !templates.exists() {
+            break;
+        }
+        for template in templates.iter() {
+            let Some(host) = template.nodes().first().and_then(dom_query::NodeRef::parent) else {
+                continue;
+            };
+            let template_id = template.nodes().first().map(|node| node.id);
+            let light: Vec<_> = host.children().into_iter().filter(|child| Some(child.id) != template_id).collect();
+
+            // Template content is only reachable through serialization
+            let outer = dom::outer_html(&template).to_string();
+            let content = outer
+                .find('>')
+                .map(|start| &outer[start + 1..])
+                .and_then(|rest| rest.strip_suffix("</template>"))
+                .unwrap_or("");
+            dom::replace_with_html(&template, content);
+
+            // Slots of this shadow root, now inline among the host's children
+            for child in host.children() {
+                if light.iter().any(|node| node.id == child.id) {
+                    continue;
+                }
+                let shadow = Selection::from(child);
+                for slot in shadow.select("slot").iter().chain(shadow.filter("slot").iter()) {
+                    let name = slot.attr("name");
+                    if light.iter().any(|node| fills_slot(node, name.as_deref())) {
+                        slot.remove();
+                    }
+                }
+            }
+        }
+    }
+    for slot in doc.select("slot").iter() {
+        etree::strip(&slot);
+    }
+}
+
+/// Whether light-DOM `node` is assigned to the slot named `name` (`None`
+/// for the default slot, which takes everything without a `slot` attribute).
+fn fills_slot(node: &dom_query::NodeRef, name: Option<&str>) -> bool {
+    match name {
+        Some(name) => node.attr("slot").is_some_and(|slot| &*slot == name),
+        None if node.is_element() => node.attr("slot").is_none(),
+        None => node.is_text() && !node.text().trim().is_empty(),
+    }
+}
+
+/// Alt text at least this long can carry content (infographic narratives).
+const MIN_LONG_ALT_CHARS: usize = 200;
+
+/// Add the alt text of infographic-style images as a paragraph after the
+/// image (or its `<figure>`, or the paragraph holding it).
+///
+/// Only alt text of at least `MIN_LONG_ALT_CHARS` with two or more
+/// sentences qualifies; short decorative alt text is left alone.
+pub fn long_alt_to_text(doc: &Document) {
+    for img in doc.select("img[alt]").iter() {
+        let alt = dom::get_attribute(&img, "alt").unwrap_or_default();
+        let alt = alt.split_whitespace().collect::<Vec<_>>().join(" ");
+        if alt.chars().count() < MIN_LONG_ALT_CHARS || sentence_count(&alt) < 2 {
+            continue;
+        }
+        let figure = img.nodes().first().and_then(|node| node.ancestors(None).into_iter().find(|anc| anc.has_name("figure")));
+        let parent = dom::parent(&img);
+        let anchor = if let Some(figure) = figure {
+            Selection::from(figure)
+        } else if dom::tag_name(&parent).as_deref() == Some("p") {
+            parent
+        } else {
+            img
+        };
+        let text = alt.replace('&', "&amp;").replace('<', "&lt;");
+        anchor.after_html(format!("<p>{text}</p>"));
+    }
+}
+
+/// Number of sentence-ending punctuation marks followed by a space or the end.
+fn sentence_count(text: &str) -> usize {
+    let chars: Vec<char> = text.chars().collect();
+    chars
+        .iter()
+        .enumerate()
+        .filter(|&(i, c)| matches!(c, '.' | '!' | '?') && chars.get(i + 1).is_none_or(|next| next.is_whitespace()))
+        .count()
+}
+
+/// Builds a document with a `<body>` for input the parser gave none
+/// (`<frameset>` pages), or `None` if the root holds no content.
+///
+/// The head is kept for metadata. `<noframes>` fallback markup, which the
+/// parser stores as raw text, is reparsed as body content.
+pub fn synthesize_body(doc: &Document) -> Option<Document> {
+    let root = doc.select("html");
+    let root_node = root.nodes().first()?;
+
+    let mut body = String::new();
+    for child in root_node.children() {
+        if child.has_name("head") || !child.is_element() {
+            continue;
+        }
+        let el = Selection::from(child);
+        let noframes = el.select("noframes");
+        if noframes.length() > 0 {
+            for fallback in noframes.iter() {
+                body.push_str(&fallback.text());
+            }
+        } else if !child.has_name("frameset") {
+            body.push_str(&dom::outer_html(&el));
+        }
+    }
+    if body.trim().is_empty() {
+        return None;
+    }
+
+    let head = doc.select("head");
+    let head_html = if head.length() > 0 { dom::inner_html(&head).to_string() } else { String::new() };
+    Some(Document::from(format!("<html><head>{head_html}</head><body>{body}</body></html>")))
+}
+
+/// Whether an element sits inside an `<article>` or `<main>` element.
+fn is_inside_article_or_main(sel: &Selection) -> bool {
+    let mut cur = sel.parent();
+    while cur.length() > 0 {
+        if let Some(tag) = dom::tag_name(&cur) {
+            if tag == "article" || tag == "main" {
+                return true;
+            }
+            if tag == "body" || tag == "html" {
+                return false;
+            }
+        }
+        cur = cur.parent();
+    }
+    false
+}
+
+/// Editable regions of rich-text editors, searched for placeholder text.
+const EDITOR_REGION_SELECTOR: &str =
+    "[contenteditable], .mce-content-body, .cke_editable, .ck-editor__editable";
+
+/// CKEditor UI classes (toolbars, widget handles, hidden selections).
+const CKEDITOR_CHROME_CLASSES: &[&str] = &[
+    "cke_top", "cke_bottom", "cke_toolbox", "cke_toolbar", "cke_dialog",
+    "cke_reset_all", "cke_widget_drag_handler_container", "cke_image_resizer",
+    "ck-toolbar", "ck-widget__type-around", "ck-widget__resizer",
+    "ck-fake-selection-container",
+];
+
+/// Default placeholder prompts shown by editors in empty blocks.
+const EDITOR_PLACEHOLDERS: &[&str] = &[
+    "type here", "type something", "start typing", "start writing",
+    "write something", "write here", "enter text here", "enter your text here",
+    "click here to edit", "click to edit", "click to add text",
+];
+
+/// Remove rich-text editor chrome from CMS preview pages.
+///
+/// - TinyMCE UI (`mce-*` / `tox-*` classes) and CKEditor UI are removed,
+///   leaving the editable content body (`mce-content-body`, `cke_editable`).
+/// - `data-mce-bogus="all"`, `data-cke-temp` and `data-cke-hidden-sel`
+///   nodes are removed; other `data-mce-bogus` wrappers are unwrapped.
+/// - Empty or placeholder-only blocks in editable regions are removed (media
+///   elements are kept), and `contenteditable` wrappers lose the attribute.
+fn remove_editor_artifacts(doc: &Document) {
+    doc.select("[data-mce-bogus=\"all\"], [data-cke-temp], [data-cke-hidden-sel]").remove();
+    for node in doc.select("[data-mce-bogus]").nodes().to_vec().into_iter().rev() {
+        etree::strip(&Selection::from(node));
+    }
+
+    let chrome: Vec<_> = doc
+        .select("[class*=\"mce-\"], [class*=\"tox-\"], [class*=\"cke_\"], [class*=\"ck-\"]")
+        .nodes()
+        .to_vec();
+    for node in chrome {
+        let el = Selection::from(node);
+        if is_editor_chrome(&el) {
+            el.remove();
+        }
+    }
+
+    // Placeholder attributes also appear outside editors (e.g. blur-up
+    // images), so only editable regions are searched
+    let candidates: Vec<_> = doc
+        .select(EDITOR_REGION_SELECTOR)
+        .select("p, div, span, [data-placeholder], [data-mce-placeholder], [aria-placeholder]")
+        .nodes()
+        .to_vec();
+    for node in candidates {
+        let el = Selection::from(node);
+        if is_editor_placeholder(&el) {
+            el.remove();
+        }
+    }
+
+    for node in doc.select("[contenteditable]").nodes() {
+        dom::remove_attribute(&Selection::from(*node), "contenteditable");
+    }
+}
+
+/// Whether an element's classes mark it as editor UI rather than content.
+fn is_editor_chrome(el: &Selection) -> bool {
+    let class = dom::class_name(el).unwrap_or_default();
+    let tokens: Vec<&str> = class.split_whitespace().collect();
+    if tokens
+        .iter()
+        .any(|t| matches!(*t, "mce-content-body" | "cke_editable" | "ck-editor__editable"))
+    {
+        return false;
+    }
+    tokens.iter().any(|t| {
+        (t.starts_with("mce-") && !t.starts_with("mce-item-"))
+            || t.starts_with("tox-")
+            || CKEDITOR_CHROME_CLASSES.contains(t)
+    })
+}
+
+/// Whether a leaf block holds only an editor placeholder (or nothing at all
+/// while declaring one).
+fn is_editor_placeholder(el: &Selection) -> bool {
+    if dom::children(el).length() > 0 || el.is("img, picture, video, audio, iframe, input") {
+        return false;
+    }
+    let text = el.text();
+    let text = text.trim();
+    let declared = ["data-placeholder", "data-mce-placeholder", "aria-placeholder"]
+        .iter()
+        .filter_map(|name| el.attr(name))
+        .any(|value| text.is_empty() || text == value.trim());
+    if declared {
+        return true;
+    }
+    let normalized = text
+        .trim_end_matches(['.', '\u{2026}', ' '])
+        .to_lowercase();
+    EDITOR_PLACEHOLDERS.contains(&normalized.as_str())
+}
+
 pub fn doc_cleaning(doc: &Document, opts: &Options) {
     doc_cleaning_inner(doc, opts, &[]);
 }
@@ -150,53 +544,43 @@ fn doc_cleaning_inner(doc: &Document, opts: &Options, preserve_tags: &[&str]) {
     }
 
     // Handle noscript elements: strip tag but keep children if they contain
-    // substantial content (>500 chars) that isn't consent/GDPR banners.
-    const NOSCRIPT_CONTENT_THRESHOLD: usize = 500;
+    // substantial content that isn't consent/GDPR banners.
     for noscript_node in doc.select("noscript").nodes() {
         let noscript = Selection::from(*noscript_node);
-        let text = noscript.text();
-        let text_lower = text.to_lowercase();
-        let text_len = text.trim().len();
-
-        let is_consent = text_lower.contains("cookie")
-            || text_lower.contains("consent")
-            || text_lower.contains("gdpr")
-            || text_lower.contains("privacy")
-            || text_lower.contains("third party partners")
-            || text_lower.contains("personalize content")
-            || text_lower.contains("enable javascript");
-
-        if text_len > NOSCRIPT_CONTENT_THRESHOLD && !is_consent {
+        if is_content_noscript(&noscript) {
             etree::strip(&noscript);
         }
     }
 
+    // Handle CMS preview pages: editor toolbars, bogus nodes, and placeholder
+    // text left behind by TinyMCE / CKEditor.
+    remove_editor_artifacts(doc);
+
     // Handle footers: remove only those NOT inside article/main content.
     // Content footers (article notes, attribution) are preserved.
     {
         let footers: Vec<_> = doc.select("footer").nodes().to_vec();
         for footer_node in footers {
             let footer = Selection::from(footer_node);
-            let mut inside_content = false;
-            let mut cur = footer.parent();
-            while cur.length() > 0 {
-                if let Some(tag) = dom::tag_name(&cur) {
-                    if tag == "article" || tag == "main" {
-                        inside_content = true;
-                        break;
-                    }
-                    if tag == "body" || tag == "html" {
-                        break;
-                    }
-                }
-                cur = cur.parent();
-            }
-            if !inside_content {
+            if !is_inside_article_or_main(&footer) {
                 dom::remove(&footer);
             }
         }
     }
 
+    // Handle asides (opt-in): pull-quotes and info boxes inside article/main
+    // become plain containers unless they are link lists. Page-level sidebars
+    // are left for the bulk cleaner below.
+    if opts.include_asides {
+        let asides: Vec<_> = doc.select("aside").nodes().to_vec();
+        for aside_node in asides {
+            let aside = Selection::from(aside_node);
+            if is_inside_article_or_main(&aside) && !link_density_test(&aside, opts) {
+                dom::rename(&aside, "div");
+            }
+        }
+    }
+
     // === Bulk cleaning via html-cleaning crate ===
     {
         use html_cleaning::{HtmlCleaner, CleaningOptions};
@@ -215,6 +599,16 @@ fn doc_cleaning_inner(doc: &Document, opts: &Options, preserve_tags: &[&str]) {
             cleaning_opts.tags_to_remove.retain(|t| !preserve_tags.contains(&t.as_str()));
         }
 
+        // Conditional: preserve math — keep <math> (normalized before cleaning)
+        if opts.preserve_math {
+            cleaning_opts.tags_to_remove.retain(|t| t != "math");
+        }
+
+        // Conditional: keep ruby annotations — the text extractor renders <rt>
+        if opts.keep_ruby_annotations {
+            cleaning_opts.tags_to_remove.retain(|t| t != "rt");
+        }
+
         // Conditional: include tables — don't remove figure/picture/source
         if opts.include_images {
             cleaning_opts.tags_to_remove.retain(|t| !matches!(t.as_str(), "figure" | "picture" | "source"));
@@ -347,8 +741,11 @@ pub fn prune_html(doc: &Document, opts: &Options) {
 
 /// Clean the extracted content (post-processing)
 ///
+/// With `preserve_content_attributes`, `class` and `id` are kept; with
+/// `include_links`, so is the `id` of in-document link targets.
+///
 /// Go equivalent: `postCleaning(doc)` (lines 398-448)
-pub fn post_cleaning(doc: &Document) {
+pub fn post_cleaning(doc: &Document, options: &Options) {
     // Remove empty nodes (process in reverse order)
     let all_elements = doc.select("*").nodes().to_vec();
 
@@ -369,6 +766,7 @@ pub fn post_cleaning(doc: &Document) {
         let sel = Selection::from(*node);
         let tag_name = dom::tag_name(&sel).unwrap_or_default();
         let allows_size = ELEMENT_WITH_SIZE_ATTR.contains(tag_name.as_str());
+        let keep_id = options.include_links && dom::has_attribute(&sel, LINK_TARGET_ATTR);
 
         // Get current attributes
         let attrs = dom::get_all_attributes(&sel);
@@ -376,8 +774,11 @@ pub fn post_cleaning(doc: &Document) {
         // Remove presentational and unsafe attributes
         for (key, _) in attrs {
             let should_remove = match key.as_str() {
+                "id" => !options.preserve_content_attributes && !keep_id,
+                "class" => !options.preserve_content_attributes,
+
                 // Always remove presentational attributes
-                "id" | "class" | "align" | "background" | "bgcolor" | "border"
+                "align" | "background" | "bgcolor" | "border"
                 | "cellpadding" | "cellspacing" | "frame" | "hspace" | "rules"
                 | "style" | "valign" | "vspace" => true,
 
@@ -395,6 +796,559 @@ pub fn post_cleaning(doc: &Document) {
     }
 }
 
+// === Intra-document Links ===
+
+/// Attribute marking elements that in-document links (`href="#..."`) point
+/// at, so their `id` survives into the extracted content.
+pub(crate) const LINK_TARGET_ATTR: &str = "data-rs-link-target";
+
+/// Mark the targets of in-document links, e.g. the headings a table of
+/// contents points at. Legacy `<a name="...">` anchors get the name as their
+/// `id`, so `#fragment` links keep working on the extracted HTML.
+///
+/// Must run before doc cleaning.
+pub fn mark_link_targets(doc: &Document) {
+    let fragments: HashSet<String> = doc
+        .select("a[href^='#']")
+        .iter()
+        .filter_map(|link| link.attr("href"))
+        .map(|href| href[1..].trim().to_string())
+        .filter(|fragment| !fragment.is_empty())
+        .collect();
+    if fragments.is_empty() {
+        return;
+    }
+
+    for node in doc.select("body [id]").nodes() {
+        let el = Selection::from(*node);
+        if el.attr("id").is_some_and(|id| fragments.contains(&*id)) {
+            dom::set_attribute(&el, LINK_TARGET_ATTR, "1");
+        }
+    }
+    for node in doc.select("body a[name]:not([id])").nodes() {
+        let el = Selection::from(*node);
+        if let Some(name) = el.attr("name").filter(|name| fragments.contains(&**name)) {
+            dom::set_attribute(&el, "id", &name);
+            dom::set_attribute(&el, LINK_TARGET_ATTR, "1");
+        }
+    }
+}
+
+/// Remove the `data-rs-*` markers that carry state across doc cleaning,
+/// for documents handed back to callers.
+pub fn strip_internal_attributes(doc: &Document) {
+    let attrs = [LINK_TARGET_ATTR, CAPTION_MARKER_ATTR];
+    let selector = attrs.map(|attr| format!("[{attr}]")).join(", ");
+    doc.select(&selector).remove_attrs(&attrs);
+}
+
+// === Caption Separation ===
+
+/// Class tokens and ids that mark photo captions and credit lines. Matched
+/// whole, so `credit-card` forms and `captionless` wrappers are left alone.
+const CAPTION_NAME_TOKENS: &[&str] = &[
+    "caption",
+    "caption-text",
+    "wp-caption-text",
+    "wp-caption-dd",
+    "photo-caption",
+    "image-caption",
+    "img-caption",
+    "credit",
+    "credits",
+    "photo-credit",
+    "image-credit",
+    "img-credit",
+    "media-credit",
+];
+
+/// Captions longer than this are treated as content, not caption text.
+const MAX_CAPTION_LEN: usize = 500;
+
+/// Maximum length of an unlabeled text block accepted as an image caption.
+const MAX_ADJACENT_CAPTION_LEN: usize = 250;
+
+/// Attribute used to carry image-adjacent caption detection across doc cleaning,
+/// which strips `<img>` tags when images are not requested.
+const CAPTION_MARKER_ATTR: &str = "data-rs-caption";
+
+/// Mark short text blocks that share a wrapper with a single image as captions
+/// (`<div><img><span>Photo: Jane Doe</span></div>`).
+///
+/// Must run before doc cleaning; `separate_captions` picks the marks up afterwards.
+pub fn mark_image_captions(doc: &Document) {
+    for node in doc.select("body p, body span, body small, body em, body cite, body div").nodes() {
+        let el = Selection::from(*node);
+        if is_adjacent_image_caption(&el) {
+            dom::set_attribute(&el, CAPTION_MARKER_ATTR, "1");
+        }
+    }
+}
+
+/// Remove caption and credit elements from the document and return their text.
+///
+/// Detected elements:
+/// - `<figcaption>`
+/// - elements with a caption or credit class token or id (`caption`,
+///   `wp-caption-text`, `photo-credit`, ...)
+/// - image-adjacent text marked by `mark_image_captions`
+///
+/// Wrappers that contain the image itself (e.g. `wp-caption`) are not removed;
+/// only their caption text is. Returned captions are unique and in document order.
+pub fn separate_captions(doc: &Document) -> Vec<String> {
+    let body = doc.select("body");
+    let Some(body_node) = body.nodes().first().copied() else {
+        return Vec::new();
+    };
+
+    let mut captions: Vec<String> = Vec::new();
+    let mut selected: Vec<dom_query::NodeRef> = Vec::new();
+
+    for node in body_node.descendants() {
+        if !node.is_element() {
+            continue;
+        }
+        if node.ancestors(None).iter().any(|anc| selected.iter().any(|s| s.id == anc.id)) {
+            continue;
+        }
+
+        let el = Selection::from(node);
+        if el.select("img, picture, video, figure").length() > 0 {
+            continue;
+        }
+
+        let tag = dom::tag_name(&el).unwrap_or_default();
+        let is_caption = tag == "figcaption"
+            || has_caption_name(&el)
+            || dom::has_attribute(&el, CAPTION_MARKER_ATTR);
+        if !is_caption {
+            continue;
+        }
+
+        let text = dom::text_content(&el).split_whitespace().collect::<Vec<_>>().join(" ");
+        if text.is_empty() || text.chars().count() > MAX_CAPTION_LEN {
+            continue;
+        }
+
+        selected.push(node);
+        if !captions.contains(&text) {
+            captions.push(text);
+        }
+    }
+
+    for node in selected {
+        dom::remove(&Selection::from(node));
+    }
+
+    captions
+}
+
+fn has_caption_name(el: &Selection) -> bool {
+    [el.attr("class"), el.attr("id")].into_iter().flatten().any(|name| {
+        name.split_whitespace()
+            .any(|token| CAPTION_NAME_TOKENS.iter().any(|t| token.eq_ignore_ascii_case(t)))
+    })
+}
+
+/// Check for an unlabeled caption: the only text-bearing sibling of a single image
+/// inside a small wrapper.
+fn is_adjacent_image_caption(el: &Selection) -> bool {
+    let tag = dom::tag_name(el).unwrap_or_default();
+    if !matches!(tag.as_str(), "p" | "span" | "small" | "em" | "cite" | "div") {
+        return false;
+    }
+
+    let parent = el.parent();
+    let parent_tag = dom::tag_name(&parent).unwrap_or_default();
+    if matches!(parent_tag.as_str(), "body" | "html" | "article" | "main" | "section") {
+        return false;
+    }
+
+    let siblings = dom::children(&parent);
+    if siblings.length() != 2 || parent.select("img, picture").length() != 1 {
+        return false;
+    }
+
+    let text = dom::text_content(el);
+    let text = text.trim();
+    !text.is_empty()
+        && text.chars().count() <= MAX_ADJACENT_CAPTION_LEN
+        && dom::text_content(&parent).trim() == text
+}
+
+// === Footnote Separation ===
+
+/// Maximum length of a footnote reference marker (`1`, `12`, `iv`, `*`).
+const MAX_FOOTNOTE_MARKER_LEN: usize = 4;
+
+/// Fragment-id parts that identify footnote targets (`#fn1`, `#cite_note-3`, `#footnote-2`).
+const FOOTNOTE_ID_PATTERNS: &[&str] = &["fn", "note", "ftn", "endnote"];
+
+/// Pair in-text footnote references with their definitions and remove the
+/// definitions from the document.
+///
+/// A reference is an `<a href="#id">` with a short marker that points at a
+/// single note: `role="doc-noteref"`, or a numbered footnote-like id
+/// (`#fn1`, `#footnote-2`, a numbered id from a `<sup>`), never the
+/// `#footnotes` list itself. With `inline_markers` its marker text is
+/// rewritten to `[marker]` so the text output keeps the reference readable.
+/// Returns `(marker, definition)` pairs in reference order, one per target.
+pub fn separate_footnotes(doc: &Document, inline_markers: bool) -> Vec<(String, String)> {
+    let mut footnotes: Vec<(String, String)> = Vec::new();
+    let mut seen_ids: Vec<String> = Vec::new();
+    let mut definitions: Vec<dom_query::NodeRef> = Vec::new();
+
+    for node in doc.select("body a[href^='#']").nodes() {
+        let link = Selection::from(*node);
+        let Some(id) = link.attr("href").and_then(|href| href.strip_prefix('#').map(|id| id.trim().to_string())) else {
+            continue;
+        };
+        let marker = dom::text_content(&link)
+            .trim()
+            .trim_start_matches('[')
+            .trim_end_matches(']')
+            .trim()
+            .to_string();
+        if !is_footnote_reference(&link, &id, &marker) {
+            continue;
+        }
+
+        // Repeated references to the same note keep the first pairing
+        if seen_ids.contains(&id) {
+            if inline_markers {
+                dom::set_inner_html(&link, &format!("[{marker}]"));
+            }
+            continue;
+        }
+
+        let Some(target) = find_footnote_target(doc, &id) else {
+            continue;
+        };
+        let definition = footnote_definition_text(&target);
+        if definition.is_empty() {
+            continue;
+        }
+
+        if inline_markers {
+            dom::set_inner_html(&link, &format!("[{marker}]"));
+        }
+        seen_ids.push(id);
+        footnotes.push((marker, definition));
+        if let Some(target_node) = target.nodes().first() {
+            definitions.push(*target_node);
+        }
+    }
+
+    for node in definitions {
+        dom::remove(&Selection::from(node));
+    }
+
+    footnotes
+}
+
+fn is_footnote_reference(link: &Selection, id: &str, marker: &str) -> bool {
+    if id.is_empty()
+        || id.contains(['"', '\\'])
+        || marker.is_empty()
+        || marker.chars().count() > MAX_FOOTNOTE_MARKER_LEN
+        || !marker.chars().all(|c| c.is_alphanumeric() || matches!(c, '*' | '\u{2020}' | '\u{2021}'))
+    {
+        return false;
+    }
+
+    if link.attr("role").is_some_and(|role| role.eq_ignore_ascii_case("doc-noteref")) {
+        return true;
+    }
+
+    // `#fn1` or `#footnote-2` name a single note; `#footnotes` or a bare
+    // `#fn` name the whole list
+    let id_lower = id.to_ascii_lowercase();
+    let mut names_note_list = false;
+    for pattern in FOOTNOTE_ID_PATTERNS {
+        for (index, _) in id_lower.match_indices(pattern) {
+            let rest = &id_lower[index + pattern.len()..];
+            if rest.starts_with('s') || rest.trim_start_matches(['-', '_', ':', '.']).is_empty() {
+                names_note_list = true;
+            } else {
+                return true;
+            }
+        }
+    }
+    !names_note_list && dom::tag_name(&link.parent()).as_deref() == Some("sup")
+}
+
+/// Resolve a fragment id to the element holding the footnote definition.
+///
+/// Empty anchors (`<li><a id="fn1"></a>Text</li>`) resolve to their parent.
+fn find_footnote_target<'a>(doc: &'a Document, id: &str) -> Option<Selection<'a>> {
+    let target = doc.select(&format!("body [id=\"{id}\"], body a[name=\"{id}\"]")).first();
+    if target.length() == 0 {
+        return None;
+    }
+    if dom::text_content(&target).trim().is_empty() {
+        let parent = target.parent();
+        let parent_tag = dom::tag_name(&parent).unwrap_or_default();
+        return (!matches!(parent_tag.as_str(), "body" | "html")).then_some(parent);
+    }
+    Some(target)
+}
+
+/// Definition text without back-reference links (`↩`, `^`).
+fn footnote_definition_text(target: &Selection) -> String {
+    let Some(root) = target.nodes().first().copied() else {
+        return String::new();
+    };
+
+    let mut text = String::new();
+    for node in root.descendants() {
+        if !node.is_text() {
+            continue;
+        }
+        let is_backref = node.ancestors(None).iter().take_while(|anc| anc.id != root.id).any(|anc| {
+            anc.node_name().is_some_and(|t| t.eq_ignore_ascii_case("a"))
+                && Selection::from(*anc).attr("href").is_some_and(|href| href.starts_with('#'))
+        });
+        if !is_backref {
+            text.push_str(&node.text());
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+// === Line Break Paragraphs ===
+
+/// Containers whose `<br><br>` runs are split into paragraphs.
+const BR_PARAGRAPH_CONTAINERS: &[&str] = &["div", "section", "article", "p"];
+
+/// Block-level tags that are never wrapped into a `<br><br>` paragraph.
+const BR_SEGMENT_BLOCK_TAGS: &[&str] = &[
+    "address", "article", "aside", "blockquote", "details", "div", "dl", "fieldset",
+    "figure", "footer", "form", "h1", "h2", "h3", "h4", "h5", "h6", "header", "hr",
+    "main", "nav", "ol", "p", "pre", "section", "table", "ul",
+];
+
+/// Move each table's `<caption>` to the front of the table.
+///
+/// The parser leaves a caption written after the rows where it was, but
+/// text, HTML, and Markdown output all present the caption as the table's
+/// title line, so it has to come first.
+pub fn hoist_table_captions(doc: &Document) {
+    for table in doc.select("table:has(> caption)").nodes() {
+        let Some(caption) = table.children().into_iter().find(|child| child.has_name("caption")) else {
+            continue;
+        };
+        if table.first_element_child().is_some_and(|first| first.id != caption.id) {
+            table.prepend_child(&caption);
+        }
+    }
+}
+
+/// Unwrap attribute-less `<div>`s whose only content is one container
+/// element (`<div><div><div><article>...`).
+///
+/// Such chains carry no class or id signals, but each level adds to the
+/// depth bonus in candidate scoring, letting a deeply wrapped block beat a
+/// shallower, better one. Wrappers with any attribute are kept, since
+/// selectors and boilerplate checks read them.
+pub fn collapse_wrapper_divs(doc: &Document) {
+    for node in doc.select("div").nodes() {
+        if !node.attrs().is_empty() {
+            continue;
+        }
+        let mut children = node.children().into_iter().filter(|child| !is_whitespace_text(child));
+        let (Some(only), None) = (children.next(), children.next()) else {
+            continue;
+        };
+        if ["div", "section", "article", "main"].iter().any(|tag| only.has_name(tag)) {
+            etree::strip(&Selection::from(*node));
+        }
+    }
+}
+
+/// Gutenberg blocks that are page or post chrome rather than content.
+const GUTENBERG_CHROME_BLOCKS: &str = ".wp-block-buttons, .wp-block-button, .wp-block-social-links, \
+    .wp-block-search, .wp-block-navigation, .wp-block-latest-posts, .wp-block-latest-comments, \
+    .wp-block-loginout, .wp-block-post-navigation-link, .wp-block-query-pagination, \
+    .wp-block-tag-cloud, .wp-block-post-terms";
+
+/// Gutenberg layout blocks, unwrapped to their content.
+const GUTENBERG_LAYOUT_BLOCKS: &str = "div.wp-block-group, div.wp-block-group__inner-container, \
+    div.wp-block-columns, div.wp-block-column, div.wp-block-cover__inner-container, \
+    div.wp-block-media-text__content";
+
+/// Gutenberg content blocks and the element each one stands for.
+const GUTENBERG_BLOCK_TAGS: &[(&str, &str)] = &[
+    ("wp-block-paragraph", "p"),
+    ("wp-block-heading", "h2"),
+    ("wp-block-list", "ul"),
+    ("wp-block-quote", "blockquote"),
+    ("wp-block-pullquote", "blockquote"),
+    ("wp-block-code", "pre"),
+    ("wp-block-preformatted", "pre"),
+    ("wp-block-verse", "pre"),
+    ("wp-block-table", "figure"),
+    ("wp-block-image", "figure"),
+];
+
+/// Whether the page was generated by WordPress (`<meta name="generator">`).
+pub fn is_wordpress(doc: &Document) -> bool {
+    doc.select("meta[name='generator' i]")
+        .iter()
+        .filter_map(|meta| meta.attr("content"))
+        .any(|content| content.trim_start().to_ascii_lowercase().starts_with("wordpress"))
+}
+
+/// Normalize WordPress block-editor (Gutenberg) markup.
+///
+/// Gutenberg marks block boundaries with `<!-- wp:... -->` comments, which
+/// cleaning drops, and with `wp-block-*` classes. Those classes are used
+/// here instead:
+///
+/// - Button, search, navigation, social-link, and latest-posts blocks are removed
+/// - Group and column wrappers are unwrapped
+/// - Content blocks get the element they stand for when the theme used
+///   another one (`<div class="wp-block-quote">` becomes `<blockquote>`), and
+///   code blocks get their `<code>`
+/// - Style tokens (`is-style-*`, `has-*`, `is-layout-*`) are dropped from
+///   content blocks so boilerplate checks don't misread them (`is-style-well`)
+pub fn normalize_gutenberg_blocks(doc: &Document) {
+    doc.select(GUTENBERG_CHROME_BLOCKS).remove();
+    for node in doc.select(GUTENBERG_LAYOUT_BLOCKS).nodes() {
+        etree::strip(&Selection::from(*node));
+    }
+
+    for (class, tag) in GUTENBERG_BLOCK_TAGS {
+        for node in doc.select(&format!(".{class}")).nodes() {
+            let block = Selection::from(*node);
+            let is_heading = node.node_name().is_some_and(|name| {
+                name.len() == 2 && name.starts_with('h') && name.as_bytes()[1].is_ascii_digit()
+            });
+            let keeps_tag = node.has_name(tag)
+                || (*tag == "h2" && is_heading)
+                || (*tag == "ul" && node.has_name("ol"))
+                || block.select(tag).length() > 0;
+            if !keeps_tag {
+                dom::rename(&block, tag);
+            }
+            if *tag == "pre" && *class == "wp-block-code" && block.select("code").length() == 0 {
+                let inner = dom::inner_html(&block);
+                dom::set_inner_html(&block, &format!("<code>{inner}</code>"));
+            }
+            dom::set_attribute(&block, "class", class);
+        }
+    }
+}
+
+/// Turn runs of two or more `<br>` into paragraph boundaries.
+///
+/// Legacy pages separate paragraphs with `<br><br>` instead of `<p>`. Each
+/// segment between such runs is wrapped in its own `<p>` (a `<p>` holding
+/// the runs is split into sibling paragraphs), so text, HTML, and Markdown
+/// output all see distinct paragraphs. Only runs directly inside block
+/// containers are split (inline elements, list items, and `<pre>` keep
+/// their breaks). Single `<br>` line breaks are kept, and segments
+/// containing block elements are left unwrapped.
+pub fn split_br_paragraphs(doc: &Document) {
+    let mut parents = Vec::new();
+    for node in doc.select("br").nodes() {
+        let Some(parent) = node.parent() else {
+            continue;
+        };
+        if !BR_PARAGRAPH_CONTAINERS.iter().any(|tag| parent.has_name(tag)) {
+            continue;
+        }
+        if parents.iter().any(|p: &dom_query::NodeRef| p.id == parent.id) {
+            continue;
+        }
+        let mut next = node.next_sibling();
+        while let Some(sibling) = next.as_ref().filter(|n| is_whitespace_text(n)) {
+            next = sibling.next_sibling();
+        }
+        if next.is_some_and(|n| n.has_name("br")) {
+            parents.push(parent);
+        }
+    }
+
+    for parent in parents {
+        // Split children into segments, dropping the `<br>` runs between them
+        let mut segments: Vec<Vec<dom_query::NodeRef>> = vec![Vec::new()];
+        let mut pending = Vec::new();
+        for child in parent.children() {
+            if child.has_name("br") || is_whitespace_text(&child) {
+                pending.push(child);
+                continue;
+            }
+            if pending.iter().filter(|n| n.has_name("br")).count() >= 2 {
+                pending.iter().for_each(dom_query::NodeRef::remove_from_parent);
+                segments.push(Vec::new());
+            } else if let Some(segment) = segments.last_mut() {
+                segment.append(&mut pending);
+            }
+            pending.clear();
+            if let Some(segment) = segments.last_mut() {
+                segment.push(child);
+            }
+        }
+        if pending.iter().filter(|n| n.has_name("br")).count() >= 2 {
+            pending.iter().for_each(dom_query::NodeRef::remove_from_parent);
+        } else if let Some(segment) = segments.last_mut() {
+            segment.append(&mut pending);
+        }
+        segments.retain(|segment| !segment.is_empty());
+        if segments.len() < 2 {
+            continue;
+        }
+
+        // Wrap each run of inline nodes; block elements stay where they are
+        let split_paragraph = parent.has_name("p");
+        for segment in segments {
+            let mut run = Vec::new();
+            for node in segment {
+                let is_block = node.is_element()
+                    && BR_SEGMENT_BLOCK_TAGS.iter().any(|tag| node.has_name(tag));
+                if is_block {
+                    wrap_br_segment(doc, &parent, split_paragraph, &mut run);
+                } else {
+                    run.push(node);
+                }
+            }
+            wrap_br_segment(doc, &parent, split_paragraph, &mut run);
+        }
+        if split_paragraph {
+            parent.remove_from_parent();
+        }
+    }
+}
+
+/// Move `run` into a new `<p>`, placed before the run (or before `parent`
+/// when a `<p>` is being split). Whitespace-only runs are left alone.
+fn wrap_br_segment<'a>(
+    doc: &'a Document,
+    parent: &dom_query::NodeRef<'a>,
+    split_paragraph: bool,
+    run: &mut Vec<dom_query::NodeRef<'a>>,
+) {
+    if run.iter().all(is_whitespace_text) {
+        run.clear();
+        return;
+    }
+    let paragraph = doc.tree.new_element("p");
+    if split_paragraph {
+        parent.insert_before(&paragraph);
+    } else {
+        run[0].insert_before(&paragraph);
+    }
+    for node in run.drain(..) {
+        paragraph.append_child(&node);
+    }
+}
+
+/// Whether a node is a text node holding only whitespace.
+fn is_whitespace_text(node: &dom_query::NodeRef) -> bool {
+    node.is_text() && node.text().trim().is_empty()
+}
+
 // === Link Density Functions ===
 // Note: link_density_test and link_density_test_tables are in src/link_density.rs
 // We use link_density_test_with_info for delete_by_link_density's backtracking logic.
@@ -443,6 +1397,9 @@ pub fn delete_by_link_density(
 
 /// Check if text contains enough characters
 ///
+/// True if any character is a letter or digit in any script (Latin, CJK,
+/// Arabic-Indic digits, ...); symbols, emoji, and punctuation don't count.
+///
 /// Go equivalent: `textCharsTest(text)`
 #[must_use]
 pub fn text_chars_test(text: &str) -> bool {
@@ -695,18 +1652,18 @@ fn is_image_file(src: &str) -> bool {
 ///
 /// Go equivalent: `duplicateTest(element, cache, opts)` in utils-extractor.go lines 136-149
 ///
-/// Returns `true` if this text has been seen more than `MAX_DUPLICATE_COUNT` times.
-fn duplicate_test(element: &Selection, cache: &mut LruCache) -> bool {
+/// Returns `true` if this text has been seen more than `opts.dedup_max_repeats` times.
+fn duplicate_test(element: &Selection, cache: &mut LruCache, opts: &Options) -> bool {
     let test_string = etree::iter_text(element, " ").trim().to_string();
 
     // Skip short text
-    if test_string.chars().count() <= MIN_DUPLICATE_CHECK_SIZE {
+    if test_string.chars().count() <= opts.dedup_min_length {
         return false;
     }
 
     // Check count and increment
     let count = cache.get(&test_string).unwrap_or(0);
-    let is_duplicate = count > MAX_DUPLICATE_COUNT;
+    let is_duplicate = count > opts.dedup_max_repeats;
 
     // Always increment the count
     cache.put(&test_string, count + 1);
@@ -714,6 +1671,71 @@ fn duplicate_test(element: &Selection, cache: &mut LruCache) -> bool {
     is_duplicate
 }
 
+/// Text blocks checked by `remove_duplicate_blocks`.
+const DEDUP_BLOCK_SELECTOR: &str = "p, li, blockquote, pre, h1, h2, h3, h4, h5, h6, td, th, dd, dt";
+
+/// Remove text blocks that repeat more than `opts.dedup_max_repeats` times.
+///
+/// Blocks are checked in document order against one cache for the whole
+/// extraction (sized by `opts.dedup_cache_size`), so the first occurrences
+/// survive. Only innermost blocks are checked, so a list item isn't counted
+/// again through the paragraph inside it.
+pub fn remove_duplicate_blocks(doc: &Document, opts: &Options) {
+    let mut cache = LruCache::new(opts.dedup_cache_size);
+    for block in doc.select(DEDUP_BLOCK_SELECTOR).iter() {
+        if block.select(DEDUP_BLOCK_SELECTOR).exists() {
+            continue;
+        }
+        if duplicate_test(&block, &mut cache, opts) {
+            block.remove();
+        }
+    }
+}
+
+/// Line prefixes of the "Tags: a, b" block closing many blog posts.
+const TAXONOMY_LINE_PREFIXES: &[&str] = &["tags:", "filed under:", "categories:", "posted in:"];
+
+/// Longest line (in chars) still treated as a taxonomy line.
+const MAX_TAXONOMY_LINE_CHARS: usize = 200;
+
+/// Text blocks checked by `remove_taxonomy_lines`.
+const TAXONOMY_BLOCK_SELECTOR: &str = "body p, body div, body li, body dd";
+
+/// Remove trailing taxonomy lines ("Tags: ...", "Filed under: ...").
+///
+/// Only innermost blocks that close their container are removed: every
+/// following sibling is empty or another taxonomy line. A paragraph that
+/// happens to start with "Categories:" mid-article is kept. Runs on the
+/// DOM so text, HTML and markdown output agree.
+pub fn remove_taxonomy_lines(doc: &Document) {
+    let mut removed = Vec::new();
+    let mut removed_ids = HashSet::new();
+    let blocks = doc.select(TAXONOMY_BLOCK_SELECTOR);
+    for node in blocks.nodes().iter().rev() {
+        if Selection::from(*node).select(TAXONOMY_BLOCK_SELECTOR).exists() {
+            continue;
+        }
+        let text = node.text();
+        let line = text.trim();
+        let is_taxonomy = line.chars().count() <= MAX_TAXONOMY_LINE_CHARS && {
+            let lower = line.to_lowercase();
+            TAXONOMY_LINE_PREFIXES.iter().any(|prefix| lower.starts_with(prefix))
+        };
+        if !is_taxonomy {
+            continue;
+        }
+        let is_trailing = std::iter::successors(node.next_sibling(), dom_query::NodeRef::next_sibling)
+            .all(|sibling| removed_ids.contains(&sibling.id) || sibling.text().trim().is_empty());
+        if is_trailing {
+            removed_ids.insert(node.id);
+            removed.push(*node);
+        }
+    }
+    for node in removed {
+        dom::remove(&Selection::from(node));
+    }
+}
+
 /// Clear all attributes from an element
 ///
 /// Go equivalent: `elem.Attr = nil` in html-processing.go
@@ -808,7 +1830,7 @@ pub fn process_node(element: &Selection, cache: Option<&mut LruCache>, opts: &Op
         // Deduplication check
         if opts.deduplicate {
             if let Some(cache) = cache {
-                if duplicate_test(element, cache) {
+                if duplicate_test(element, cache, opts) {
                     return false;
                 }
             }
@@ -896,7 +1918,7 @@ pub fn handle_text_node(
     // Deduplication check
     if opts.deduplicate {
         if let Some(cache) = cache {
-            if duplicate_test(node, cache) {
+            if duplicate_test(node, cache, opts) {
                 return false;
             }
         }
@@ -993,12 +2015,16 @@ pub fn convert_tags(tree: &Selection, opts: &Options) {
             let target = dom::get_attribute(&sel, "target")
                 .map(|t| t.trim().to_string())
                 .unwrap_or_default();
+            let title = dom::get_attribute(&sel, "title")
+                .filter(|_| opts.preserve_link_titles)
+                .map(|t| t.split_whitespace().collect::<Vec<_>>().join(" "))
+                .unwrap_or_default();
 
             // Clear all attributes
             clear_all_attributes(&sel);
 
             // Convert relative URL to absolute and set back
-            if !href.is_empty() {
+            if !href.is_empty() && url_utils::scheme_allowed(&href, &opts.allowed_link_schemes) {
                 let absolute_href = create_absolute_url(&href, opts.url.as_deref());
                 dom::set_attribute(&sel, "href", &absolute_href);
             }
@@ -1006,6 +2032,9 @@ pub fn convert_tags(tree: &Selection, opts: &Options) {
             if !target.is_empty() {
                 dom::set_attribute(&sel, "target", &target);
             }
+            if !title.is_empty() {
+                dom::set_attribute(&sel, "title", &title);
+            }
         }
     } else {
         // Delete links for faster processing (if not including links)
@@ -1030,6 +2059,11 @@ pub fn convert_tags(tree: &Selection, opts: &Options) {
         for node in tree.select("protected-a").nodes() {
             let sel = Selection::from(*node);
             dom::rename(&sel, "a");
+            if dom::get_attribute(&sel, "href")
+                .is_some_and(|href| !url_utils::scheme_allowed(&href, &opts.allowed_link_schemes))
+            {
+                dom::remove_attribute(&sel, "href");
+            }
         }
     }
 
@@ -1081,6 +2115,79 @@ mod tests {
 
     // Note: link_density_test* tests moved to src/link_density.rs
 
+    #[test]
+    fn test_long_alt_to_text_skips_short_alt() {
+        let long = "Line chart of unemployment from 2000 to 2020. It peaks at ten percent in 2009 and falls steadily to four percent by 2019, before rising again sharply in the final months of the series as lockdowns closed shops and offices.";
+        let doc = dom::parse(&format!(
+            r#"<body><p>Intro <img src="a.png" alt="{long}"></p><img src="b.png" alt="A photo. Of a cat."></body>"#
+        ));
+        long_alt_to_text(&doc);
+
+        let paragraphs: Vec<String> = doc.select("body > p").iter().map(|p| p.text().to_string()).collect();
+        assert_eq!(paragraphs, vec!["Intro ".to_string(), long.to_string()]);
+    }
+
+    #[test]
+    fn test_expand_shadow_roots_inlines_nested_templates() {
+        let doc = dom::parse(
+            r#"<body><x-post><template shadowrootmode="open"><h2>Title</h2><x-body><template shadowrootmode="open"><p>Inner text</p><slot>Fallback</slot></template></x-body></template><span>Light</span></x-post></body>"#,
+        );
+        expand_shadow_roots(&doc);
+
+        assert_eq!(doc.select("template").length(), 0);
+        assert_eq!(doc.select("slot").length(), 0);
+        assert_eq!(doc.select("x-post").text().to_string(), "TitleInner textFallbackLight");
+    }
+
+    #[test]
+    fn test_expand_shadow_roots_drops_fallback_of_filled_slots() {
+        let doc = dom::parse(
+            r#"<body><x-card><template shadowrootmode="open"><h2><slot name="title">Untitled</slot></h2><slot>No summary</slot><footer><slot name="byline">Anonymous</slot></footer></template><span slot="title">Harbour reopens</span><p>Crews returned on Monday.</p></x-card></body>"#,
+        );
+        expand_shadow_roots(&doc);
+
+        assert_eq!(doc.select("slot").length(), 0);
+        let text = doc.select("x-card").text().to_string();
+        assert!(!text.contains("Untitled") && !text.contains("No summary"), "{text}");
+        // Unfilled slots keep their fallback
+        assert!(text.contains("Anonymous"), "{text}");
+        assert!(text.contains("Harbour reopens") && text.contains("Crews returned on Monday."), "{text}");
+    }
+
+    #[test]
+    fn test_promote_noscript_images_removes_only_placeholders() {
+        let doc = dom::parse(
+            r#"<div id="lazy"><img src="data:image/gif;base64,R0lG" data-src="/a.jpg"><noscript><img src="/a.jpg"></noscript></div>
+            <div id="other"><img src="/chart.png"><noscript><img src="/b.jpg"></noscript></div>
+            <div id="pixel"><img src="/c.jpg"><noscript><img src="/track.gif" width="1" height="1"></noscript></div>"#,
+        );
+        promote_noscript_images(&doc);
+
+        let srcs = |id: &str| -> Vec<String> {
+            doc.select(&format!("#{id} > img")).iter().filter_map(|img| img.attr("src").map(|s| s.to_string())).collect()
+        };
+        assert_eq!(srcs("lazy"), vec!["/a.jpg"]);
+        assert_eq!(srcs("other"), vec!["/chart.png", "/b.jpg"]);
+        assert_eq!(doc.select("#pixel noscript").length(), 1);
+        assert_eq!(srcs("pixel"), vec!["/c.jpg"]);
+    }
+
+    #[test]
+    fn test_remove_icon_navigation_keeps_linked_images_and_text() {
+        let doc = dom::parse(
+            r#"<body><ul id="icons"><li><a href="/"><i class="fa fa-home"></i></a></li><li><a href="/s">🔍</a></li></ul>
+            <ul id="gallery"><li><a href="/1"><img src="1.jpg"></a></li><li><a href="/2"><img src="2.jpg"></a></li></ul>
+            <ul id="labels"><li><a href="/x">✉</a> Mail</li><li><a href="/y">☎</a></li></ul>
+            <div id="text"><a href="/x">✉</a> <a href="/y">☎</a></div></body>"#,
+        );
+        remove_icon_navigation(&doc);
+
+        assert!(!doc.select("#icons").exists());
+        assert!(doc.select("#gallery").exists());
+        assert!(doc.select("#labels").exists());
+        assert!(doc.select("#text").exists());
+    }
+
     #[test]
     fn test_text_chars_test() {
         assert!(text_chars_test("Hello world"));
@@ -1090,6 +2197,20 @@ mod tests {
         assert!(!text_chars_test("..."));
     }
 
+    #[test]
+    fn test_text_chars_test_other_scripts_and_symbols() {
+        // CJK, Arabic letters, Arabic-Indic and Devanagari digits count as text
+        assert!(text_chars_test("第三章"));
+        assert!(text_chars_test("مرحبا"));
+        assert!(text_chars_test("٣ ١٢"));
+        assert!(text_chars_test("१२३"));
+        // Dividers, box drawing, and emoji do not
+        assert!(!text_chars_test("✦ ✦ ✦"));
+        assert!(!text_chars_test("═══════"));
+        assert!(!text_chars_test("🌊🌊🌊 🐚"));
+        assert!(!text_chars_test("* * *"));
+    }
+
     #[test]
     fn test_process_node() {
         let doc = dom::parse("<p>  Text content  </p>");
@@ -1129,6 +2250,55 @@ mod tests {
 
     // === Document Cleaning Tests ===
 
+    #[test]
+    fn test_split_br_paragraphs_wraps_segments() {
+        let doc = dom::parse("<div><h2>Title</h2>First <b>one</b><br>line two<br><br>\n<br>Second<br><br>Third<br><br></div>");
+
+        split_br_paragraphs(&doc);
+
+        let paragraphs: Vec<String> = doc.select("div > p").iter().map(|p| p.inner_html().to_string()).collect();
+        assert_eq!(paragraphs, vec!["First <b>one</b><br>line two", "Second", "Third"]);
+        assert_eq!(doc.select("div > h2").length(), 1);
+        assert_eq!(doc.select("br").length(), 1);
+    }
+
+    #[test]
+    fn test_split_br_paragraphs_splits_paragraph_and_skips_pre() {
+        let doc = dom::parse("<div><p>One<br><br>Two</p><pre>a<br><br>b</pre><span>Only<br>single</span></div>");
+
+        split_br_paragraphs(&doc);
+
+        let paragraphs: Vec<String> = doc.select("p").iter().map(|p| p.text().to_string()).collect();
+        assert_eq!(paragraphs, vec!["One", "Two"]);
+        assert_eq!(doc.select("pre br").length(), 2);
+        assert_eq!(doc.select("span br").length(), 1);
+    }
+
+    #[test]
+    fn test_split_br_paragraphs_leaves_inline_and_list_items() {
+        let doc = dom::parse("<div><span>One<br><br>Two</span><ul><li>Three<br><br>Four</li></ul></div>");
+
+        split_br_paragraphs(&doc);
+
+        assert_eq!(doc.select("p").length(), 0);
+        assert_eq!(doc.select("span br").length(), 2);
+        assert_eq!(doc.select("li br").length(), 2);
+    }
+
+    #[test]
+    fn test_collapse_wrapper_divs_keeps_attributed_wrappers() {
+        let doc = dom::parse(
+            r#"<div><div> <div class="entry-content"><div><article><p>Body</p></article></div></div> </div></div><div><p>Lone paragraph</p></div>"#,
+        );
+
+        collapse_wrapper_divs(&doc);
+
+        assert_eq!(
+            doc.select("body").inner_html().trim(),
+            r#"<div class="entry-content"><article><p>Body</p></article></div> <div><p>Lone paragraph</p></div>"#
+        );
+    }
+
     #[test]
     fn test_doc_cleaning_removes_script() {
         let doc = dom::parse("<div><script>alert(1)</script><p>Content</p></div>");
@@ -1163,6 +2333,65 @@ mod tests {
         assert_eq!(doc.select("p").length(), 1);
     }
 
+    #[test]
+    fn test_doc_cleaning_removes_editor_artifacts() {
+        let doc = dom::parse(concat!(
+            r#"<div class="mce-tinymce mce-container"><div class="mce-toolbar">Bold Italic</div></div>"#,
+            r#"<div class="mce-content-body" contenteditable="true">"#,
+            r#"<p data-mce-style="color: red">Story text</p>"#,
+            r#"<span data-mce-bogus="all">caret</span>"#,
+            r#"<p>Type here...</p>"#,
+            r#"<p data-placeholder="Add a caption"></p>"#,
+            r#"</div>"#,
+            r#"<div class="cke_top"><span class="cke_toolbox">Toolbar</span></div>"#,
+            r#"<div class="ck-toolbar">Heading</div>"#,
+        ));
+        let opts = Options::default();
+
+        doc_cleaning(&doc, &opts);
+
+        let text = doc.select("body").text();
+        assert!(text.contains("Story text"));
+        assert!(!text.contains("Bold Italic"));
+        assert!(!text.contains("caret"));
+        assert!(!text.contains("Type here"));
+        assert!(!text.contains("Toolbar"));
+        assert!(!text.contains("Heading"));
+        assert_eq!(doc.select(".mce-content-body").length(), 1);
+        assert_eq!(doc.select("[contenteditable]").length(), 0);
+        assert_eq!(doc.select("[data-placeholder]").length(), 0);
+    }
+
+    #[test]
+    fn test_doc_cleaning_keeps_placeholder_images() {
+        let doc = dom::parse(concat!(
+            r#"<figure><img src="/photos/harbour.jpg" data-placeholder="blur"></figure>"#,
+            r#"<div contenteditable="true"><img src="/photos/quay.jpg" data-placeholder="blur">"#,
+            r#"<p data-placeholder="Add a caption"></p></div>"#,
+            r#"<p data-placeholder="Comments">Comments</p>"#,
+        ));
+        let opts = Options {
+            include_images: true,
+            ..Options::default()
+        };
+
+        doc_cleaning(&doc, &opts);
+
+        assert_eq!(doc.select("img").length(), 2);
+        // Only the placeholder block inside the editable region goes
+        assert_eq!(doc.select("p[data-placeholder]").length(), 1);
+    }
+
+    #[test]
+    fn test_doc_cleaning_keeps_non_editor_classes() {
+        let doc = dom::parse(r#"<div class="commerce-header block-intro"><p>Type here to search the catalogue of parts.</p></div>"#);
+        let opts = Options::default();
+
+        doc_cleaning(&doc, &opts);
+
+        assert!(doc.select("body").text().contains("Type here to search"));
+    }
+
     #[test]
     fn test_prune_html_removes_empty() {
         let doc = dom::parse("<div><p></p><p>Content</p></div>");
@@ -1175,22 +2404,157 @@ mod tests {
         assert_eq!(doc.select("p").text().to_string(), "Content");
     }
 
+    #[test]
+    fn test_separate_captions_by_class_and_figcaption() {
+        let doc = dom::parse(r#"<html><body><article>
+            <p>Body paragraph.</p>
+            <div class="wp-caption"><img src="a.jpg"><p class="wp-caption-text">The harbour at dawn.</p></div>
+            <figure><img src="b.jpg"><figcaption>Fishing boats returning.</figcaption></figure>
+            <p class="photo-credit">Photo: Jane Doe</p>
+        </article></body></html>"#);
+
+        let captions = separate_captions(&doc);
+
+        assert_eq!(
+            captions,
+            vec!["The harbour at dawn.", "Fishing boats returning.", "Photo: Jane Doe"]
+        );
+        // Images and body text survive, caption text is gone
+        assert_eq!(doc.select("img").length(), 2);
+        assert_eq!(doc.select("figcaption").length(), 0);
+        assert!(doc.select("article").text().contains("Body paragraph."));
+        assert!(!doc.select("article").text().contains("Jane Doe"));
+    }
+
+    #[test]
+    fn test_separate_captions_matches_whole_class_tokens() {
+        let doc = dom::parse(r#"<html><body><article>
+            <form class="credit-card"><label>Card number</label></form>
+            <div class="captionless">Body paragraph.</div>
+            <span class="Caption">Harbour at dawn.</span>
+        </article></body></html>"#);
+
+        let captions = separate_captions(&doc);
+
+        assert_eq!(captions, vec!["Harbour at dawn."]);
+        assert_eq!(doc.select("form.credit-card").length(), 1);
+        assert_eq!(doc.select("div.captionless").length(), 1);
+    }
+
+    #[test]
+    fn test_separate_captions_adjacent_to_image() {
+        let doc = dom::parse(r#"<html><body><article>
+            <div><img src="a.jpg"><span>A crowd gathers outside the courthouse.</span></div>
+            <div><p>First paragraph of the story.</p><p>Second paragraph of the story.</p></div>
+        </article></body></html>"#);
+
+        mark_image_captions(&doc);
+        // Doc cleaning strips images; the marks must survive it
+        doc.select("img").remove();
+        let captions = separate_captions(&doc);
+
+        assert_eq!(captions, vec!["A crowd gathers outside the courthouse."]);
+        assert_eq!(doc.select("p").length(), 2);
+    }
+
+    #[test]
+    fn test_separate_footnotes_resolves_empty_anchor_targets() {
+        let doc = dom::parse(r##"<html><body><article>
+            <p>A claim that needs a source.<a href="#note-a">*</a></p>
+            <p>A link to <a href="#top">the top</a> is not a footnote.</p>
+            <div><p><a name="note-a"></a>Source for the claim.</p></div>
+        </article></body></html>"##);
+
+        let footnotes = separate_footnotes(&doc, true);
+
+        assert_eq!(footnotes, vec![("*".to_string(), "Source for the claim.".to_string())]);
+        assert!(doc.select("body").text().contains("source.[*]"));
+        assert!(!doc.select("body").text().contains("Source for the claim"));
+    }
+
+    #[test]
+    fn test_separate_footnotes_ignores_links_to_the_note_list() {
+        let doc = dom::parse(r##"<html><body><article>
+            <p>First claim.<sup><a href="#footnotes">1</a></sup> Second claim.<a href="#fn2" role="doc-noteref">2</a></p>
+            <ol id="footnotes"><li id="fn1">First source.</li><li id="fn2">Second source.</li></ol>
+        </article></body></html>"##);
+
+        let footnotes = separate_footnotes(&doc, false);
+
+        assert_eq!(footnotes, vec![("2".to_string(), "Second source.".to_string())]);
+        assert!(doc.select("body").text().contains("Second claim.2"));
+        assert!(doc.select("#fn1").exists());
+    }
+
     #[test]
     fn test_post_cleaning_removes_class() {
         let doc = dom::parse(r##"<div><p class="article" id="main">Text</p></div>"##);
 
-        post_cleaning(&doc);
+        post_cleaning(&doc, &Options::default());
 
         let p = doc.select("p");
         assert!(dom::get_attribute(&p, "class").is_none());
         assert!(dom::get_attribute(&p, "id").is_none());
     }
 
+    #[test]
+    fn test_post_cleaning_preserves_class_and_id_when_enabled() {
+        let doc = dom::parse(
+            r##"<div><p class="article" id="main" style="color:red" onclick="go()">Text</p></div>"##,
+        );
+        let options = Options {
+            preserve_content_attributes: true,
+            ..Options::default()
+        };
+
+        post_cleaning(&doc, &options);
+
+        let p = doc.select("p");
+        assert_eq!(dom::get_attribute(&p, "class").as_deref(), Some("article"));
+        assert_eq!(dom::get_attribute(&p, "id").as_deref(), Some("main"));
+        assert!(dom::get_attribute(&p, "style").is_none());
+        assert!(dom::get_attribute(&p, "onclick").is_none());
+    }
+
+    #[test]
+    fn test_normalize_gutenberg_blocks() {
+        let doc = dom::parse(
+            r#"<head><meta name="generator" content="WordPress 6.5"></head><div class="wp-block-columns">
+            <div class="wp-block-column"><p class="wp-block-paragraph has-large-font-size">One</p></div>
+            <div class="wp-block-column"><h3 class="wp-block-heading">Two</h3></div></div>
+            <div class="wp-block-social-links"><a href="https://x.com/a">X</a></div>"#,
+        );
+        assert!(is_wordpress(&doc));
+
+        normalize_gutenberg_blocks(&doc);
+
+        let body = doc.select("body").inner_html().split_whitespace().collect::<String>();
+        assert_eq!(body, r#"<pclass="wp-block-paragraph">One</p><h3class="wp-block-heading">Two</h3>"#);
+    }
+
+    #[test]
+    fn test_post_cleaning_keeps_link_target_ids_with_links() {
+        let doc = dom::parse(
+            r##"<div><a href="#usage">Usage</a><h2 id="usage">Usage</h2><h2 id="other">Other</h2></div>"##,
+        );
+        let options = Options {
+            include_links: true,
+            ..Options::default()
+        };
+
+        mark_link_targets(&doc);
+        post_cleaning(&doc, &options);
+
+        assert_eq!(dom::get_attribute(&doc.select("h2").first(), "id").as_deref(), Some("usage"));
+        assert!(dom::get_attribute(&doc.select("h2").last(), "id").is_none());
+        assert!(dom::get_attribute(&doc.select("h2").first(), LINK_TARGET_ATTR).is_none());
+    }
+
     #[test]
     fn test_post_cleaning_keeps_href() {
         let doc = dom::parse(r##"<div><a href="http://example.com" class="link">Link</a></div>"##);
 
-        post_cleaning(&doc);
+        post_cleaning(&doc, &Options::default());
 
         let a = doc.select("a");
         assert!(dom::get_attribute(&a, "href").is_some());
@@ -1201,7 +2565,7 @@ mod tests {
     fn test_post_cleaning_removes_empty_nodes() {
         let doc = dom::parse("<div><p></p><span></span><p>Content</p></div>");
 
-        post_cleaning(&doc);
+        post_cleaning(&doc, &Options::default());
 
         // Empty p and span should be stripped
         assert_eq!(doc.select("p").length(), 1);
@@ -1214,7 +2578,7 @@ mod tests {
     fn test_post_cleaning_keeps_void_elements() {
         let doc = dom::parse(r##"<div><br><img src="test.jpg"><p>Content</p></div>"##);
 
-        post_cleaning(&doc);
+        post_cleaning(&doc, &Options::default());
 
         // Void elements should be preserved even though they're "empty"
         assert_eq!(doc.select("br").length(), 1);
@@ -1482,6 +2846,23 @@ mod tests {
         assert_eq!(href, Some("https://example.com/page".to_string()));
     }
 
+    #[test]
+    fn test_convert_tags_drops_disallowed_schemes() {
+        for include_links in [true, false] {
+            let html = r#"<div><p><a href="javascript:alert(1)">Bad</a> <a href="tel:+15550100">Call</a></p></div>"#;
+            let doc = dom::parse(html);
+            let div = doc.select("div");
+            let mut opts = Options::default();
+            opts.include_links = include_links;
+
+            convert_tags(&div, &opts);
+
+            let links = div.select("a");
+            assert_eq!(dom::get_attribute(&links.first(), "href"), None);
+            assert_eq!(dom::get_attribute(&links.last(), "href"), Some("tel:+15550100".to_string()));
+        }
+    }
+
     #[test]
     fn test_convert_tags_code_detection_hljs() {
         let html = r#"<pre><span class="hljs-keyword">let</span> x = 1;</pre>"#;
@@ -1529,11 +2910,12 @@ mod tests {
         let doc = dom::parse("<p>Short</p>");
         let p = doc.select("p");
         let mut cache = LruCache::new(100);
+        let opts = Options::default();
 
         // Short text should never be considered duplicate
-        assert!(!duplicate_test(&p, &mut cache));
-        assert!(!duplicate_test(&p, &mut cache));
-        assert!(!duplicate_test(&p, &mut cache));
+        assert!(!duplicate_test(&p, &mut cache, &opts));
+        assert!(!duplicate_test(&p, &mut cache, &opts));
+        assert!(!duplicate_test(&p, &mut cache, &opts));
     }
 
     #[test]
@@ -1541,14 +2923,63 @@ mod tests {
         let doc = dom::parse("<p>This is a much longer text that exceeds the minimum duplicate check size threshold of one hundred characters for proper testing.</p>");
         let p = doc.select("p");
         let mut cache = LruCache::new(100);
+        let opts = Options::default();
+
+        // First 3 occurrences should pass (count <= dedup_max_repeats)
+        assert!(!duplicate_test(&p, &mut cache, &opts)); // count becomes 1
+        assert!(!duplicate_test(&p, &mut cache, &opts)); // count becomes 2
+        assert!(!duplicate_test(&p, &mut cache, &opts)); // count becomes 3, but check is count > 2, so still false
+
+        // Fourth occurrence should be duplicate (count=3 > dedup_max_repeats=2)
+        assert!(duplicate_test(&p, &mut cache, &opts)); // count becomes 4, check is 4 > 2 = true
+    }
+
+    #[test]
+    fn test_duplicate_test_respects_configured_thresholds() {
+        let doc = dom::parse("<p>Subscribe to our newsletter for weekly updates.</p>");
+        let p = doc.select("p");
+        let opts = Options {
+            dedup_min_length: 20,
+            dedup_max_repeats: 0,
+            ..Options::default()
+        };
+        let mut cache = LruCache::new(opts.dedup_cache_size);
+
+        assert!(!duplicate_test(&p, &mut cache, &opts));
+        assert!(duplicate_test(&p, &mut cache, &opts));
+    }
+
+    #[test]
+    fn test_dedup_cache_size_bounds_detection_window() {
+        // A repeated block separated by many distinct paragraphs: a small cache
+        // evicts it between occurrences, a large one keeps counting.
+        let repeated = "This boilerplate disclaimer is repeated after every section of the very long document and should be caught.";
+        let mut html = String::from("<div>");
+        for section in 0..4 {
+            for i in 0..20 {
+                html.push_str(&format!(
+                    "<p>Section {section} paragraph {i} has its own distinct text that is long enough to be tracked by the deduplication cache.</p>"
+                ));
+            }
+            html.push_str(&format!("<p class=\"repeat\">{repeated}</p>"));
+        }
+        html.push_str("</div>");
+        let doc = dom::parse(&html);
+
+        let count_duplicates = |opts: &Options| {
+            let mut cache = LruCache::new(opts.dedup_cache_size);
+            doc.select("p")
+                .nodes()
+                .iter()
+                .filter(|node| duplicate_test(&Selection::from(**node), &mut cache, opts))
+                .count()
+        };
 
-        // First 3 occurrences should pass (count <= MAX_DUPLICATE_COUNT)
-        assert!(!duplicate_test(&p, &mut cache)); // count becomes 1
-        assert!(!duplicate_test(&p, &mut cache)); // count becomes 2
-        assert!(!duplicate_test(&p, &mut cache)); // count becomes 3, but check is count > 2, so still false
+        let small = Options { deduplicate: true, dedup_cache_size: 10, dedup_max_repeats: 0, ..Options::default() };
+        let large = Options { deduplicate: true, dedup_cache_size: 1000, dedup_max_repeats: 0, ..Options::default() };
 
-        // Fourth occurrence should be duplicate (count=3 > MAX_DUPLICATE_COUNT=2)
-        assert!(duplicate_test(&p, &mut cache)); // count becomes 4, check is 4 > 2 = true
+        assert_eq!(count_duplicates(&small), 0);
+        assert_eq!(count_duplicates(&large), 3);
     }
 
     #[test]
@@ -0,0 +1,146 @@
+//! Per-paragraph language detection.
+//!
+//! A lightweight heuristic for mixed-language pages: the dominant script
+//! decides non-Latin languages, and stopword hits decide between common
+//! Latin-script languages. No model is loaded.
+
+use crate::result::LanguageSpan;
+
+/// Minimum stopword hits before a Latin-script paragraph is tagged.
+const MIN_STOPWORD_HITS: usize = 2;
+
+/// Frequent function words per language (ISO 639-1), lowercase.
+const STOPWORDS: &[(&str, &[&str])] = &[
+    ("en", &["the", "and", "of", "to", "is", "that", "it", "was", "for", "with", "are", "this", "be", "have", "not", "they", "but", "from", "which"]),
+    ("fr", &["le", "la", "les", "et", "des", "est", "une", "du", "que", "qui", "dans", "pour", "pas", "sur", "au", "avec", "ce", "nous", "sont"]),
+    ("de", &["der", "die", "das", "und", "ist", "nicht", "ein", "eine", "zu", "den", "mit", "von", "sich", "auch", "auf", "für", "dem", "wir", "wird"]),
+    ("es", &["el", "los", "las", "y", "es", "una", "por", "con", "para", "del", "como", "más", "pero", "está", "muy", "también", "lo", "sus"]),
+    ("it", &["di", "che", "è", "della", "per", "non", "sono", "gli", "anche", "questo", "nella", "delle", "alla", "essere", "ha", "più"]),
+    ("pt", &["os", "não", "uma", "do", "da", "em", "dos", "das", "são", "também", "mais", "ao", "pelo", "pela", "muito", "foi"]),
+    ("nl", &["het", "een", "en", "van", "niet", "dat", "op", "te", "zijn", "met", "voor", "ook", "maar", "wij", "wordt", "deze"]),
+];
+
+/// Splits `text` into paragraphs and tags each with its detected language.
+///
+/// Paragraphs are the non-empty lines of `text`; paragraphs whose language
+/// can't be determined get `language: None`.
+pub(crate) fn detect_language_spans(text: &str) -> Vec<LanguageSpan> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| LanguageSpan {
+            text: line.to_string(),
+            language: detect_language(line).map(str::to_string),
+        })
+        .collect()
+}
+
+/// Detects the language of a single paragraph.
+fn detect_language(text: &str) -> Option<&'static str> {
+    if let Some(lang) = dominant_script_language(text) {
+        return Some(lang);
+    }
+
+    let words: Vec<String> = text
+        .split(|c: char| !c.is_alphabetic())
+        .filter(|w| !w.is_empty())
+        .map(str::to_lowercase)
+        .collect();
+
+    let mut scores: Vec<(&str, usize)> = STOPWORDS
+        .iter()
+        .map(|(lang, stopwords)| {
+            let hits = words.iter().filter(|w| stopwords.contains(&w.as_str())).count();
+            (*lang, hits)
+        })
+        .collect();
+    scores.sort_by_key(|(_, hits)| std::cmp::Reverse(*hits));
+
+    match scores.as_slice() {
+        [(lang, best), (_, second), ..] if *best >= MIN_STOPWORD_HITS && best > second => Some(lang),
+        _ => None,
+    }
+}
+
+/// Language implied by a non-Latin script covering most of the letters.
+fn dominant_script_language(text: &str) -> Option<&'static str> {
+    let mut counts: Vec<(&'static str, usize)> = Vec::new();
+    let mut letters = 0usize;
+    let mut has_kana = false;
+    let mut has_ukrainian = false;
+
+    for c in text.chars().filter(|c| c.is_alphabetic()) {
+        letters += 1;
+        let lang = match c {
+            '\u{3040}'..='\u{30FF}' => {
+                has_kana = true;
+                "ja"
+            }
+            '\u{4E00}'..='\u{9FFF}' => "zh",
+            '\u{AC00}'..='\u{D7AF}' | '\u{1100}'..='\u{11FF}' => "ko",
+            '\u{0400}'..='\u{04FF}' => {
+                has_ukrainian |= matches!(c, 'і' | 'ї' | 'є' | 'ґ' | 'І' | 'Ї' | 'Є' | 'Ґ');
+                "ru"
+            }
+            '\u{0600}'..='\u{06FF}' => "ar",
+            '\u{0590}'..='\u{05FF}' => "he",
+            '\u{0370}'..='\u{03FF}' => "el",
+            '\u{0E00}'..='\u{0E7F}' => "th",
+            '\u{0900}'..='\u{097F}' => "hi",
+            _ => continue,
+        };
+        match counts.iter_mut().find(|(l, _)| *l == lang) {
+            Some((_, n)) => *n += 1,
+            None => counts.push((lang, 1)),
+        }
+    }
+
+    // Japanese mixes kanji with kana; kana alone is enough to tell it apart from Chinese
+    let non_latin: usize = counts.iter().map(|(_, n)| n).sum();
+    if letters == 0 || non_latin * 2 <= letters {
+        return None;
+    }
+    if has_kana {
+        return Some("ja");
+    }
+    let (lang, _) = counts.into_iter().max_by_key(|(_, n)| *n)?;
+    if lang == "ru" && has_ukrainian {
+        return Some("uk");
+    }
+    Some(lang)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_latin_languages_by_stopwords() {
+        assert_eq!(detect_language("The committee said that it was not ready for the vote."), Some("en"));
+        assert_eq!(detect_language("Nous ne sommes pas prêts pour le vote, a dit la ministre."), Some("fr"));
+        assert_eq!(detect_language("Die Regierung ist mit dem Vorschlag nicht einverstanden."), Some("de"));
+    }
+
+    #[test]
+    fn detects_non_latin_scripts() {
+        assert_eq!(detect_language("東京は日本の首都です。"), Some("ja"));
+        assert_eq!(detect_language("北京是中国的首都。"), Some("zh"));
+        assert_eq!(detect_language("Москва является столицей России."), Some("ru"));
+        assert_eq!(detect_language("Київ є столицею України."), Some("uk"));
+    }
+
+    #[test]
+    fn short_or_ambiguous_paragraphs_are_untagged() {
+        assert_eq!(detect_language("Photo: Reuters"), None);
+        assert_eq!(detect_language("42"), None);
+    }
+
+    #[test]
+    fn spans_follow_paragraph_lines() {
+        let spans = detect_language_spans("The minister said that it was final.\n\nLa décision est prise et elle ne sera pas modifiée.");
+
+        assert_eq!(spans.len(), 2);
+        assert_eq!(spans[0].language.as_deref(), Some("en"));
+        assert_eq!(spans[1].language.as_deref(), Some("fr"));
+    }
+}
@@ -42,9 +42,18 @@
 
 mod error;
 mod extract;
+mod fingerprint;
+mod language;
+mod liveblog;
+mod math;
+mod offsets;
 mod options;
 mod patterns;
+mod quality;
 mod result;
+mod scorer;
+mod tables;
+mod xhtml;
 
 /// Page type classification (URL heuristics, HTML signals, ML classifier).
 pub mod page_type;
@@ -80,8 +89,13 @@ pub(crate) mod link_density;
 
 // Public API - re-exports
 pub use error::{Error, Result};
-pub use options::Options;
-pub use result::{ExtractResult, ImageData, Metadata};
+pub use fingerprint::content_hash;
+pub use options::{LineEnding, Options, TrailingSlashPolicy};
+pub use result::{
+    EventData, ExtractResult, ExtractedTable, ExtractionStatus, ImageData, ImageSource,
+    LanguageSpan, LiveUpdate, Metadata, QualitySignals, Rating, VideoMetadata,
+};
+pub use scorer::{ContentScorer, DefaultScorer, ScoringContext};
 
 /// Extracts main content from an HTML document using default options.
 ///
@@ -140,6 +154,126 @@ pub fn extract_with_options(html: &str, options: &Options) -> Result<ExtractResu
     extract::extract_content(html, options)
 }
 
+/// Extracts main content and also returns the cleaned document.
+///
+/// The returned [`dom_query::Document`] is the page as extraction saw it
+/// before selecting the main content: boilerplate (navigation, scripts,
+/// ads, consent dialogs, footers outside the article, ...) has been removed
+/// and tags converted, as described for [`clean_html`]. Use it to run your
+/// own analysis on top of the crate's cleaning instead of re-implementing
+/// it. The [`ExtractResult`] is identical to [`extract_with_options`]'s.
+///
+/// # Example
+///
+/// ```rust
+/// use rs_trafilatura::{extract_with_cleaned_doc, Options};
+///
+/// let html = r#"<html><body><nav><a href="/">Home</a></nav>
+///     <article><p>Main content here.</p></article>
+///     <script>track();</script></body></html>"#;
+/// let (_result, cleaned) = extract_with_cleaned_doc(html, &Options::default())?;
+/// assert_eq!(cleaned.select("nav").length(), 0);
+/// assert!(cleaned.select("article").text().contains("Main content here."));
+/// # Ok::<(), rs_trafilatura::Error>(())
+/// ```
+#[allow(clippy::missing_errors_doc)]
+pub fn extract_with_cleaned_doc(html: &str, options: &Options) -> Result<(ExtractResult, dom_query::Document)> {
+    extract::extract_content_with_cleaned_doc(html, options)
+}
+
+/// Extracts only the comment section of an HTML document.
+///
+/// Skips metadata, page classification, and main content extraction, which
+/// makes it much cheaper than [`extract_with_options`] when only the
+/// comments are needed. Returns `(comments_text, comments_html)`, the same
+/// values [`ExtractResult`] carries with `include_comments`; both are `None`
+/// when no comment section is found. `include_comments` itself is ignored.
+///
+/// # Example
+///
+/// ```rust
+/// use rs_trafilatura::{extract_comments_only, Options};
+///
+/// let html = r#"<html><body><article><p>The article.</p></article>
+///     <div id="comments"><p>Great write-up, thanks for sharing these details with everyone.</p></div>
+///     </body></html>"#;
+/// let options = Options { min_output_comm_size: 1, ..Options::default() };
+/// let (text, _html) = extract_comments_only(html, &options)?;
+/// assert!(text.is_some_and(|text| text.contains("Great write-up")));
+/// # Ok::<(), rs_trafilatura::Error>(())
+/// ```
+#[allow(clippy::missing_errors_doc)]
+pub fn extract_comments_only(html: &str, options: &Options) -> Result<(Option<String>, Option<String>)> {
+    extract::extract_comments_only(html, options)
+}
+
+/// Counts the `<img>` elements in a document's main content node.
+///
+/// A cheap media-richness signal, e.g. for prioritizing crawl queues: the
+/// content node is located as in extraction, but nothing is cleaned,
+/// scored against fallbacks, or extracted. Returns 0 when no content node
+/// is found.
+///
+/// # Example
+///
+/// ```rust
+/// use rs_trafilatura::count_content_images;
+///
+/// let doc = dom_query::Document::from(r#"<html><body>
+///     <header><img src="/logo.png"></header>
+///     <article><p>Story</p><img src="/a.jpg"><img src="/b.jpg"></article>
+///     </body></html>"#);
+/// assert_eq!(count_content_images(&doc), 2);
+/// ```
+#[must_use]
+pub fn count_content_images(doc: &dom_query::Document) -> usize {
+    extract::count_content_images(doc)
+}
+
+/// Runs boilerplate cleaning on an HTML document without extracting content.
+///
+/// Returns the cleaned document as HTML, for callers that want the crate's
+/// cleaning but do their own content selection. The transformations are the
+/// ones [`extract_with_options`] applies before it looks for the main content:
+///
+/// - `<noscript>` blocks holding real content are unwrapped; consent notices are not
+/// - CMS editor toolbars and placeholder text are removed
+/// - Footers outside `<article>`/`<main>` are removed
+/// - Scripts, styles, navigation, forms, ads, and cookie/consent/modal dialogs
+///   are removed
+/// - Tables are removed unless `include_tables`; figures and pictures are
+///   removed unless `include_images`
+/// - Empty elements are pruned
+/// - Links are made absolute against `url` when `include_links`; otherwise
+///   links outside paragraphs, lists, and tables are unwrapped to their text
+/// - Link `href`s with a scheme outside `allowed_link_schemes` are removed
+/// - Highlighted `<pre>`/`<blockquote>` blocks are converted to `<code>`
+/// - With `preserve_math`, KaTeX/MathJax/LaTeX math becomes `<math>` and is kept
+///
+/// # Example
+///
+/// ```rust
+/// use rs_trafilatura::{clean_html, Options};
+///
+/// let html = r#"<html><body><nav><a href="/">Home</a></nav>
+///     <article><p>Main content here.</p></article>
+///     <script>track();</script></body></html>"#;
+/// let cleaned = clean_html(html, &Options::default());
+/// assert!(cleaned.contains("Main content here."));
+/// assert!(!cleaned.contains("<nav>"));
+/// assert!(!cleaned.contains("track()"));
+/// ```
+#[must_use]
+pub fn clean_html(html: &str, options: &Options) -> String {
+    let doc = dom::parse(html);
+    if options.preserve_math {
+        math::normalize_math(&doc);
+    }
+    html_processing::doc_cleaning(&doc, options);
+    html_processing::convert_tags(&doc.select("body"), options);
+    doc.html().to_string()
+}
+
 /// Extracts main content from HTML bytes with automatic encoding detection.
 ///
 /// This function accepts HTML as raw bytes, detects the character encoding
@@ -216,3 +350,44 @@ pub fn extract_bytes_with_options(html: &[u8], options: &Options) -> Result<Extr
     let html_str = encoding::transcode_to_utf8(html);
     extract_with_options(&html_str, options)
 }
+
+/// Fetch a page with a caller-supplied fetcher and extract it.
+///
+/// The crate has no HTTP client of its own; `fetch` receives `url` and
+/// returns the raw response body, which is decoded as in
+/// [`extract_bytes_with_options`]. `url` is used as `Options::url`, so
+/// relative metadata URLs (related links, author and video URLs) resolve
+/// against it.
+///
+/// # Errors
+///
+/// Returns `Error::FetchError` if the fetcher fails, otherwise the errors of
+/// [`extract_bytes_with_options`].
+///
+/// # Example
+///
+/// ```rust
+/// use rs_trafilatura::{extract_url, Options};
+///
+/// let fetch = |_url: &str| -> Result<Vec<u8>, std::io::Error> {
+///     Ok(b"<html><body><article><p>Fetched content</p></article></body></html>".to_vec())
+/// };
+/// let result = extract_url("https://example.com/post", fetch, &Options::default())?;
+/// assert_eq!(result.metadata.url.as_deref(), Some("https://example.com/post"));
+/// # Ok::<(), rs_trafilatura::Error>(())
+/// ```
+pub fn extract_url<F, E>(url: &str, fetch: F, options: &Options) -> Result<ExtractResult>
+where
+    F: Fn(&str) -> std::result::Result<Vec<u8>, E>,
+    E: std::fmt::Display,
+{
+    let body = fetch(url).map_err(|err| Error::FetchError {
+        url: url.to_string(),
+        message: err.to_string(),
+    })?;
+    let options = Options {
+        url: Some(url.to_string()),
+        ..options.clone()
+    };
+    extract_bytes_with_options(&body, &options)
+}
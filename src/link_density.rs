@@ -44,6 +44,18 @@ fn collect_link_info(links: &Selection) -> (usize, usize, usize) {
     (link_length, n_short_links, n_non_empty_links)
 }
 
+/// Share of the element's text that sits inside links, from 0.0 (no link
+/// text) to 1.0 (all link text). Empty elements have a density of 0.0.
+#[must_use]
+pub fn link_density(element: &Selection) -> f32 {
+    let text_length = element.text().trim().chars().count();
+    if text_length == 0 {
+        return 0.0;
+    }
+    let (link_length, _, _) = collect_link_info(&element.select("a"));
+    (link_length as f32 / text_length as f32).min(1.0)
+}
+
 /// Check whether sections will be removed because they're rich in links (probably boilerplate).
 ///
 /// Go equivalent: `linkDensityTest` (html-processing.go:246-306)
@@ -325,6 +337,15 @@ mod tests {
         assert!(!link_density_test(&p, &options));
     }
 
+    #[test]
+    fn test_link_density_ratio() {
+        let doc = Document::from(r#"<div><a href="/a">Home</a> <a href="/b">News</a> Welcome</div><p>No links</p>"#);
+
+        let density = link_density(&doc.select("div"));
+        assert!((density - 8.0 / 17.0).abs() < 1e-6, "{density}");
+        assert!(link_density(&doc.select("p")).abs() < f32::EPSILON);
+    }
+
     #[test]
     fn test_link_density_no_links() {
         let html = r#"<p>This paragraph has no links at all.</p>"#;
@@ -0,0 +1,164 @@
+//! Live-blog update entries.
+//!
+//! Live blogs publish a stream of timestamped updates rather than one
+//! article. Extraction still yields the whole stream as `content_text`;
+//! [`extract_live_updates`] additionally returns each update on its own,
+//! read from the uncleaned document since cleaning strips the timestamps.
+
+use std::collections::HashSet;
+
+use dom_query::{Document, NodeRef, Selection};
+
+use crate::dom;
+use crate::metadata::meta_tags::parse_meta_date;
+use crate::result::LiveUpdate;
+
+/// Class patterns of a single live-blog entry.
+const ENTRY_SELECTOR: &str = "[class*='live-update'], [class*='liveblog-entry'], \
+    [class*='liveblog-post'], [class*='liveblog-update'], [class*='live-blog-post'], \
+    [class*='live-blog-update'], [class*='post-update'], [class*='live-post'], [class*='lb-post']";
+
+/// Containers whose children are entries when they carry no entry class.
+const CONTAINER_SELECTOR: &str = "[class*='liveblog'], [class*='live-blog'], [id*='liveblog']";
+
+/// Classes and ids that mark a page as a live blog.
+const LIVE_BLOG_SELECTOR: &str = "[class*='liveblog'], [class*='live-blog'], [id*='liveblog'], [id*='live-blog']";
+
+/// Elements holding an entry's timestamp.
+const TIMESTAMP_SELECTOR: &str = "time, [class*='timestamp'], [class*='update-time'], [class*='entry-time']";
+
+/// Entry chrome left out of the update text.
+const ENTRY_CHROME_SELECTOR: &str = "script, style, button, [class*='share'], [class*='social']";
+
+/// Block elements whose text makes up an update.
+const BLOCK_SELECTOR: &str = "h1, h2, h3, h4, h5, h6, p, li, blockquote, figcaption";
+
+/// Fewest timestamped entries for a page to count as a live blog.
+const MIN_LIVE_UPDATES: usize = 2;
+
+/// Collect the timestamped updates of a live blog, in document order.
+///
+/// Entries are found by class (`live-update`, `liveblog-entry`,
+/// `post-update`, ...) or as the children of a `liveblog` container, and
+/// must each contain a timestamp element. Only pages marked as a live blog
+/// (a `liveblog` class or id, or `LiveBlogPosting` JSON-LD) are searched,
+/// and pages with fewer than two entries yield nothing.
+pub(crate) fn extract_live_updates(doc: &Document) -> Vec<LiveUpdate> {
+    if !is_live_blog(doc) {
+        return Vec::new();
+    }
+    let mut entries = outermost(doc.select(ENTRY_SELECTOR).nodes());
+    if entries.len() < MIN_LIVE_UPDATES {
+        entries = doc
+            .select(CONTAINER_SELECTOR)
+            .nodes()
+            .iter()
+            .map(NodeRef::element_children)
+            .find(|children| children.iter().filter(|child| has_timestamp(child)).count() >= MIN_LIVE_UPDATES)
+            .unwrap_or_default();
+    }
+
+    let updates: Vec<LiveUpdate> = entries
+        .iter()
+        .filter(|entry| has_timestamp(entry))
+        .filter_map(|entry| live_update(&Selection::from(*entry)))
+        .collect();
+    if updates.len() < MIN_LIVE_UPDATES {
+        return Vec::new();
+    }
+    updates
+}
+
+fn is_live_blog(doc: &Document) -> bool {
+    doc.select(LIVE_BLOG_SELECTOR).exists()
+        || doc
+            .select("script[type='application/ld+json']")
+            .iter()
+            .any(|script| script.text().contains("LiveBlogPosting"))
+}
+
+/// Drop matches nested inside another match (e.g. `live-update__body`
+/// inside `live-update`).
+fn outermost<'a>(nodes: &[NodeRef<'a>]) -> Vec<NodeRef<'a>> {
+    let ids: HashSet<_> = nodes.iter().map(|node| node.id).collect();
+    nodes
+        .iter()
+        .filter(|node| !node.ancestors_it(None).any(|ancestor| ids.contains(&ancestor.id)))
+        .copied()
+        .collect()
+}
+
+fn has_timestamp(entry: &NodeRef) -> bool {
+    Selection::from(*entry).select(TIMESTAMP_SELECTOR).length() > 0
+}
+
+fn live_update(entry: &Selection) -> Option<LiveUpdate> {
+    let stamp = entry.select(TIMESTAMP_SELECTOR).first();
+    let timestamp = stamp
+        .attr("datetime")
+        .and_then(|value| parse_meta_date(&value))
+        .or_else(|| parse_meta_date(&stamp.text()));
+
+    let clone = dom::clone_element(entry, true);
+    clone.select(TIMESTAMP_SELECTOR).remove();
+    clone.select(ENTRY_CHROME_SELECTOR).remove();
+
+    let blocks: Vec<String> = clone
+        .select(BLOCK_SELECTOR)
+        .iter()
+        .filter(|block| block.select(BLOCK_SELECTOR).length() == 0)
+        .map(|block| normalize_space(&block.text()))
+        .filter(|text| !text.is_empty())
+        .collect();
+    let text = if blocks.is_empty() {
+        normalize_space(&clone.select("body").text())
+    } else {
+        blocks.join("\n")
+    };
+
+    (!text.is_empty()).then_some(LiveUpdate { timestamp, text })
+}
+
+fn normalize_space(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_liveblog_container_children_without_entry_class() {
+        let doc = Document::from(
+            r#"<div class="liveblog-stream">
+                <div><time datetime="2024-05-01T09:00:00Z">09:00</time><p>Polls open.</p></div>
+                <div><time datetime="2024-05-01T10:30:00Z">10:30</time><p>Turnout is high.</p></div>
+            </div>"#,
+        );
+        let updates = extract_live_updates(&doc);
+
+        assert_eq!(updates.len(), 2);
+        assert_eq!(updates[1].text, "Turnout is high.");
+        assert_eq!(updates[1].timestamp.map(|t| t.to_rfc3339()).as_deref(), Some("2024-05-01T10:30:00+00:00"));
+    }
+
+    #[test]
+    fn test_single_timestamped_update_is_not_a_liveblog() {
+        let doc = Document::from(
+            r#"<article><p>Story.</p><div class="post-update"><time>Updated 10:00</time><p>Correction.</p></div></article>"#,
+        );
+        assert!(extract_live_updates(&doc).is_empty());
+    }
+
+    #[test]
+    fn test_updates_without_live_blog_signal_are_ignored() {
+        let entries = r#"<div class="post-update"><time>09:00</time><p>First.</p></div>
+            <div class="post-update"><time>10:00</time><p>Second.</p></div>"#;
+        assert!(extract_live_updates(&Document::from(format!("<article>{entries}</article>"))).is_empty());
+
+        let doc = Document::from(format!(
+            r#"<script type="application/ld+json">{{"@type": "LiveBlogPosting"}}</script><article>{entries}</article>"#
+        ));
+        assert_eq!(extract_live_updates(&doc).len(), 2);
+    }
+}
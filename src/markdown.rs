@@ -1,7 +1,9 @@
 //! Markdown processing utilities.
 //!
 //! This module provides utilities for generating clean Markdown output,
-//! including escaping special characters and converting HTML tables to GFM format.
+//! including escaping special characters, converting HTML tables to GFM format,
+//! flattening `<details>` sections into blockquotes, lifting table
+//! captions out as title lines, and carrying link titles through conversion.
 
 /// Characters that have special meaning in Markdown and need escaping.
 const MARKDOWN_SPECIAL_CHARS: &[char] = &['\\', '*', '_', '[', ']', '<', '>'];
@@ -278,6 +280,140 @@ pub fn post_process_markdown(markdown: &str) -> String {
     result
 }
 
+/// Rewrite `<details>`/`<summary>` sections so they survive Markdown conversion.
+///
+/// Markdown has no collapsible section, so each `<details>` becomes a
+/// blockquote opened by its summary in bold. Nested sections nest quotes:
+///
+/// ```text
+/// > **How do I reset my password?**
+/// >
+/// > Use the link on the sign-in page.
+/// ```
+#[must_use]
+pub fn details_to_blockquotes(html: &str) -> String {
+    use dom_query::Document;
+
+    if !html.contains("<details") {
+        return html.to_string();
+    }
+
+    let doc = Document::from(html);
+    for summary in doc.select("details > summary").iter() {
+        summary.set_html(format!("<strong>{}</strong>", summary.inner_html()));
+        summary.rename("p");
+    }
+    doc.select("details").rename("blockquote");
+
+    doc.select("body").inner_html().to_string()
+}
+
+/// Rewrite nested blockquote markers email-style, one `>` per level with
+/// no spaces between them (`> > text` becomes `>> text`).
+///
+/// Fenced code blocks are left alone.
+#[must_use]
+pub fn compact_quote_markers(markdown: &str) -> String {
+    let mut in_fence = false;
+    let lines: Vec<String> = markdown
+        .split('\n')
+        .map(|line| {
+            if line.trim_start().starts_with("```") {
+                in_fence = !in_fence;
+            }
+            if in_fence || !line.starts_with('>') {
+                return line.to_string();
+            }
+            let mut depth = 0;
+            let mut rest = line;
+            while let Some(after) = rest.strip_prefix('>') {
+                depth += 1;
+                rest = after.strip_prefix(' ').unwrap_or(after);
+            }
+            if rest.is_empty() {
+                ">".repeat(depth)
+            } else {
+                format!("{} {rest}", ">".repeat(depth))
+            }
+        })
+        .collect();
+    lines.join("\n")
+}
+
+/// Lift table `<caption>`s out as bold title paragraphs before their tables.
+///
+/// GFM tables have no caption syntax, and the converter drops `<caption>`,
+/// so the caption becomes a line of its own above the table:
+///
+/// ```text
+/// **Population by year**
+///
+/// | Year | Population |
+/// ```
+#[must_use]
+pub fn table_captions_to_titles(html: &str) -> String {
+    use dom_query::Document;
+
+    if !html.contains("<caption") {
+        return html.to_string();
+    }
+
+    let doc = Document::from(html);
+    for caption in doc.select("table > caption").iter() {
+        let title = format!("<p><strong>{}</strong></p>", caption.inner_html());
+        caption.parent().before_html(title);
+        caption.remove();
+    }
+
+    doc.select("body").inner_html().to_string()
+}
+
+/// Token appended to the `index`-th titled link's href during conversion;
+/// letters and digits only, so URL escaping leaves it alone.
+fn link_title_placeholder(index: usize) -> String {
+    format!("LINKTITLE{index}X")
+}
+
+/// Move each link's `title` attribute into a placeholder at the end of its
+/// href, since the converter only keeps the URL.
+///
+/// Returns the rewritten HTML and the titles, for [`restore_link_titles`].
+#[must_use]
+pub fn link_titles_to_placeholders(html: &str) -> (String, Vec<String>) {
+    use dom_query::Document;
+
+    if !html.contains(" title=") {
+        return (html.to_string(), Vec::new());
+    }
+
+    let doc = Document::from(html);
+    let mut titles = Vec::new();
+    for link in doc.select("a[href][title]").iter() {
+        let title = link.attr("title").unwrap_or_default().trim().to_string();
+        link.remove_attr("title");
+        if title.is_empty() {
+            continue;
+        }
+        let href = link.attr("href").unwrap_or_default();
+        link.set_attr("href", &format!("{href}{}", link_title_placeholder(titles.len())));
+        titles.push(title);
+    }
+    (doc.select("body").inner_html().to_string(), titles)
+}
+
+/// Turn the placeholders from [`link_titles_to_placeholders`] into
+/// `[text](url "title")` link titles.
+#[must_use]
+pub fn restore_link_titles(markdown: &str, titles: &[String]) -> String {
+    let mut out = markdown.to_string();
+    for (index, title) in titles.iter().enumerate() {
+        let token = link_title_placeholder(index);
+        let title = title.replace('\\', "\\\\").replace('"', "\\\"");
+        out = out.replace(&format!("{token})"), &format!(" \"{title}\")")).replace(&token, "");
+    }
+    out
+}
+
 /// Convert an HTML table to GitHub Flavored Markdown format.
 ///
 /// # Arguments
@@ -561,6 +697,53 @@ mod tests {
         assert!(result.contains("* Item 2"));
     }
 
+    // ============================================================================
+    // compact_quote_markers tests
+    #[test]
+    fn test_compact_quote_markers() {
+        let markdown = "Reply\n\n> Question\n>\n> > Original\n\n```\n> > kept\n```";
+        assert_eq!(
+            compact_quote_markers(markdown),
+            "Reply\n\n> Question\n>\n>> Original\n\n```\n> > kept\n```"
+        );
+    }
+
+    // details_to_blockquotes tests
+    // ============================================================================
+
+    #[test]
+    fn test_details_become_blockquotes() {
+        let html = "<details><summary>Question</summary><p>Answer</p></details>";
+        let result = details_to_blockquotes(html);
+        assert_eq!(result, "<blockquote><p><strong>Question</strong></p><p>Answer</p></blockquote>");
+    }
+
+    #[test]
+    fn test_table_caption_becomes_title_paragraph() {
+        let html = "<table><caption>Results</caption><tr><td>1</td></tr></table>";
+        assert_eq!(
+            table_captions_to_titles(html),
+            "<p><strong>Results</strong></p><table><tbody><tr><td>1</td></tr></tbody></table>"
+        );
+    }
+
+    #[test]
+    fn test_link_title_round_trips_through_placeholder() {
+        let (html, titles) = link_titles_to_placeholders(r#"<p><a href="/a" title="Say &quot;hi&quot;">A</a> <a href="/b">B</a></p>"#);
+        assert_eq!(titles, ["Say \"hi\""]);
+        assert_eq!(
+            restore_link_titles("[A](/aLINKTITLE0X) [B](/b)", &titles),
+            r#"[A](/a "Say \"hi\"") [B](/b)"#
+        );
+        assert!(html.contains("/aLINKTITLE0X"));
+    }
+
+    #[test]
+    fn test_html_without_details_is_unchanged() {
+        let html = "<p>No <summary>collapsible</summary> sections</p>";
+        assert_eq!(details_to_blockquotes(html), html);
+    }
+
     // ============================================================================
     // html_table_to_markdown tests
     // ============================================================================
@@ -0,0 +1,241 @@
+//! Math preservation for `Options::preserve_math`.
+//!
+//! Rendered math comes in several shapes: plain MathML, KaTeX (MathML plus a
+//! visual HTML copy), MathJax 2 (`<script type="math/tex">` sources next to
+//! rendered spans), MathJax 3 (`<mjx-container>` with assistive MathML), and
+//! raw LaTeX in `<span class="math">\(...\)</span>`. [`normalize_math`]
+//! rewrites all of them to `<math>` elements, carrying the LaTeX source as an
+//! `application/x-tex` annotation where one is known, so the rest of the
+//! pipeline only has to handle `<math>`.
+
+use dom_query::{Document, NodeRef, Selection};
+
+use crate::dom;
+use crate::extract::escape_html;
+
+/// MathML attributes kept in `content_html`; anything else (event handlers,
+/// links, styles) is dropped.
+const MATH_ATTRIBUTES: &[&str] = &[
+    "accent", "accentunder", "alttext", "columnalign", "depth", "display", "displaystyle",
+    "encoding", "fence", "form", "height", "largeop", "linethickness", "lspace", "mathsize",
+    "mathvariant", "movablelimits", "notation", "rowalign", "rspace", "scriptlevel",
+    "separator", "stretchy", "symmetric", "width", "xmlns",
+];
+
+/// Rendered MathJax 2 output, redundant once its `math/tex` source is kept.
+const MATHJAX2_RENDERED: &str = ".MathJax_Preview, .MathJax, .MathJax_Display, .MathJax_SVG, \
+    .MathJax_SVG_Display, .MathJax_CHTML, .MathJax_CHTML_Display";
+
+/// Rewrite KaTeX, MathJax, and raw-LaTeX math in `doc` to `<math>` elements.
+pub(crate) fn normalize_math(doc: &Document) {
+    // KaTeX: the MathML copy (with its TeX annotation) is kept, the visual
+    // HTML copy would flatten into garbage text
+    doc.select(".katex-html").remove();
+
+    // MathJax 2: the script holds the source, the spans are rendering
+    let tex_scripts = doc.select(r#"script[type^="math/tex"]"#);
+    if tex_scripts.length() > 0 {
+        doc.select(MATHJAX2_RENDERED).remove();
+        for script in tex_scripts.iter() {
+            let display = script.attr("type").is_some_and(|t| t.contains("mode=display"));
+            let tex = script.text();
+            dom::replace_with_html(&script, &tex_math_html(tex.trim(), display));
+        }
+    }
+
+    // MathJax 3: swap the container for its assistive MathML
+    for container in doc.select("mjx-container").iter() {
+        let math = container.select("math");
+        if math.length() == 0 {
+            continue;
+        }
+        if container.attr("display").as_deref() == Some("true") {
+            math.set_attr("display", "block");
+        }
+        let html = dom::outer_html(&math.first()).to_string();
+        dom::replace_with_html(&container, &html);
+    }
+
+    // Unrendered LaTeX source, e.g. Pandoc's `<span class="math inline">\(x\)</span>`
+    for span in doc.select("span.math, div.math").iter() {
+        if span.children().length() > 0 {
+            continue;
+        }
+        let text = span.text();
+        let Some((tex, delimited_display)) = strip_tex_delimiters(text.trim()) else {
+            continue;
+        };
+        let display = delimited_display || span.attr("class").is_some_and(|c| c.contains("display"));
+        dom::replace_with_html(&span, &tex_math_html(tex, display));
+    }
+}
+
+/// Strip `\(...\)`, `\[...\]`, `$$...$$`, or `$...$` from LaTeX source.
+/// Returns the inner TeX and whether the delimiters denote display math.
+fn strip_tex_delimiters(text: &str) -> Option<(&str, bool)> {
+    let (tex, display) = if let Some(inner) = text.strip_prefix("\\(").and_then(|t| t.strip_suffix("\\)")) {
+        (inner, false)
+    } else if let Some(inner) = text.strip_prefix("\\[").and_then(|t| t.strip_suffix("\\]")) {
+        (inner, true)
+    } else if let Some(inner) = text.strip_prefix("$$").and_then(|t| t.strip_suffix("$$")) {
+        (inner, true)
+    } else if let Some(inner) = text.strip_prefix('$').and_then(|t| t.strip_suffix('$')) {
+        (inner, false)
+    } else {
+        return None;
+    };
+    let tex = tex.trim();
+    (!tex.is_empty()).then_some((tex, display))
+}
+
+/// A `<math>` element carrying only a TeX annotation.
+fn tex_math_html(tex: &str, display: bool) -> String {
+    let display_attr = if display { r#" display="block""# } else { "" };
+    format!(
+        r#"<math{display_attr}><semantics><annotation encoding="application/x-tex">{}</annotation></semantics></math>"#,
+        escape_html(tex)
+    )
+}
+
+/// LaTeX source of a `<math>` element: its TeX annotation, else `alttext`.
+pub(crate) fn math_source(math: &Selection) -> Option<String> {
+    let annotation = math.select("annotation").iter().find(|a| {
+        a.attr("encoding").is_some_and(|e| e.to_ascii_lowercase().contains("tex"))
+    });
+    annotation
+        .map(|a| a.text().trim().to_string())
+        .or_else(|| math.attr("alttext").map(|alt| alt.trim().to_string()))
+        .filter(|source| !source.is_empty())
+}
+
+/// Whether a `<math>` element is display (block) math.
+pub(crate) fn is_display_math(math: &Selection) -> bool {
+    math.attr("display").is_some_and(|d| d.eq_ignore_ascii_case("block"))
+}
+
+/// Text rendering of a `<math>` element: `$tex$` (`$$tex$$` for display
+/// math), or `[math]` when no LaTeX source is available.
+pub(crate) fn math_to_text(math: &Selection) -> String {
+    match math_source(math) {
+        Some(tex) if is_display_math(math) => format!("$${tex}$$"),
+        Some(tex) => format!("${tex}$"),
+        None => "[math]".to_string(),
+    }
+}
+
+/// Serialize a `<math>` element for `content_html`, keeping only
+/// presentational MathML attributes and dropping `annotation-xml`.
+pub(crate) fn push_math_html(math: &Selection, out: &mut String) {
+    if let Some(node) = math.nodes().first() {
+        push_math_node(node, out);
+    }
+}
+
+fn push_math_node(node: &NodeRef, out: &mut String) {
+    if node.is_text() {
+        out.push_str(&escape_html(&node.text()));
+        return;
+    }
+    let Some(name) = node.node_name() else {
+        return;
+    };
+    let name = name.to_ascii_lowercase();
+    if name == "annotation-xml" {
+        return;
+    }
+
+    out.push('<');
+    out.push_str(&name);
+    for attr in node.attrs() {
+        let key = attr.name.local.to_ascii_lowercase();
+        if MATH_ATTRIBUTES.contains(&&*key) {
+            out.push(' ');
+            out.push_str(&key);
+            out.push_str("=\"");
+            out.push_str(&escape_html(&attr.value));
+            out.push('"');
+        }
+    }
+    out.push('>');
+    for child in node.children() {
+        if child.is_element() || child.is_text() {
+            push_math_node(&child, out);
+        }
+    }
+    out.push_str("</");
+    out.push_str(&name);
+    out.push('>');
+}
+
+/// Token standing in for the `index`-th formula during Markdown conversion;
+/// letters and digits only, so Markdown escaping leaves it alone.
+fn placeholder(index: usize) -> String {
+    format!("MATHPLACEHOLDER{index}X")
+}
+
+/// Replace each `<math>` in `html` with a placeholder token before Markdown
+/// conversion. Returns the rewritten HTML and the `$...$` text of each formula.
+pub(crate) fn math_to_placeholders(html: &str) -> (String, Vec<String>) {
+    if !html.contains("<math") {
+        return (html.to_string(), Vec::new());
+    }
+
+    let doc = Document::from(html);
+    let mut formulas = Vec::new();
+    for math in doc.select("math").iter() {
+        let token = placeholder(formulas.len());
+        formulas.push(math_to_text(&math));
+        if is_display_math(&math) {
+            dom::replace_with_html(&math, &format!("<p>{token}</p>"));
+        } else {
+            dom::replace_with_html(&math, &token);
+        }
+    }
+    (doc.select("body").inner_html().to_string(), formulas)
+}
+
+/// Put the formulas back in place of the tokens from [`math_to_placeholders`].
+pub(crate) fn restore_placeholders(markdown: &str, formulas: &[String]) -> String {
+    let mut out = markdown.to_string();
+    for (index, formula) in formulas.iter().enumerate() {
+        out = out.replace(&placeholder(index), formula);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_mathjax2_script() {
+        let doc = Document::from(
+            r#"<p>Energy <span class="MathJax">E=mc2</span><script type="math/tex">E = mc^2</script> holds.</p>"#,
+        );
+        normalize_math(&doc);
+
+        let math = doc.select("math");
+        assert_eq!(math.length(), 1);
+        assert_eq!(math_to_text(&math).as_str(), "$E = mc^2$");
+        assert!(!doc.select("body").text().contains("E=mc2"));
+    }
+
+    #[test]
+    fn test_normalize_raw_latex_span() {
+        let doc = Document::from(r#"<p><span class="math display">\[\int_0^1 x\,dx\]</span></p>"#);
+        normalize_math(&doc);
+
+        let math = doc.select("math");
+        assert!(is_display_math(&math));
+        assert_eq!(math_to_text(&math).as_str(), r"$$\int_0^1 x\,dx$$");
+    }
+
+    #[test]
+    fn test_math_html_drops_unsafe_attributes() {
+        let doc = Document::from(r#"<math display="block" onclick="x()"><mi href="javascript:x()">a</mi></math>"#);
+        let mut out = String::new();
+        push_math_html(&doc.select("math"), &mut out);
+
+        assert_eq!(out, r#"<math display="block"><mi>a</mi></math>"#);
+    }
+}
@@ -11,8 +11,10 @@ use std::sync::LazyLock;
 
 use crate::dom;
 use crate::etree;
-use crate::result::Metadata;
+use crate::result::{Metadata, Rating};
+use super::json_ld::{parse_rating_count, parse_rating_value};
 use crate::selector::{self, meta as meta_selectors};
+use crate::url_utils;
 use crate::Options;
 
 // ============================================================
@@ -55,6 +57,74 @@ static CC_PATTERN: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new(r"creativecommons\.org/licenses/([a-z-]+)/").expect("valid regex")
 });
 
+// ============================================================
+// MICROFORMATS EXTRACTION
+// ============================================================
+
+/// Extract title, author, and dates from an `h-entry` (or legacy `hentry`)
+/// microformat, common on IndieWeb blogs that carry no other metadata.
+///
+/// Only fills fields that are still empty.
+#[must_use]
+pub fn extract_dom_microformats(doc: &Document, original: Metadata, opts: &Options) -> Metadata {
+    let mut result = original;
+
+    let entry = doc.select(".h-entry, .hentry").first();
+    if !entry.exists() {
+        return result;
+    }
+
+    if result.title.is_none() {
+        // Skip the p-name of a nested h-card (the author's name)
+        let name = entry.select(".p-name, .entry-title").nodes().iter().copied().find(|node| {
+            !node.ancestors(None).iter().any(|anc| {
+                dom::get_attribute(&Selection::from(*anc), "class")
+                    .is_some_and(|class| class.split_whitespace().any(|token| matches!(token, "h-card" | "p-author")))
+            })
+        });
+        if let Some(name) = name {
+            let text = etree::iter_text(&Selection::from(name), " ").trim().to_string();
+            if !text.is_empty() {
+                result.title = Some(text);
+            }
+        }
+    }
+
+    if result.author.is_none() {
+        let author = entry.select(".p-author, .author").first();
+        if author.exists() {
+            // An h-card author carries its name in a nested p-name
+            let card_name = author.select(".p-name, .fn").first();
+            let elem = if card_name.exists() { card_name } else { author };
+            let text = extract_author_text(&elem);
+            if !text.is_empty() && super::meta_tags::validate_metadata_name(&text) {
+                result.author = normalize_author(&text, opts);
+            }
+        }
+    }
+
+    if result.date.is_none() {
+        result.date = microformat_date(&entry, ".dt-published, .published");
+    }
+    if result.date_modified.is_none() {
+        result.date_modified = microformat_date(&entry, ".dt-updated, .updated");
+    }
+
+    result
+}
+
+/// Parse the first `selector` match within `entry`, preferring its
+/// `datetime` attribute over its text.
+fn microformat_date(entry: &Selection, selector: &str) -> Option<chrono::DateTime<chrono::Utc>> {
+    let elem = entry.select(selector).first();
+    if !elem.exists() {
+        return None;
+    }
+    dom::get_attribute(&elem, "datetime")
+        .and_then(|value| super::meta_tags::parse_meta_date(&value))
+        .or_else(|| super::meta_tags::parse_meta_date(dom::text_content(&elem).trim()))
+}
+
 // ============================================================
 // TITLE EXTRACTION
 // ============================================================
@@ -146,12 +216,30 @@ pub fn extract_dom_title(doc: &Document, original: Metadata, _opts: &Options) ->
     if let Some(h1) = h1_text {
         if h1.len() > 5 {
             result.title = Some(h1);
+            return result;
         }
     }
 
+    // Titleless SPAs often only name the content region
+    result.title = content_region_label(doc);
+
     result
 }
 
+/// Generic landmark labels that say nothing about the page.
+const GENERIC_REGION_LABELS: &[&str] = &["main", "main content", "content", "page content", "primary", "article"];
+
+/// The `aria-label` of the page's `<main>`/`<article>` region, unless generic.
+fn content_region_label(doc: &Document) -> Option<String> {
+    doc.select(r#"main[aria-label], [role="main"][aria-label], article[aria-label]"#)
+        .iter()
+        .filter_map(|region| region.attr("aria-label"))
+        .map(|label| label.split_whitespace().collect::<Vec<_>>().join(" "))
+        .find(|label| {
+            (3..200).contains(&label.len()) && !GENERIC_REGION_LABELS.contains(&label.to_lowercase().as_str())
+        })
+}
+
 /// Extract the first substantial H1 text from the document.
 fn extract_first_h1(doc: &Document) -> Option<String> {
     for h1 in doc.select("h1").nodes() {
@@ -200,17 +288,35 @@ pub fn extract_dom_author(doc: &Document, original: Metadata, opts: &Options) ->
 
 /// Extract text from author element, handling nested structures.
 fn extract_author_text(elem: &Selection) -> String {
-    // Try direct text first
-    let text = etree::iter_text(elem, " ").trim().to_string();
-
-    // Clean up common prefixes
-    text
-        .strip_prefix("By ")
-        .or_else(|| text.strip_prefix("by "))
-        .or_else(|| text.strip_prefix("Written by "))
-        .unwrap_or(&text)
-        .trim()
-        .to_string()
+    let text = etree::iter_text(elem, " ");
+    strip_byline_prefix(text.trim()).to_string()
+}
+
+/// Byline lead-ins stripped from author names, lowercase.
+const AUTHOR_PREFIXES: &[&str] = &[
+    "written by", "posted by", "authored by", "reported by", "reporting by", "story by", "words by",
+    "author:", "by:", "by",
+];
+
+/// Words marking a byline segment as a role or affiliation, not a name.
+const AUTHOR_ROLE_WORDS: &[&str] = &[
+    "staff", "writer", "reporter", "editor", "correspondent", "contributor", "columnist",
+    "journalist", "producer", "photographer", "analyst", "intern", "fellow", "senior", "chief",
+];
+
+/// Strip "By"/"Written by"-style lead-ins (repeatedly, case-insensitively).
+fn strip_byline_prefix(text: &str) -> &str {
+    let mut name = text.trim();
+    while let Some(rest) = AUTHOR_PREFIXES.iter().find_map(|prefix| {
+        let head = name.get(..prefix.len())?;
+        let rest = &name[prefix.len()..];
+        // "By" must be its own word ("Byron" is a name)
+        let word_end = prefix.ends_with(':') || rest.starts_with(|c: char| c.is_whitespace() || c == ':');
+        (head.eq_ignore_ascii_case(prefix) && word_end).then_some(rest)
+    }) {
+        name = rest.trim_start_matches(|c: char| c.is_whitespace() || c == ':');
+    }
+    name
 }
 
 /// Normalize author names.
@@ -239,6 +345,43 @@ fn normalize_author(name: &str, _opts: &Options) -> Option<String> {
     }
 }
 
+/// Reduce a byline to the author name(s), for `clean_author_names`.
+///
+/// On top of [`normalize_author`], strips lead-ins and cuts trailing roles,
+/// affiliations, and dates ("Jane Smith, Staff Writer", "Jane Smith |
+/// Reuters", "Jane Smith, March 3, 2024"). Commas between names are kept
+/// ("Jane Smith, John Doe").
+pub(super) fn clean_author_name(byline: &str, opts: &Options) -> Option<String> {
+    let byline = strip_byline_prefix(byline);
+
+    // Affiliation after a pipe, bullet, or dash separator
+    let byline = [" | ", " \u{00b7} ", " \u{2022} ", " - ", " \u{2013} ", " \u{2014} "]
+        .iter()
+        .fold(byline, |byline, sep| byline.split(sep).next().unwrap_or(byline));
+
+    // Role and date segments go; emails are dropped per word first, since
+    // the email pattern would swallow a following comma
+    let names = byline
+        .split(',')
+        .map(|segment| {
+            segment
+                .split_whitespace()
+                .filter(|word| !word.contains('@'))
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .filter(|segment| {
+            let lower = segment.to_lowercase();
+            !segment.is_empty()
+                && !segment.chars().any(|c| c.is_ascii_digit())
+                && !lower.split_whitespace().any(|word| AUTHOR_ROLE_WORDS.contains(&word))
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    normalize_author(&names, opts)
+}
+
 // ============================================================
 // URL EXTRACTION
 // ============================================================
@@ -372,12 +515,16 @@ pub fn extract_dom_categories(doc: &Document, original: Metadata, _opts: &Option
 
 /// Extract tags from DOM.
 ///
+/// Microformat `rel="tag"` links are merged into tags from any source;
+/// the other DOM rules only apply when no tags were found yet.
+///
 /// Go equivalent: `extractDomTags(doc)` (lines 682-740)
 #[must_use]
 pub fn extract_dom_tags(doc: &Document, original: Metadata, _opts: &Options) -> Metadata {
     let mut result = original;
 
     if !result.tags.is_empty() {
+        merge_rel_tags(doc, &mut result.tags);
         return result;
     }
 
@@ -401,10 +548,33 @@ pub fn extract_dom_tags(doc: &Document, original: Metadata, _opts: &Options) ->
     if !tags.is_empty() {
         result.tags = clean_cat_tags(tags);
     }
+    merge_rel_tags(doc, &mut result.tags);
 
     result
 }
 
+/// `rel="tag"` links in the article's own header or footer; tag clouds in
+/// sidebars and footers of the page label other posts.
+const REL_TAG_SELECTOR: &str = r#"article header a[rel~="tag"], article footer a[rel~="tag"]"#;
+
+/// Append the text of the article's `<a rel="tag">` links not already in
+/// `tags` (compared case-insensitively).
+///
+/// WordPress marks category links `rel="category tag"`; those are skipped.
+fn merge_rel_tags(doc: &Document, tags: &mut Vec<String>) {
+    for link in doc.select(REL_TAG_SELECTOR).iter() {
+        let rel = link.attr("rel").unwrap_or_default().to_ascii_lowercase();
+        if rel.split_whitespace().any(|token| token == "category") {
+            continue;
+        }
+        let text = etree::iter_text(&link, " ");
+        let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+        if !text.is_empty() && text.len() < 100 && !tags.iter().any(|tag| tag.eq_ignore_ascii_case(&text)) {
+            tags.push(text);
+        }
+    }
+}
+
 /// Clean and normalize category/tag lists.
 ///
 /// Go equivalent: `cleanCatTags(catTags)` (lines 740-760)
@@ -512,6 +682,41 @@ pub fn extract_dom_date(doc: &Document, original: Metadata, _opts: &Options) ->
     result
 }
 
+/// Record the raw `datetime` value and the displayed text of the `<time>`
+/// element that shows the publication date, so callers get both without
+/// re-deriving one from the other.
+///
+/// The first `<time datetime>` whose value parses to the same day as
+/// `date` is used, whichever source `date` came from.
+#[must_use]
+pub fn extract_dom_date_display(doc: &Document, original: Metadata, _opts: &Options) -> Metadata {
+    let mut result = original;
+
+    let Some(date) = result.date else {
+        return result;
+    };
+    if result.date_iso.is_some() {
+        return result;
+    }
+
+    for node in doc.select("time[datetime]").nodes() {
+        let el = Selection::from(*node);
+        let Some(iso) = dom::get_attribute(&el, "datetime").map(|value| value.trim().to_string()) else {
+            continue;
+        };
+        let same_day = super::meta_tags::parse_meta_date(&iso).is_some_and(|parsed| parsed.date_naive() == date.date_naive());
+        if !same_day {
+            continue;
+        }
+        let display = dom::text_content(&el).split_whitespace().collect::<Vec<_>>().join(" ");
+        result.date_display = (!display.is_empty() && display != iso).then_some(display);
+        result.date_iso = Some(iso);
+        break;
+    }
+
+    result
+}
+
 // ============================================================
 // LICENSE EXTRACTION
 // ============================================================
@@ -563,10 +768,387 @@ pub fn extract_dom_license(doc: &Document, original: Metadata, _opts: &Options)
     result
 }
 
+// ============================================================
+// LOGO EXTRACTION
+// ============================================================
+
+/// Selectors for masthead logo images, most specific first.
+const LOGO_SELECTORS: &[&str] = &[
+    "header img[class*='logo']",
+    "header [class*='logo'] img",
+    "[class*='masthead'] img",
+    "[class*='site-logo'] img",
+    "[class*='navbar-brand'] img",
+    "img[class*='logo']",
+];
+
+/// Extract the site logo from a masthead `<img>`.
+///
+/// Fallback for pages without a JSON-LD publisher logo.
+#[must_use]
+pub fn extract_dom_logo(doc: &Document, original: Metadata, _opts: &Options) -> Metadata {
+    let mut result = original;
+
+    if result.logo.is_some() {
+        return result;
+    }
+
+    // One document walk for all selectors, then priority order among the hits.
+    let candidates = doc.select(&LOGO_SELECTORS.join(", "));
+    for selector in LOGO_SELECTORS {
+        for node in candidates.nodes().iter().filter(|node| node.is(selector)) {
+            let img = Selection::from(*node);
+            let src = dom::get_attribute(&img, "src")
+                .or_else(|| dom::get_attribute(&img, "data-src"))
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty() && !s.starts_with("data:"));
+            if let Some(src) = src {
+                result.logo = Some(src);
+                return result;
+            }
+        }
+    }
+
+    result
+}
+
+// ============================================================
+// RELATED LINKS EXTRACTION
+// ============================================================
+
+/// Selectors for "related articles" style widgets.
+const RELATED_BLOCK_SELECTORS: &str = "[class*='related'], [id*='related'], \
+    [class*='recommended'], [id*='recommended'], \
+    [class*='more-stories'], [id*='more-stories']";
+
+/// Collect link targets from related-article widgets.
+///
+/// These blocks are discarded as boilerplate during content extraction, so
+/// the links are gathered here from the uncleaned document. Relative hrefs
+/// are resolved against the page URL; fragments, `javascript:` and `mailto:`
+/// links are skipped.
+#[must_use]
+pub fn extract_dom_related_links(doc: &Document, original: Metadata, opts: &Options) -> Metadata {
+    let mut result = original;
+
+    if !opts.collect_related_links {
+        return result;
+    }
+
+    let base = result
+        .url
+        .as_deref()
+        .or(opts.url.as_deref())
+        .and_then(url_utils::parse_url);
+
+    for node in doc.select(RELATED_BLOCK_SELECTORS).select("a[href]").nodes() {
+        let Some(href) = dom::get_attribute(&Selection::from(*node), "href") else {
+            continue;
+        };
+        let href = href.trim();
+        if href.is_empty()
+            || href.starts_with('#')
+            || href.starts_with("javascript:")
+            || href.starts_with("mailto:")
+        {
+            continue;
+        }
+        let link = match &base {
+            Some(base) => url_utils::create_absolute_url(href, base),
+            None => href.to_string(),
+        };
+        if !result.related_links.contains(&link) {
+            result.related_links.push(link);
+        }
+    }
+
+    result
+}
+
+// ============================================================
+// SOCIAL PROFILE / FEED EXTRACTION
+// ============================================================
+
+/// Site chrome where publishers list their social profiles.
+const SOCIAL_BLOCK_SELECTORS: &str = "footer, header, [class*='footer'], [id*='footer'], \
+    [class*='social'], [id*='social']";
+
+/// Domains of the social networks whose profile links are collected.
+const SOCIAL_DOMAINS: &[&str] = &[
+    "twitter.com", "x.com", "facebook.com", "linkedin.com", "youtube.com", "instagram.com",
+    "tiktok.com", "pinterest.com", "threads.net", "bsky.app", "mastodon.social", "github.com",
+];
+
+/// URL fragments of share buttons and single posts rather than profiles.
+const NON_PROFILE_MARKERS: &[&str] = &[
+    "/intent/", "/share", "sharer", "sharearticle", "/status/", "/posts/", "/watch", "/pin/create",
+];
+
+/// Collect the publisher's social profile links and feed URL.
+///
+/// Profiles are `<a href>` targets on known social domains inside the
+/// header, footer, or a "social" block, which doc cleaning later discards.
+/// Share buttons and links to individual posts are skipped. The feed is
+/// the first RSS/Atom `<link rel="alternate">`. Only runs with
+/// `Options::collect_social_links`.
+#[must_use]
+pub fn extract_dom_social_links(doc: &Document, original: Metadata, opts: &Options) -> Metadata {
+    let mut result = original;
+
+    if !opts.collect_social_links {
+        return result;
+    }
+
+    let base = result
+        .url
+        .as_deref()
+        .or(opts.url.as_deref())
+        .and_then(url_utils::parse_url);
+
+    for node in doc.select(SOCIAL_BLOCK_SELECTORS).select("a[href]").nodes() {
+        let Some(href) = dom::get_attribute(&Selection::from(*node), "href") else {
+            continue;
+        };
+        let Some(link) = url_utils::parse_url(href.trim()) else {
+            continue;
+        };
+        let Some(host) = link.host_str().map(str::to_ascii_lowercase) else {
+            continue;
+        };
+        let host = host.strip_prefix("www.").or_else(|| host.strip_prefix("m.")).unwrap_or(&host);
+        let is_social = SOCIAL_DOMAINS
+            .iter()
+            .any(|domain| host == *domain || host.ends_with(&format!(".{domain}")));
+        let lower = link.as_str().to_ascii_lowercase();
+        if !is_social || link.path() == "/" || NON_PROFILE_MARKERS.iter().any(|marker| lower.contains(marker)) {
+            continue;
+        }
+        let link = link.to_string();
+        if !result.social_links.contains(&link) {
+            result.social_links.push(link);
+        }
+    }
+
+    if result.feed_url.is_none() {
+        let feeds = doc.select(
+            "link[rel~='alternate'][type='application/rss+xml'][href], \
+             link[rel~='alternate'][type='application/atom+xml'][href]",
+        );
+        result.feed_url = feeds.nodes().iter().find_map(|node| {
+            let href = dom::get_attribute(&Selection::from(*node), "href")?;
+            let href = href.trim();
+            if href.is_empty() {
+                return None;
+            }
+            Some(match &base {
+                Some(base) => url_utils::create_absolute_url(href, base),
+                None => href.to_string(),
+            })
+        });
+    }
+
+    result
+}
+
+// ============================================================
+// PRINT / PDF LINK EXTRACTION
+// ============================================================
+
+/// Selectors for a publisher's print version, most specific first.
+const PRINT_LINK_SELECTORS: &[&str] = &[
+    "link[rel~='alternate'][media='print']",
+    "a[rel~='print']",
+    "a[class*='print-link']",
+    "a[class*='print-version']",
+    "a[class*='printer-friendly']",
+    "a[class*='print-article']",
+];
+
+/// Selectors for a PDF version of the page, most specific first.
+const PDF_LINK_SELECTORS: &[&str] = &[
+    "meta[name='citation_pdf_url']",
+    "link[rel~='alternate'][type='application/pdf']",
+    "a[type='application/pdf']",
+    "a[class*='pdf-link']",
+    "a[class*='download-pdf']",
+];
+
+/// Extract links to the print and PDF versions of the page.
+///
+/// Both are usually cleaner sources than the page itself. Relative targets
+/// are resolved against the page URL; `javascript:` handlers (e.g.
+/// `window.print()` buttons) and fragments are skipped.
+#[must_use]
+pub fn extract_dom_print_links(doc: &Document, original: Metadata, opts: &Options) -> Metadata {
+    let mut result = original;
+
+    let base = result
+        .url
+        .as_deref()
+        .or(opts.url.as_deref())
+        .and_then(url_utils::parse_url);
+
+    if result.print_url.is_some() && result.pdf_url.is_some() {
+        return result;
+    }
+
+    // One document walk for both link kinds
+    let candidates = doc.select(&[PRINT_LINK_SELECTORS, PDF_LINK_SELECTORS].concat().join(", "));
+    if result.print_url.is_none() {
+        result.print_url = first_link_target(&candidates, PRINT_LINK_SELECTORS, base.as_ref());
+    }
+    if result.pdf_url.is_none() {
+        result.pdf_url = first_link_target(&candidates, PDF_LINK_SELECTORS, base.as_ref());
+    }
+
+    result
+}
+
+/// First usable `href` (or `content`, for `<meta>`) among `candidates`,
+/// trying `selectors` in priority order.
+fn first_link_target(candidates: &Selection, selectors: &[&str], base: Option<&url::Url>) -> Option<String> {
+    for selector in selectors {
+        for node in candidates.nodes().iter().filter(|node| node.is(selector)) {
+            let el = Selection::from(*node);
+            let Some(target) = dom::get_attribute(&el, "href").or_else(|| dom::get_attribute(&el, "content")) else {
+                continue;
+            };
+            let target = target.trim();
+            if target.is_empty() || target.starts_with('#') || target.starts_with("javascript:") {
+                continue;
+            }
+            return Some(match base {
+                Some(base) => url_utils::create_absolute_url(target, base),
+                None => target.to_string(),
+            });
+        }
+    }
+    None
+}
+
+// ============================================================
+// READING TIME EXTRACTION
+// ============================================================
+
+/// Selectors for publisher reading-time badges.
+const READING_TIME_SELECTORS: &str = "[class*='reading-time'], [class*='read-time'], \
+    [class*='readingTime'], [class*='reading_time'], [class*='time-to-read']";
+
+/// Longest text accepted as a reading-time badge.
+const MAX_READING_TIME_LEN: usize = 40;
+
+/// Extract a declared reading time from a reading-time element.
+///
+/// Fallback for pages without a Twitter card reading-time pair; the badge
+/// text must be short and contain a number (e.g. "4 min read").
+#[must_use]
+pub fn extract_dom_reading_time(doc: &Document, original: Metadata, _opts: &Options) -> Metadata {
+    let mut result = original;
+
+    if result.declared_reading_time.is_some() {
+        return result;
+    }
+
+    result.declared_reading_time = doc.select(READING_TIME_SELECTORS).nodes().iter().find_map(|node| {
+        let text = dom::text_content(&Selection::from(*node));
+        let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+        let is_badge = text.chars().count() <= MAX_READING_TIME_LEN && text.chars().any(|c| c.is_ascii_digit());
+        is_badge.then_some(text)
+    });
+
+    result
+}
+
+// ============================================================
+// RATING EXTRACTION
+// ============================================================
+
+/// Extract aggregate rating from `itemprop` microdata.
+///
+/// Fallback for pages without JSON-LD `AggregateRating`; reads `ratingValue`,
+/// `bestRating`, and `reviewCount`/`ratingCount` from `content` or element text.
+#[must_use]
+pub fn extract_dom_rating(doc: &Document, original: Metadata, _opts: &Options) -> Metadata {
+    let mut result = original;
+
+    if result.rating.is_some() {
+        return result;
+    }
+
+    let itemprop = |name: &str| {
+        doc.select(&format!("[itemprop='{name}']")).nodes().iter().find_map(|node| {
+            let elem = Selection::from(*node);
+            let text = dom::get_attribute(&elem, "content").unwrap_or_else(|| dom::text_content(&elem).to_string());
+            let text = text.trim().to_string();
+            (!text.is_empty()).then_some(text)
+        })
+    };
+
+    let Some(value) = itemprop("ratingValue").as_deref().and_then(parse_rating_value) else {
+        return result;
+    };
+
+    result.rating = Some(Rating {
+        value,
+        best: itemprop("bestRating").as_deref().and_then(parse_rating_value),
+        count: itemprop("reviewCount")
+            .or_else(|| itemprop("ratingCount"))
+            .as_deref()
+            .and_then(parse_rating_count),
+    });
+
+    result
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_extract_dom_date_display() {
+        let html = r#"<html><body>
+            <time datetime="2020-01-01">New Year</time>
+            <time datetime="2021-08-15">August 15, 2021</time>
+        </body></html>"#;
+        let doc = Document::from(html);
+        let metadata = Metadata { date: super::super::meta_tags::parse_meta_date("2021-08-15T09:30:00Z"), ..Metadata::default() };
+        let result = extract_dom_date_display(&doc, metadata, &Options::default());
+
+        assert_eq!(result.date_iso.as_deref(), Some("2021-08-15"));
+        assert_eq!(result.date_display.as_deref(), Some("August 15, 2021"));
+    }
+
+    #[test]
+    fn test_extract_dom_microformats() {
+        let html = r#"<html><body><article class="h-entry">
+            <a class="p-author h-card" href="/"><span class="p-name">Jane Doe</span></a>
+            <h1 class="p-name">Notes on gardening</h1>
+            <time class="dt-published" datetime="2023-04-05T10:00:00Z">April 5</time>
+            <time class="dt-updated" datetime="2023-05-01T08:00:00Z">May 1</time>
+            <div class="e-content"><p>Body</p></div>
+        </article></body></html>"#;
+        let doc = Document::from(html);
+        let result = extract_dom_microformats(&doc, Metadata::default(), &Options::default());
+
+        assert_eq!(result.title.as_deref(), Some("Notes on gardening"));
+        assert_eq!(result.author.as_deref(), Some("Jane Doe"));
+        assert_eq!(result.date.map(|d| d.to_rfc3339()).as_deref(), Some("2023-04-05T10:00:00+00:00"));
+        assert_eq!(result.date_modified.map(|d| d.to_rfc3339()).as_deref(), Some("2023-05-01T08:00:00+00:00"));
+    }
+
+    #[test]
+    fn test_extract_dom_reading_time() {
+        let html = r#"<html><body>
+            <div class="reading-time-wrapper"><span>Reading time varies by article and device.</span> Some more text here</div>
+            <span class="post-reading-time"> 4 min
+                read </span>
+        </body></html>"#;
+        let doc = Document::from(html);
+        let result = extract_dom_reading_time(&doc, Metadata::default(), &Options::default());
+
+        assert_eq!(result.declared_reading_time.as_deref(), Some("4 min read"));
+    }
+
     #[test]
     fn test_examine_title_element_simple() {
         let html = "<html><head><title>Article Title</title></head><body></body></html>";
@@ -620,6 +1202,22 @@ mod tests {
         assert_eq!(metadata.title, Some("Main Article Heading".to_string()));
     }
 
+    #[test]
+    fn test_extract_dom_title_from_region_label() {
+        let html = r#"<html><body>
+            <nav aria-label="Main"><a href="/">Home</a></nav>
+            <main aria-label="Quarterly Report"><p>Revenue grew in every region.</p></main>
+        </body></html>"#;
+
+        let doc = Document::from(html);
+        let metadata = extract_dom_title(&doc, Metadata::default(), &Options::default());
+        assert_eq!(metadata.title, Some("Quarterly Report".to_string()));
+
+        let generic = Document::from(r#"<html><body><main aria-label="Main content"><p>Text</p></main></body></html>"#);
+        let metadata = extract_dom_title(&generic, Metadata::default(), &Options::default());
+        assert_eq!(metadata.title, None);
+    }
+
     #[test]
     fn test_extract_dom_url_canonical() {
         let html = r#"<!DOCTYPE html>
@@ -682,6 +1280,50 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_clean_author_name() {
+        let clean = |byline: &str| clean_author_name(byline, &Options::default());
+
+        assert_eq!(clean("By Jane Smith").as_deref(), Some("Jane Smith"));
+        assert_eq!(clean("Jane Smith, Staff Writer").as_deref(), Some("Jane Smith"));
+        assert_eq!(clean("Written by: Jane Smith | Reuters").as_deref(), Some("Jane Smith"));
+        assert_eq!(clean("by Jane Smith jane@example.com, March 3, 2024").as_deref(), Some("Jane Smith"));
+        assert_eq!(clean("Jane Smith, John Doe").as_deref(), Some("Jane Smith, John Doe"));
+        assert_eq!(clean("Byron Katz").as_deref(), Some("Byron Katz"));
+        assert_eq!(clean("By Staff").as_deref(), None);
+    }
+
+    #[test]
+    fn test_extract_dom_tags_merges_rel_tag_links() {
+        let doc = crate::dom::parse(
+            r#"<html><body><article><p>Post body</p>
+            <footer>Filed under <a href="/c/news" rel="category tag">News</a>.
+            Tagged <a href="/t/rust" rel="tag">Rust</a>, <a href="/t/wasm" rel="tag">WebAssembly</a>.</footer>
+            </article></body></html>"#,
+        );
+        let original = Metadata {
+            tags: vec!["rust".to_string(), "Compilers".to_string()],
+            ..Metadata::default()
+        };
+
+        let result = extract_dom_tags(&doc, original, &Options::default());
+        assert_eq!(result.tags, vec!["rust", "Compilers", "WebAssembly"]);
+
+        let result = extract_dom_tags(&doc, Metadata::default(), &Options::default());
+        assert_eq!(result.tags, vec!["Rust", "WebAssembly"]);
+
+        let sidebar = crate::dom::parse(
+            r#"<html><body><article><p>Post body</p></article>
+            <aside>Popular: <a href="/t/go" rel="tag">Go</a></aside></body></html>"#,
+        );
+        let original = Metadata {
+            tags: vec!["rust".to_string()],
+            ..Metadata::default()
+        };
+        let result = extract_dom_tags(&sidebar, original, &Options::default());
+        assert_eq!(result.tags, vec!["rust"]);
+    }
+
     #[test]
     fn test_clean_cat_tags() {
         let input = vec![
@@ -691,4 +1333,78 @@ mod tests {
         let result = clean_cat_tags(input);
         assert_eq!(result, vec!["Technology", "Science", "Innovation"]);
     }
+
+    #[test]
+    fn test_extract_dom_rating_from_microdata() {
+        let html = r#"<html><body><div itemscope itemtype="https://schema.org/Product">
+            <div itemprop="aggregateRating" itemscope itemtype="https://schema.org/AggregateRating">
+                <meta itemprop="ratingValue" content="4,2">
+                <meta itemprop="bestRating" content="5">
+                <span itemprop="ratingCount">87</span> ratings
+            </div>
+        </div></body></html>"#;
+
+        let doc = Document::from(html);
+        let metadata = extract_dom_rating(&doc, Metadata::default(), &Options::default());
+
+        assert_eq!(
+            metadata.rating,
+            Some(Rating { value: 4.2, best: Some(5.0), count: Some(87) })
+        );
+    }
+
+    #[test]
+    fn test_extract_dom_logo_from_masthead() {
+        let html = r#"<html><body>
+            <header><a href="/"><img class="site-logo__img" src="/static/logo.svg" alt="Coastal Press"></a></header>
+            <article><img src="/photos/harbour.jpg"></article>
+        </body></html>"#;
+
+        let doc = Document::from(html);
+        let metadata = extract_dom_logo(&doc, Metadata::default(), &Options::default());
+
+        assert_eq!(metadata.logo.as_deref(), Some("/static/logo.svg"));
+    }
+
+    #[test]
+    fn test_extract_dom_print_links_skips_print_buttons() {
+        let html = r#"<html><head>
+            <link rel="alternate" type="application/pdf" href="/reports/budget.pdf">
+        </head><body>
+            <a class="print-link" href="javascript:window.print()">Print</a>
+            <a class="print-link" href="/news/budget?print=1">Printer-friendly version</a>
+        </body></html>"#;
+
+        let doc = Document::from(html);
+        let opts = Options { url: Some("https://example.com/news/budget".to_string()), ..Options::default() };
+        let metadata = extract_dom_print_links(&doc, Metadata::default(), &opts);
+
+        assert_eq!(metadata.print_url.as_deref(), Some("https://example.com/news/budget?print=1"));
+        assert_eq!(metadata.pdf_url.as_deref(), Some("https://example.com/reports/budget.pdf"));
+    }
+
+    #[test]
+    fn test_extract_dom_social_links_skips_share_buttons() {
+        let html = r#"<html><body>
+            <article><p>See <a href="https://twitter.com/someone/status/1">this post</a>.</p>
+            <div class="share"><a href="https://twitter.com/intent/tweet?url=x">Tweet</a>
+            <a href="https://www.facebook.com/sharer/sharer.php?u=x">Share</a></div></article>
+            <footer><ul class="social">
+                <li><a href="https://www.facebook.com/CoastalPress">Facebook</a></li>
+                <li><a href="https://www.linkedin.com/company/coastal-press/">LinkedIn</a></li>
+                <li><a href="https://www.facebook.com/CoastalPress">Like us</a></li>
+                <li><a href="https://twitter.com/">Twitter</a></li>
+            </ul></footer>
+        </body></html>"#;
+
+        let doc = Document::from(html);
+        let opts = Options { collect_social_links: true, ..Options::default() };
+        let metadata = extract_dom_social_links(&doc, Metadata::default(), &opts);
+
+        assert_eq!(
+            metadata.social_links,
+            ["https://www.facebook.com/CoastalPress", "https://www.linkedin.com/company/coastal-press/"]
+        );
+        assert!(metadata.feed_url.is_none());
+    }
 }
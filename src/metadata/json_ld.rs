@@ -6,7 +6,7 @@
 use dom_query::{Document, Selection};
 use serde_json::Value;
 use crate::dom;
-use crate::result::Metadata;
+use crate::result::{EventData, Metadata, Rating};
 use crate::Options;
 
 /// Schema data container with importance scoring.
@@ -24,6 +24,16 @@ pub struct SchemaData {
     pub parent: Option<Box<SchemaData>>,
 }
 
+/// Typed schemas found in a document, grouped by what they feed.
+#[derive(Debug, Default)]
+struct SchemaBuckets {
+    persons: Vec<SchemaData>,
+    organizations: Vec<SchemaData>,
+    articles: Vec<SchemaData>,
+    ratings: Vec<SchemaData>,
+    events: Vec<SchemaData>,
+}
+
 /// Extract metadata from JSON-LD scripts.
 ///
 /// Go equivalent: `extractJsonLd(opts, doc, originalMetadata)` (lines 23-91)
@@ -40,7 +50,7 @@ pub fn extract_json_ld(doc: &Document, original: Metadata, _opts: &Options) -> M
     let mut result = original;
 
     // Decode all JSON-LD scripts
-    let (persons, organizations, articles) = decode_json_ld(doc);
+    let SchemaBuckets { persons, organizations, articles, ratings, events } = decode_json_ld(doc);
 
     // Extract author from persons
     if result.author.is_none() {
@@ -62,6 +72,11 @@ pub fn extract_json_ld(doc: &Document, original: Metadata, _opts: &Options) -> M
         }
     }
 
+    // Extract publisher logo from organizations
+    if result.logo.is_none() {
+        result.logo = organizations.iter().find_map(|org| extract_schema_url(&org.data, "logo"));
+    }
+
     // Extract from articles (title, description, categories, etc.)
     for article in &articles {
         if result.title.is_none() {
@@ -87,6 +102,14 @@ pub fn extract_json_ld(doc: &Document, original: Metadata, _opts: &Options) -> M
             }
         }
 
+        if result.date_modified.is_none() {
+            if let Some(date_str) = get_single_string_value(&article.data, "dateModified") {
+                if let Ok(date) = parse_json_ld_date(&date_str) {
+                    result.date_modified = Some(date);
+                }
+            }
+        }
+
         // Extract image
         if result.image.is_none() {
             if let Some(image) = extract_schema_image(&article.data) {
@@ -102,18 +125,25 @@ pub fn extract_json_ld(doc: &Document, original: Metadata, _opts: &Options) -> M
         }
     }
 
+    // Extract aggregate rating (products, recipes, local businesses, ...)
+    if result.rating.is_none() {
+        result.rating = ratings.iter().find_map(|rating| parse_aggregate_rating(&rating.data));
+    }
+
+    // Extract event details (concerts, meetups, ...)
+    if result.event.is_none() {
+        result.event = events.iter().find_map(|event| parse_event(&event.data));
+    }
+
     result
 }
 
-/// Parse and categorize JSON-LD scripts into persons, organizations, and articles.
+/// Parse and categorize JSON-LD scripts into persons, organizations, articles,
+/// aggregate ratings, and events.
 ///
 /// Go equivalent: `decodeJsonLd(doc, opts)` (lines 93-189)
-fn decode_json_ld(
-    doc: &Document,
-) -> (Vec<SchemaData>, Vec<SchemaData>, Vec<SchemaData>) {
-    let mut persons: Vec<SchemaData> = Vec::new();
-    let mut organizations: Vec<SchemaData> = Vec::new();
-    let mut articles: Vec<SchemaData> = Vec::new();
+fn decode_json_ld(doc: &Document) -> SchemaBuckets {
+    let mut buckets = SchemaBuckets::default();
 
     // Find all JSON-LD scripts
     for script in doc.select(r#"script[type="application/ld+json"]"#).nodes() {
@@ -131,26 +161,21 @@ fn decode_json_ld(
         };
 
         // Process the schema(s)
-        process_schema_value(&data, None, 0, &mut persons, &mut organizations, &mut articles);
+        process_schema_value(&data, None, 0, &mut buckets);
     }
 
     // Sort by importance (higher first)
-    persons.sort_by(|a, b| b.importance.cmp(&a.importance));
-    organizations.sort_by(|a, b| b.importance.cmp(&a.importance));
-    articles.sort_by(|a, b| b.importance.cmp(&a.importance));
+    buckets.persons.sort_by(|a, b| b.importance.cmp(&a.importance));
+    buckets.organizations.sort_by(|a, b| b.importance.cmp(&a.importance));
+    buckets.articles.sort_by(|a, b| b.importance.cmp(&a.importance));
+    buckets.ratings.sort_by_key(|r| std::cmp::Reverse(r.importance));
+    buckets.events.sort_by_key(|e| std::cmp::Reverse(e.importance));
 
-    (persons, organizations, articles)
+    buckets
 }
 
 /// Recursively process schema values.
-fn process_schema_value(
-    value: &Value,
-    parent: Option<&SchemaData>,
-    depth: i32,
-    persons: &mut Vec<SchemaData>,
-    organizations: &mut Vec<SchemaData>,
-    articles: &mut Vec<SchemaData>,
-) {
+fn process_schema_value(value: &Value, parent: Option<&SchemaData>, depth: i32, buckets: &mut SchemaBuckets) {
     match value {
         Value::Object(map) => {
             // Check if this is a schema object
@@ -159,7 +184,7 @@ fn process_schema_value(
             if types.is_empty() {
                 // Not a typed schema, recurse anyway
                 for (_, val) in map {
-                    process_schema_value(val, parent, depth, persons, organizations, articles);
+                    process_schema_value(val, parent, depth, buckets);
                 }
             } else {
                 let importance = calculate_importance(&types, parent, depth);
@@ -172,23 +197,27 @@ fn process_schema_value(
 
                 // Categorize by type
                 if is_person_type(&types) {
-                    persons.push(schema_data.clone());
+                    buckets.persons.push(schema_data.clone());
                 } else if is_organization_type(&types) {
-                    organizations.push(schema_data.clone());
+                    buckets.organizations.push(schema_data.clone());
                 } else if is_article_type(&types) {
-                    articles.push(schema_data.clone());
+                    buckets.articles.push(schema_data.clone());
+                } else if is_rating_type(&types) {
+                    buckets.ratings.push(schema_data.clone());
+                } else if is_event_type(&types) {
+                    buckets.events.push(schema_data.clone());
                 }
 
                 // Recurse into nested objects
                 for (_, val) in map {
-                    process_schema_value(val, Some(&schema_data), depth + 1, persons, organizations, articles);
+                    process_schema_value(val, Some(&schema_data), depth + 1, buckets);
                 }
             }
         }
         Value::Array(arr) => {
             // Handle @graph arrays
             for item in arr {
-                process_schema_value(item, parent, depth, persons, organizations, articles);
+                process_schema_value(item, parent, depth, buckets);
             }
         }
         _ => {}
@@ -326,6 +355,15 @@ fn is_article_type(types: &[String]) -> bool {
     ))
 }
 
+fn is_rating_type(types: &[String]) -> bool {
+    types.iter().any(|t| t == "aggregaterating")
+}
+
+/// `Event` and its subtypes (`MusicEvent`, `Festival`, `Hackathon`, ...).
+fn is_event_type(types: &[String]) -> bool {
+    types.iter().any(|t| t.ends_with("event") || matches!(t.as_str(), "festival" | "hackathon" | "eventseries"))
+}
+
 fn calculate_importance(types: &[String], parent: Option<&SchemaData>, depth: i32) -> i32 {
     let base = if is_article_type(types) { 100 } else { 50 };
     let depth_penalty = depth * 10;
@@ -335,7 +373,12 @@ fn calculate_importance(types: &[String], parent: Option<&SchemaData>, depth: i3
 }
 
 fn extract_schema_image(data: &serde_json::Map<String, Value>) -> Option<String> {
-    if let Some(image) = data.get("image") {
+    extract_schema_url(data, "image")
+}
+
+/// URL from an image-like property: a string, an `ImageObject`, or an array of either.
+fn extract_schema_url(data: &serde_json::Map<String, Value>, key: &str) -> Option<String> {
+    if let Some(image) = data.get(key) {
         match image {
             Value::String(s) => return Some(s.clone()),
             Value::Object(obj) => {
@@ -359,6 +402,99 @@ fn extract_schema_image(data: &serde_json::Map<String, Value>) -> Option<String>
     None
 }
 
+/// Build a `Rating` from an `AggregateRating` object. Requires `ratingValue`.
+fn parse_aggregate_rating(data: &serde_json::Map<String, Value>) -> Option<Rating> {
+    let number = |key: &str| match data.get(key)? {
+        Value::Number(n) => Some(n.to_string()),
+        Value::String(s) => Some(s.clone()),
+        _ => None,
+    };
+
+    Some(Rating {
+        value: parse_rating_value(&number("ratingValue")?)?,
+        best: number("bestRating").as_deref().and_then(parse_rating_value),
+        count: number("reviewCount")
+            .or_else(|| number("ratingCount"))
+            .as_deref()
+            .and_then(parse_rating_count),
+    })
+}
+
+/// Build an `EventData` from an `Event` object. Requires a name or start date.
+fn parse_event(data: &serde_json::Map<String, Value>) -> Option<EventData> {
+    let date = |key: &str| {
+        get_single_string_value(data, key).and_then(|date_str| parse_json_ld_date(&date_str).ok())
+    };
+
+    let mut event = EventData {
+        name: get_single_string_value(data, "name"),
+        start: date("startDate"),
+        end: date("endDate"),
+        ..EventData::default()
+    };
+    if event.name.is_none() && event.start.is_none() {
+        return None;
+    }
+
+    // `location` is a place name, a `Place`/`VirtualLocation`, or an array of those
+    let location = match data.get("location") {
+        Some(Value::Array(arr)) => arr.first(),
+        other => other,
+    };
+    match location {
+        Some(Value::String(name)) => event.location_name = Some(name.trim().to_string()).filter(|n| !n.is_empty()),
+        Some(Value::Object(place)) => {
+            event.location_name = get_single_string_value(place, "name");
+            event.location_address = match place.get("address") {
+                Some(Value::Object(address)) => format_postal_address(address),
+                _ => get_single_string_value(place, "address"),
+            };
+        }
+        _ => {}
+    }
+
+    let performers = match data.get("performer") {
+        Some(Value::Array(arr)) => arr.iter().collect(),
+        Some(value) => vec![value],
+        None => Vec::new(),
+    };
+    event.performers = performers
+        .into_iter()
+        .filter_map(|performer| match performer {
+            Value::String(name) => Some(name.trim().to_string()).filter(|n| !n.is_empty()),
+            Value::Object(obj) => get_schema_names(obj, "Person", "PerformingGroup"),
+            _ => None,
+        })
+        .collect();
+
+    Some(event)
+}
+
+/// Join the parts of a `PostalAddress` into one line.
+fn format_postal_address(address: &serde_json::Map<String, Value>) -> Option<String> {
+    let parts: Vec<String> = ["streetAddress", "addressLocality", "addressRegion", "postalCode", "addressCountry"]
+        .iter()
+        .filter_map(|key| match address.get(*key) {
+            // `addressCountry` may be a `Country` object
+            Some(Value::Object(country)) => get_single_string_value(country, "name"),
+            _ => get_single_string_value(address, key),
+        })
+        .collect();
+    (!parts.is_empty()).then(|| parts.join(", "))
+}
+
+/// Parse a rating score such as `4.5` or `4,5`.
+pub(crate) fn parse_rating_value(text: &str) -> Option<f64> {
+    let value: f64 = text.trim().replace(',', ".").parse().ok()?;
+    (value.is_finite() && value >= 0.0).then_some(value)
+}
+
+/// Parse a review count, ignoring thousands separators (`1,234` / `1.234`).
+pub(crate) fn parse_rating_count(text: &str) -> Option<u64> {
+    let digits: String = text.trim().chars().filter(|c| !matches!(c, ',' | '.' | ' ' | '\u{a0}')).collect();
+    digits.parse().ok()
+}
+
 fn parse_json_ld_date(date_str: &str) -> Result<chrono::DateTime<chrono::Utc>, ()> {
     // Try ISO 8601 format
     if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(date_str) {
@@ -370,6 +506,11 @@ fn parse_json_ld_date(date_str: &str) -> Result<chrono::DateTime<chrono::Utc>, (
         return Ok(dt.and_utc());
     }
 
+    // Event times are often given without seconds
+    if let Ok(dt) = chrono::NaiveDateTime::parse_from_str(date_str, "%Y-%m-%dT%H:%M") {
+        return Ok(dt.and_utc());
+    }
+
     if let Ok(date) = chrono::NaiveDate::parse_from_str(date_str, "%Y-%m-%d") {
         return Ok(date.and_hms_opt(0, 0, 0).unwrap_or_default().and_utc());
     }
@@ -537,4 +678,83 @@ mod tests {
         assert_eq!(metadata.author, Some("Original Author".to_string()));
         assert_eq!(metadata.title, Some("New Title".to_string()));
     }
+
+    #[test]
+    fn test_aggregate_rating_extraction() {
+        let html = r#"<script type="application/ld+json">{
+            "@type": "Product",
+            "name": "Trail Runner 3",
+            "aggregateRating": {"@type": "AggregateRating", "ratingValue": "4.6", "bestRating": 5, "reviewCount": "1,284"}
+        }</script>"#;
+
+        let doc = Document::from(html);
+        let metadata = extract_json_ld(&doc, Metadata::default(), &Options::default());
+
+        assert_eq!(
+            metadata.rating,
+            Some(Rating { value: 4.6, best: Some(5.0), count: Some(1284) })
+        );
+    }
+
+    #[test]
+    fn test_aggregate_rating_absent() {
+        let html = r#"<script type="application/ld+json">{"@type":"Article","headline":"No ratings here"}</script>"#;
+
+        let doc = Document::from(html);
+        let metadata = extract_json_ld(&doc, Metadata::default(), &Options::default());
+
+        assert!(metadata.rating.is_none());
+    }
+
+    #[test]
+    fn test_event_extraction() {
+        let html = r#"<script type="application/ld+json">{
+            "@context": "https://schema.org",
+            "@type": "MusicEvent",
+            "name": "Harbour Lights Festival",
+            "startDate": "2025-07-12T19:30",
+            "endDate": "2025-07-12T23:00:00+01:00",
+            "location": {
+                "@type": "Place",
+                "name": "Pier Hall",
+                "address": {"@type": "PostalAddress", "streetAddress": "1 Quay St", "addressLocality": "Cork", "addressCountry": "IE"}
+            },
+            "performer": [{"@type": "MusicGroup", "name": "The Tides"}, "DJ Shoal"]
+        }</script>"#;
+
+        let doc = Document::from(html);
+        let metadata = extract_json_ld(&doc, Metadata::default(), &Options::default());
+        let event = metadata.event.expect("event data");
+
+        assert_eq!(event.name.as_deref(), Some("Harbour Lights Festival"));
+        assert_eq!(event.start.map(|d| d.to_rfc3339()).as_deref(), Some("2025-07-12T19:30:00+00:00"));
+        assert_eq!(event.end.map(|d| d.to_rfc3339()).as_deref(), Some("2025-07-12T22:00:00+00:00"));
+        assert_eq!(event.location_name.as_deref(), Some("Pier Hall"));
+        assert_eq!(event.location_address.as_deref(), Some("1 Quay St, Cork, IE"));
+        assert_eq!(event.performers, vec!["The Tides", "DJ Shoal"]);
+    }
+
+    #[test]
+    fn test_event_absent_on_article() {
+        let html = r#"<script type="application/ld+json">{"@type":"NewsArticle","headline":"Festival announced"}</script>"#;
+
+        let doc = Document::from(html);
+        let metadata = extract_json_ld(&doc, Metadata::default(), &Options::default());
+
+        assert!(metadata.event.is_none());
+    }
+
+    #[test]
+    fn test_publisher_logo_extraction() {
+        let html = r#"<script type="application/ld+json">{
+            "@type": "NewsArticle",
+            "headline": "Harbour reopens",
+            "publisher": {"@type": "Organization", "name": "Coastal Press", "logo": {"@type": "ImageObject", "url": "https://example.com/logo.png"}}
+        }</script>"#;
+
+        let doc = Document::from(html);
+        let metadata = extract_json_ld(&doc, Metadata::default(), &Options::default());
+
+        assert_eq!(metadata.logo.as_deref(), Some("https://example.com/logo.png"));
+    }
 }
@@ -7,7 +7,8 @@
 use dom_query::{Document, Selection};
 use chrono::{DateTime, Utc};
 use crate::dom;
-use crate::result::Metadata;
+use crate::result::{Metadata, VideoMetadata};
+use crate::url_utils;
 use crate::Options;
 
 /// Examine HTML meta tags for metadata.
@@ -21,8 +22,12 @@ use crate::Options;
 /// - Schema.org itemprops
 /// - Dublin Core tags
 #[must_use]
-pub fn examine_meta(doc: &Document, original: Metadata, _opts: &Options) -> Metadata {
+pub fn examine_meta(doc: &Document, original: Metadata, opts: &Options) -> Metadata {
     let mut result = original;
+    let mut video = result.video.take().unwrap_or_default();
+    let mut citation_authors = Vec::new();
+    let mut twitter_labels = Vec::new();
+    let mut twitter_data = Vec::new();
 
     // Collect all meta tags
     for node in doc.select("meta").nodes() {
@@ -46,14 +51,33 @@ pub fn examine_meta(doc: &Document, original: Metadata, _opts: &Options) -> Meta
             // Author
             "author" | "article:author" | "dc.creator" | "dc.contributor"
             | "byl" | "sailthru.author" | "parsely-author" => {
-                if result.author.is_none() && validate_metadata_name(&content) {
+                if is_author_url(&content) {
+                    if result.author_url.is_none() {
+                        result.author_url = Some(content.trim().to_string());
+                    }
+                } else if result.author.is_none() && validate_metadata_name(&content) {
                     result.author = Some(content.clone());
                 }
             }
 
+            // Scholarly citation tags (Google Scholar / Highwire Press); one
+            // citation_author tag per author
+            "citation_author" => {
+                let author = citation_author_name(&content);
+                if validate_metadata_name(&author) && !citation_authors.contains(&author) {
+                    citation_authors.push(author);
+                }
+            }
+            "citation_doi" if result.doi.is_none() => {
+                result.doi = normalize_doi(&content);
+            }
+            "citation_journal_title" | "citation_conference_title" if result.sitename.is_none() => {
+                result.sitename = Some(content.trim().to_string());
+            }
+
             // Title
             "og:title" | "twitter:title" | "dc.title" | "sailthru.title"
-            | "parsely-title" | "title" => {
+            | "parsely-title" | "title" | "citation_title" => {
                 if result.title.is_none() {
                     result.title = Some(content.clone());
                 }
@@ -96,7 +120,12 @@ pub fn examine_meta(doc: &Document, original: Metadata, _opts: &Options) -> Meta
             | "datepublished" | "datemodified" | "og:updated_time"
             | "sailthru.date" | "parsely-pub-date" | "datelastpubbed"
             | "pubdate" | "publish_date" | "publishdate"
-            | "timestamp" | "pdate" | "cxenseparse:recs:publishtime" => {
+            | "timestamp" | "pdate" | "cxenseparse:recs:publishtime"
+            | "citation_publication_date" | "citation_date" | "citation_online_date" => {
+                let is_modified = matches!(name.as_str(), "article:modified_time" | "datemodified" | "og:updated_time");
+                if is_modified && result.date_modified.is_none() {
+                    result.date_modified = parse_meta_date(&content);
+                }
                 if result.date.is_none() {
                     if let Some(date) = parse_meta_date(&content) {
                         result.date = Some(date);
@@ -104,10 +133,12 @@ pub fn examine_meta(doc: &Document, original: Metadata, _opts: &Options) -> Meta
                 }
             }
 
-            // Tags
-            "article:tag" | "keywords" | "parsely-tags" | "sailthru.tags" => {
-                if result.tags.is_empty() {
-                    result.tags = parse_tag_list(&content);
+            // Tags: every source is merged (comma/semicolon lists, deduplicated)
+            "article:tag" | "keywords" | "news_keywords" | "parsely-tags" | "sailthru.tags" => {
+                for tag in parse_tag_list(&content) {
+                    if !result.tags.contains(&tag) {
+                        result.tags.push(tag);
+                    }
                 }
             }
 
@@ -127,6 +158,9 @@ pub fn examine_meta(doc: &Document, original: Metadata, _opts: &Options) -> Meta
 
             // Language
             "og:locale" | "language" | "dc.language" | "content-language" => {
+                if name == "og:locale" && result.locale.is_none() {
+                    result.locale = normalize_locale(&content);
+                }
                 if result.language.is_none() {
                     // Extract primary language code
                     let lang = content.split('_').next()
@@ -143,10 +177,58 @@ pub fn examine_meta(doc: &Document, original: Metadata, _opts: &Options) -> Meta
                 }
             }
 
+            // Brand color
+            "theme-color" | "msapplication-tilecolor"
+                if result.theme_color.is_none() && is_valid_css_color(&content) =>
+            {
+                result.theme_color = Some(content.trim().to_ascii_lowercase());
+            }
+
+            // Indexing directives (noindex, nofollow, ...)
+            "robots" | "googlebot" => {
+                for directive in parse_robots_directives(&content) {
+                    if !result.robots.contains(&directive) {
+                        result.robots.push(directive);
+                    }
+                }
+            }
+
+            // Available translations
+            "og:locale:alternate" => {
+                push_alternate_locale(&mut result, &content);
+            }
+
+            // Video (og:video, og:video:url, og:video:type, ...)
+            _ if is_video_property(&name) => {
+                apply_video_property(&mut video, &name, &content);
+            }
+
+            // Twitter card label/data pairs (twitter:label1 + twitter:data1, ...)
+            _ if name.starts_with("twitter:label") => {
+                twitter_labels.push((name["twitter:label".len()..].to_string(), content.to_lowercase()));
+            }
+            _ if name.starts_with("twitter:data") => {
+                twitter_data.push((name["twitter:data".len()..].to_string(), content.clone()));
+            }
+
             _ => {}
         }
     }
 
+    if result.author.is_none() && !citation_authors.is_empty() {
+        result.author = Some(citation_authors.join(", "));
+    }
+
+    let base = opts.url.as_deref().or(result.url.as_deref());
+    result.video = finish_video(video, base);
+    if let (Some(author_url), Some(base_url)) = (result.author_url.as_mut(), base.and_then(url_utils::parse_url)) {
+        *author_url = url_utils::create_absolute_url(author_url, &base_url);
+    }
+
+    if result.declared_reading_time.is_none() {
+        result.declared_reading_time = twitter_reading_time(&twitter_labels, &twitter_data);
+    }
+
     // Also check <html lang="...">
     if result.language.is_none() {
         if let Some(node) = doc.select("html").nodes().first() {
@@ -185,6 +267,40 @@ pub fn examine_meta(doc: &Document, original: Metadata, _opts: &Options) -> Meta
     result
 }
 
+/// Citation author names are usually "Last, First"; reorder them to
+/// "First Last" so several can be joined with commas.
+fn citation_author_name(content: &str) -> String {
+    let content = content.split_whitespace().collect::<Vec<_>>().join(" ");
+    match content.split_once(',') {
+        Some((last, first)) if !first.contains(',') && !first.trim().is_empty() => {
+            format!("{} {}", first.trim(), last.trim())
+        }
+        _ => content,
+    }
+}
+
+/// Bare DOI (`10.1234/abc`) from a `citation_doi` value, which may carry a
+/// `doi:` prefix or be a doi.org URL.
+fn normalize_doi(content: &str) -> Option<String> {
+    let content = content.trim();
+    let doi = content.find("10.").map(|start| &content[start..])?;
+    doi.contains('/').then(|| doi.to_string())
+}
+
+/// Find a reading time declared in a Twitter card label/data pair, e.g.
+/// `twitter:label1` = "Est. reading time" with `twitter:data1` = "5 minutes".
+///
+/// Both lists hold `(index suffix, content)`, collected in the meta tag pass.
+fn twitter_reading_time(labels: &[(String, String)], data: &[(String, String)]) -> Option<String> {
+    labels.iter().find_map(|(index, label)| {
+        if !(label.contains("reading time") || label.contains("read time") || label.contains("time to read")) {
+            return None;
+        }
+        let value = data.iter().find(|(data_index, _)| data_index == index)?.1.trim();
+        (!value.is_empty()).then(|| value.to_string())
+    })
+}
+
 /// Normalize a license string: convert known Creative Commons URLs to short names.
 fn normalize_license(s: &str) -> String {
     let s = s.trim();
@@ -215,6 +331,7 @@ fn normalize_license(s: &str) -> String {
 #[must_use]
 pub fn extract_open_graph(doc: &Document, original: Metadata) -> Metadata {
     let mut result = original;
+    let mut video = result.video.take().unwrap_or_default();
 
     for node in doc.select("meta[property^='og:']").nodes() {
         let meta = Selection::from(*node);
@@ -257,18 +374,145 @@ pub fn extract_open_graph(doc: &Document, original: Metadata) -> Metadata {
                 }
             }
             "og:locale" => {
+                if result.locale.is_none() {
+                    result.locale = normalize_locale(&content);
+                }
                 if result.language.is_none() {
                     let lang = content.split('_').next().unwrap_or(&content);
                     result.language = Some(lang.to_lowercase());
                 }
             }
+            "og:locale:alternate" => {
+                push_alternate_locale(&mut result, &content);
+            }
+            _ if is_video_property(&property) => {
+                apply_video_property(&mut video, &property, &content);
+            }
             _ => {}
         }
     }
 
+    result.video = finish_video(video, result.url.as_deref());
+
     result
 }
 
+/// Normalize an Open Graph locale such as `en-us` or `EN_US` to `en_US`.
+///
+/// Unlike [`Metadata::language`], the region is kept. Returns `None` for
+/// values that don't look like a locale.
+fn normalize_locale(value: &str) -> Option<String> {
+    let value = value.trim();
+    let mut parts = value.split(['_', '-']);
+    let lang = parts.next()?;
+    if !(2..=3).contains(&lang.len()) || !lang.chars().all(|c| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    let mut locale = lang.to_ascii_lowercase();
+    if let Some(region) = parts.next() {
+        if !(2..=3).contains(&region.len()) || !region.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return None;
+        }
+        locale.push('_');
+        locale.push_str(&region.to_ascii_uppercase());
+    }
+    Some(locale)
+}
+
+/// Record an `og:locale:alternate` value, skipping invalid and duplicate locales.
+fn push_alternate_locale(result: &mut Metadata, content: &str) {
+    if let Some(locale) = normalize_locale(content) {
+        if !result.alternates.contains(&locale) {
+            result.alternates.push(locale);
+        }
+    }
+}
+
+/// Check whether a meta property name describes Open Graph video metadata.
+fn is_video_property(name: &str) -> bool {
+    name.starts_with("og:video") || name == "video:duration"
+}
+
+/// Record a single `og:video*` property. The first value for each field wins.
+fn apply_video_property(video: &mut VideoMetadata, name: &str, content: &str) {
+    let content = content.trim();
+    match name {
+        "og:video" | "og:video:url" => {
+            video.url.get_or_insert_with(|| content.to_string());
+        }
+        "og:video:secure_url" => {
+            video.secure_url.get_or_insert_with(|| content.to_string());
+        }
+        "og:video:type" => {
+            video.mime_type.get_or_insert_with(|| content.to_lowercase());
+        }
+        "og:video:width" => video.width = video.width.or_else(|| content.parse().ok()),
+        "og:video:height" => video.height = video.height.or_else(|| content.parse().ok()),
+        "og:video:duration" | "video:duration" => {
+            video.duration = video.duration.or_else(|| content.parse().ok());
+        }
+        _ => {}
+    }
+}
+
+/// Resolve relative video URLs against `base` and drop empty video metadata.
+fn finish_video(mut video: VideoMetadata, base: Option<&str>) -> Option<VideoMetadata> {
+    if video == VideoMetadata::default() {
+        return None;
+    }
+
+    if let Some(base_url) = base.and_then(url_utils::parse_url) {
+        for url in [&mut video.url, &mut video.secure_url].into_iter().flatten() {
+            *url = url_utils::create_absolute_url(url, &base_url);
+        }
+    }
+
+    Some(video)
+}
+
+/// Whether an author meta value is a profile link rather than a name
+/// (e.g. `https://example.com/author/jane-doe` or `/author/jane-doe`).
+fn is_author_url(content: &str) -> bool {
+    let content = content.trim();
+    (content.starts_with("http://") || content.starts_with("https://") || content.starts_with('/'))
+        && !content.contains(char::is_whitespace)
+}
+
+/// Path segments that introduce an author slug in profile URLs.
+const AUTHOR_PATH_SEGMENTS: &[&str] = &[
+    "author", "authors", "by", "contributor", "contributors", "people", "profile",
+    "profiles", "staff", "writer", "writers",
+];
+
+/// Derive a display name from an author profile URL such as
+/// `https://example.com/author/jane-doe/` (→ "Jane Doe").
+///
+/// Only slugs that follow a known segment (`/author/`, `/people/`, ...) are
+/// used, so social profile handles aren't mistaken for names.
+#[must_use]
+pub fn author_name_from_url(url: &str) -> Option<String> {
+    let path = url.split(['?', '#']).next().unwrap_or(url);
+    let path = path.split_once("://").map_or(path, |(_, rest)| rest.split_once('/').map_or("", |(_, p)| p));
+    let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+    let slug = segments.windows(2).find_map(|pair| {
+        AUTHOR_PATH_SEGMENTS.contains(&pair[0].to_ascii_lowercase().as_str()).then_some(pair[1])
+    })?;
+
+    let name = slug
+        .split(['-', '_', '+', '.'])
+        .filter(|word| !word.is_empty() && !word.chars().all(|c| c.is_ascii_digit()))
+        .map(|word| {
+            let mut chars = word.chars();
+            chars.next().map_or_else(String::new, |first| {
+                first.to_uppercase().chain(chars.flat_map(char::to_lowercase)).collect()
+            })
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    validate_metadata_name(&name).then_some(name)
+}
+
 /// Validate that a metadata name looks like a real author name.
 ///
 /// Go equivalent: `validateMetadataName(name)` (lines 332-398)
@@ -381,6 +625,42 @@ fn parse_tag_list(content: &str) -> Vec<String> {
         .collect()
 }
 
+/// Accept hex (`#rgb`, `#rgba`, `#rrggbb`, `#rrggbbaa`) and `rgb()`/`rgba()` colors.
+fn is_valid_css_color(value: &str) -> bool {
+    let value = value.trim().to_ascii_lowercase();
+
+    if let Some(hex) = value.strip_prefix('#') {
+        return matches!(hex.len(), 3 | 4 | 6 | 8) && hex.chars().all(|c| c.is_ascii_hexdigit());
+    }
+
+    let args = value
+        .strip_prefix("rgba(")
+        .or_else(|| value.strip_prefix("rgb("))
+        .and_then(|rest| rest.strip_suffix(')'));
+    let Some(args) = args else {
+        return false;
+    };
+    let parts: Vec<&str> = args
+        .split([',', ' ', '/'])
+        .map(str::trim)
+        .filter(|p| !p.is_empty())
+        .collect();
+    matches!(parts.len(), 3 | 4)
+        && parts.iter().all(|p| {
+            let number = p.strip_suffix('%').unwrap_or(p);
+            number.parse::<f64>().is_ok_and(|n| n >= 0.0)
+        })
+}
+
+/// Split a robots meta value into lowercase directive tokens.
+fn parse_robots_directives(content: &str) -> Vec<String> {
+    content
+        .split(',')
+        .map(|s| s.trim().to_ascii_lowercase())
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -405,6 +685,29 @@ mod tests {
         assert_eq!(metadata.tags, vec!["test", "article", "example"]);
     }
 
+    #[test]
+    fn test_citation_meta_tags() {
+        let html = r#"<html><head>
+            <meta name="citation_title" content="Sediment transport in tidal estuaries">
+            <meta name="citation_author" content="Okafor, Ada">
+            <meta name="citation_author" content="Lindqvist, Erik">
+            <meta name="citation_publication_date" content="2021/03/15">
+            <meta name="citation_journal_title" content="Journal of Coastal Research">
+            <meta name="citation_doi" content="doi:10.1016/j.coastal.2021.0042">
+        </head><body></body></html>"#;
+
+        let doc = Document::from(html);
+        let metadata = examine_meta(&doc, Metadata::default(), &Options::default());
+
+        assert_eq!(metadata.title.as_deref(), Some("Sediment transport in tidal estuaries"));
+        assert_eq!(metadata.author.as_deref(), Some("Ada Okafor, Erik Lindqvist"));
+        assert_eq!(metadata.date.map(|d| d.date_naive().to_string()).as_deref(), Some("2021-03-15"));
+        assert_eq!(metadata.sitename.as_deref(), Some("Journal of Coastal Research"));
+        assert_eq!(metadata.doi.as_deref(), Some("10.1016/j.coastal.2021.0042"));
+        assert_eq!(normalize_doi("https://doi.org/10.1000/xyz123").as_deref(), Some("10.1000/xyz123"));
+        assert_eq!(normalize_doi("n/a"), None);
+    }
+
     #[test]
     fn test_open_graph_tags() {
         let html = r#"<!DOCTYPE html>
@@ -507,6 +810,87 @@ mod tests {
         assert!(parse_meta_date("invalid date").is_none());
     }
 
+    #[test]
+    fn test_open_graph_video() {
+        let html = r#"<!DOCTYPE html>
+        <html>
+        <head>
+            <meta property="og:url" content="https://example.com/videos/launch">
+            <meta property="og:video" content="/media/launch.mp4">
+            <meta property="og:video:secure_url" content="https://cdn.example.com/launch.mp4">
+            <meta property="og:video:type" content="video/mp4">
+            <meta property="og:video:width" content="1280">
+            <meta property="og:video:height" content="720">
+            <meta property="video:duration" content="95">
+        </head>
+        <body></body>
+        </html>"#;
+
+        let doc = Document::from(html);
+        let metadata = examine_meta(&doc, Metadata::default(), &Options::default());
+        let video = metadata.video.expect("video metadata should be present");
+
+        assert_eq!(video.url.as_deref(), Some("https://example.com/media/launch.mp4"));
+        assert_eq!(video.secure_url.as_deref(), Some("https://cdn.example.com/launch.mp4"));
+        assert_eq!(video.mime_type.as_deref(), Some("video/mp4"));
+        assert_eq!(video.width, Some(1280));
+        assert_eq!(video.height, Some(720));
+        assert_eq!(video.duration, Some(95));
+    }
+
+    #[test]
+    fn test_open_graph_locale() {
+        let html = r#"<html><head>
+            <meta property="og:locale" content="en_us">
+            <meta property="og:locale:alternate" content="fr_FR">
+            <meta property="og:locale:alternate" content="es_ES">
+            <meta property="og:locale:alternate" content="fr_FR">
+            <meta property="og:locale:alternate" content="not a locale">
+        </head><body></body></html>"#;
+
+        let doc = Document::from(html);
+        let metadata = examine_meta(&doc, Metadata::default(), &Options::default());
+
+        assert_eq!(metadata.locale.as_deref(), Some("en_US"));
+        assert_eq!(metadata.language.as_deref(), Some("en"));
+        assert_eq!(metadata.alternates, vec!["fr_FR", "es_ES"]);
+
+        let og = extract_open_graph(&doc, Metadata::default());
+        assert_eq!(og.locale.as_deref(), Some("en_US"));
+        assert_eq!(og.alternates, vec!["fr_FR", "es_ES"]);
+    }
+
+    #[test]
+    fn test_article_author_url() {
+        let html = r#"<html><head>
+            <meta property="article:author" content="/author/jane-doe/">
+        </head><body></body></html>"#;
+
+        let doc = Document::from(html);
+        let opts = Options {
+            url: Some("https://example.com/news/story".to_string()),
+            ..Options::default()
+        };
+        let metadata = examine_meta(&doc, Metadata::default(), &opts);
+
+        assert_eq!(metadata.author, None);
+        assert_eq!(metadata.author_url.as_deref(), Some("https://example.com/author/jane-doe/"));
+        assert_eq!(author_name_from_url("https://example.com/author/jane-doe/").as_deref(), Some("Jane Doe"));
+        assert_eq!(author_name_from_url("https://www.facebook.com/janedoe"), None);
+    }
+
+    #[test]
+    fn test_no_video_metadata() {
+        let html = r#"<meta property="og:title" content="Plain Article">"#;
+
+        let doc = Document::from(html);
+        let metadata = examine_meta(&doc, Metadata::default(), &Options::default());
+        assert!(metadata.video.is_none());
+
+        let og = extract_open_graph(&doc, Metadata::default());
+        assert!(og.video.is_none());
+    }
+
     #[test]
     fn test_preserves_existing_metadata() {
         let html = r#"<meta name="author" content="New Author">"#;
@@ -522,4 +906,48 @@ mod tests {
         // Should preserve original
         assert_eq!(metadata.author, Some("Original Author".to_string()));
     }
+
+    #[test]
+    fn test_twitter_reading_time() {
+        let html = r#"<html><head>
+            <meta name="twitter:label1" content="Written by">
+            <meta name="twitter:data1" content="Jane Doe">
+            <meta name="twitter:label2" content="Est. reading time">
+            <meta name="twitter:data2" content="7 minutes">
+        </head><body></body></html>"#;
+        let doc = Document::from(html);
+        let result = examine_meta(&doc, Metadata::default(), &Options::default());
+
+        assert_eq!(result.declared_reading_time.as_deref(), Some("7 minutes"));
+    }
+
+    #[test]
+    fn test_robots_directives() {
+        let html = r#"<html><head>
+            <meta name="robots" content="NoIndex, nofollow">
+            <meta name="googlebot" content="noarchive, nofollow, max-snippet:-1">
+        </head><body></body></html>"#;
+
+        let doc = Document::from(html);
+        let metadata = examine_meta(&doc, Metadata::default(), &Options::default());
+
+        assert_eq!(metadata.robots, vec!["noindex", "nofollow", "noarchive", "max-snippet:-1"]);
+    }
+
+    #[test]
+    fn test_theme_color_validation() {
+        let html = r##"<html><head>
+            <meta name="theme-color" content="javascript:alert(1)">
+            <meta name="theme-color" content="#1A73E8">
+        </head><body></body></html>"##;
+
+        let doc = Document::from(html);
+        let metadata = examine_meta(&doc, Metadata::default(), &Options::default());
+
+        assert_eq!(metadata.theme_color.as_deref(), Some("#1a73e8"));
+        assert!(is_valid_css_color("rgb(26, 115, 232)"));
+        assert!(is_valid_css_color("rgba(26 115 232 / 50%)"));
+        assert!(!is_valid_css_color("#12345"));
+        assert!(!is_valid_css_color("blue; background: url(x)"));
+    }
 }
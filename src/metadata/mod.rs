@@ -10,12 +10,14 @@ pub mod meta_tags;
 use dom_query::Document;
 use crate::result::Metadata;
 use crate::url_utils;
+use crate::options::TrailingSlashPolicy;
 use crate::Options;
 
 pub use dom_extraction::{
-    examine_title_element, extract_dom_author, extract_dom_categories,
-    extract_dom_license, extract_dom_sitename, extract_dom_tags,
-    extract_dom_title, extract_dom_url,
+    examine_title_element, extract_dom_author, extract_dom_categories, extract_dom_date_display,
+    extract_dom_license, extract_dom_logo, extract_dom_microformats, extract_dom_print_links,
+    extract_dom_rating, extract_dom_reading_time, extract_dom_related_links, extract_dom_sitename,
+    extract_dom_social_links, extract_dom_tags, extract_dom_title, extract_dom_url,
 };
 pub use json_ld::extract_json_ld;
 pub use meta_tags::{examine_meta, extract_open_graph, validate_metadata_name};
@@ -52,14 +54,25 @@ pub fn extract_metadata(doc: &Document, opts: &Options) -> Metadata {
     metadata = meta_tags::examine_meta(doc, metadata, opts);
 
     // 3. Extract from DOM (fallback for missing fields)
+    metadata = dom_extraction::extract_dom_microformats(doc, metadata, opts);
     metadata = dom_extraction::extract_dom_title(doc, metadata, opts);
     metadata = dom_extraction::extract_dom_author(doc, metadata, opts);
+    if metadata.author.is_none() {
+        metadata.author = metadata.author_url.as_deref().and_then(meta_tags::author_name_from_url);
+    }
     metadata = dom_extraction::extract_dom_date(doc, metadata, opts);
+    metadata = dom_extraction::extract_dom_date_display(doc, metadata, opts);
     metadata = dom_extraction::extract_dom_url(doc, metadata, opts);
     metadata = dom_extraction::extract_dom_sitename(doc, metadata, opts);
     metadata = dom_extraction::extract_dom_categories(doc, metadata, opts);
     metadata = dom_extraction::extract_dom_tags(doc, metadata, opts);
     metadata = dom_extraction::extract_dom_license(doc, metadata, opts);
+    metadata = dom_extraction::extract_dom_rating(doc, metadata, opts);
+    metadata = dom_extraction::extract_dom_logo(doc, metadata, opts);
+    metadata = dom_extraction::extract_dom_reading_time(doc, metadata, opts);
+    metadata = dom_extraction::extract_dom_related_links(doc, metadata, opts);
+    metadata = dom_extraction::extract_dom_print_links(doc, metadata, opts);
+    metadata = dom_extraction::extract_dom_social_links(doc, metadata, opts);
 
     // 4. Post-processing
     metadata = post_process_metadata(metadata, opts);
@@ -78,9 +91,39 @@ pub fn extract_metadata(doc: &Document, opts: &Options) -> Metadata {
         }
     }
 
+    // 7. Trailing-slash policy for link fields
+    apply_link_trailing_slash(&mut metadata, opts.link_trailing_slash);
+
     metadata
 }
 
+/// Normalize the trailing slash of every link-valued metadata field.
+fn apply_link_trailing_slash(metadata: &mut Metadata, policy: TrailingSlashPolicy) {
+    if policy == TrailingSlashPolicy::Preserve {
+        return;
+    }
+    for url in [
+        &mut metadata.url,
+        &mut metadata.author_url,
+        &mut metadata.print_url,
+        &mut metadata.pdf_url,
+        &mut metadata.feed_url,
+    ]
+    .into_iter()
+    .flatten()
+    {
+        *url = url_utils::apply_trailing_slash(url, policy);
+    }
+    for links in [&mut metadata.related_links, &mut metadata.social_links] {
+        let mut seen = std::collections::HashSet::new();
+        for url in links.iter_mut() {
+            *url = url_utils::apply_trailing_slash(url, policy);
+        }
+        // `/foo` and `/foo/` may have been listed separately
+        links.retain(|url| seen.insert(url.clone()));
+    }
+}
+
 /// Post-process metadata to clean and validate.
 /// Decode common HTML entities in text.
 fn decode_html_entities(text: &str) -> String {
@@ -108,12 +151,14 @@ fn decode_html_entities(text: &str) -> String {
 /// Common patterns: "Article Title | Site Name", "Article Title - Site Name"
 /// Uses heuristics: the suffix/prefix is typically short (≤5 words) while the
 /// actual title is substantial (>10 chars). If `sitename` is available, it's
-/// used to confirm the suffix is indeed a site name.
-fn strip_site_suffix(title: &str, sitename: Option<&str>) -> String {
+/// used to confirm the suffix is indeed a site name. `extra_separators`
+/// (`Options::title_separators`) are tried after the built-in ones.
+fn strip_site_suffix(title: &str, sitename: Option<&str>, extra_separators: &[String]) -> String {
     // Separators to check — ordered by specificity
     let separators: &[&str] = &[" | ", " \u{2022} ", " \u{00bb} ", " - ", " \u{2013} ", " \u{2014} "];
+    let extra = extra_separators.iter().map(String::as_str).filter(|sep| !sep.trim().is_empty());
 
-    for sep in separators {
+    for sep in separators.iter().copied().chain(extra) {
         // Try all split points for this separator (handles chained suffixes)
         let positions: Vec<usize> = title.match_indices(sep).map(|(i, _)| i).collect();
 
@@ -150,14 +195,14 @@ fn strip_site_suffix(title: &str, sitename: Option<&str>) -> String {
     title.to_string()
 }
 
-fn post_process_metadata(mut metadata: Metadata, _opts: &Options) -> Metadata {
+fn post_process_metadata(mut metadata: Metadata, opts: &Options) -> Metadata {
     // Trim and clean title
     if let Some(ref mut title) = metadata.title {
         *title = title.trim().to_string();
         // Decode HTML entities that may appear in meta tag content
         *title = decode_html_entities(title);
         // Strip site name suffixes (e.g., "Article | Site Name" → "Article")
-        *title = strip_site_suffix(title, metadata.sitename.as_deref());
+        *title = strip_site_suffix(title, metadata.sitename.as_deref(), &opts.title_separators);
         if title.is_empty() {
             metadata.title = None;
         }
@@ -170,6 +215,13 @@ fn post_process_metadata(mut metadata: Metadata, _opts: &Options) -> Metadata {
         }
     }
 
+    if opts.clean_author_names {
+        if let Some(raw) = metadata.author.take() {
+            metadata.author = dom_extraction::clean_author_name(&raw, opts);
+            metadata.author_raw = Some(raw);
+        }
+    }
+
     if let Some(ref mut description) = metadata.description {
         *description = description.trim().to_string();
         if description.is_empty() {
@@ -197,6 +249,10 @@ fn post_process_metadata(mut metadata: Metadata, _opts: &Options) -> Metadata {
         .filter(|s| !s.is_empty())
         .collect();
 
+    if opts.normalize_tags {
+        metadata.tags = normalize_tags(metadata.tags);
+    }
+
     // Validate URL
     if let Some(ref url) = metadata.url {
         let (_, is_valid) = url_utils::validate_url(url, None);
@@ -216,6 +272,21 @@ fn post_process_metadata(mut metadata: Metadata, _opts: &Options) -> Metadata {
     metadata
 }
 
+/// Trim surrounding punctuation and drop tags that differ only by case,
+/// keeping the first-seen casing.
+fn normalize_tags(tags: Vec<String>) -> Vec<String> {
+    let mut seen = std::collections::HashSet::new();
+    tags.into_iter()
+        .map(|tag| {
+            tag.trim_matches(|c: char| {
+                c.is_whitespace() || matches!(c, ',' | ';' | ':' | '.' | '!' | '?' | '"' | '\'' | '|' | '/' | '(' | ')' | '[' | ']' | '{' | '}' | '\u{201c}' | '\u{201d}' | '\u{2018}' | '\u{2019}')
+            })
+            .to_string()
+        })
+        .filter(|tag| !tag.is_empty() && seen.insert(tag.to_lowercase()))
+        .collect()
+}
+
 /// Check if an author name is in the blacklist.
 ///
 /// Go equivalent: `removeBlacklistedAuthors(current, opts)` (metadata.go lines 822-850)
@@ -257,6 +328,21 @@ pub fn extract_metadata_light(doc: &Document, opts: &Options) -> Metadata {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_normalize_tags_dedupes_case_insensitively() {
+        let tags = vec![
+            "Politics".to_string(),
+            "politics".to_string(),
+            " \"Elections\", ".to_string(),
+            "POLITICS".to_string(),
+            "elections".to_string(),
+            "C++".to_string(),
+            "...".to_string(),
+        ];
+
+        assert_eq!(normalize_tags(tags), vec!["Politics", "Elections", "C++"]);
+    }
+
     #[test]
     fn test_extract_metadata_priority() {
         // JSON-LD should take priority
@@ -370,7 +456,7 @@ mod tests {
     #[test]
     fn test_strip_suffix_pipe() {
         assert_eq!(
-            strip_site_suffix("What is Cloud Computing? | Google Cloud", None),
+            strip_site_suffix("What is Cloud Computing? | Google Cloud", None, &[]),
             "What is Cloud Computing?"
         );
     }
@@ -378,7 +464,7 @@ mod tests {
     #[test]
     fn test_strip_suffix_dash() {
         assert_eq!(
-            strip_site_suffix("10 Easy Steps for Interior Design - Tarkett", None),
+            strip_site_suffix("10 Easy Steps for Interior Design - Tarkett", None, &[]),
             "10 Easy Steps for Interior Design"
         );
     }
@@ -386,7 +472,7 @@ mod tests {
     #[test]
     fn test_strip_suffix_em_dash() {
         assert_eq!(
-            strip_site_suffix("Wedding Planning Tools — The Knot", None),
+            strip_site_suffix("Wedding Planning Tools — The Knot", None, &[]),
             "Wedding Planning Tools"
         );
     }
@@ -394,7 +480,7 @@ mod tests {
     #[test]
     fn test_strip_prefix_pattern() {
         assert_eq!(
-            strip_site_suffix("BBC | World News Today Is Happening", None),
+            strip_site_suffix("BBC | World News Today Is Happening", None, &[]),
             "World News Today Is Happening"
         );
     }
@@ -403,18 +489,18 @@ mod tests {
     fn test_no_strip_subtitle() {
         // Both sides are long — the dash is a subtitle separator, not a site name
         let title = "The Complete Guide - Everything You Need to Know About Rust";
-        assert_eq!(strip_site_suffix(title, None), title);
+        assert_eq!(strip_site_suffix(title, None, &[]), title);
     }
 
     #[test]
     fn test_no_separator() {
-        assert_eq!(strip_site_suffix("Simple Title", None), "Simple Title");
+        assert_eq!(strip_site_suffix("Simple Title", None, &[]), "Simple Title");
     }
 
     #[test]
     fn test_strip_with_known_sitename() {
         assert_eq!(
-            strip_site_suffix("Article Title | Example Site", Some("Example Site")),
+            strip_site_suffix("Article Title | Example Site", Some("Example Site"), &[]),
             "Article Title"
         );
     }
@@ -422,11 +508,21 @@ mod tests {
     #[test]
     fn test_strip_prefix_with_known_sitename() {
         assert_eq!(
-            strip_site_suffix("Example Site | Article Title Here", Some("Example Site")),
+            strip_site_suffix("Example Site | Article Title Here", Some("Example Site"), &[]),
             "Article Title Here"
         );
     }
 
+    #[test]
+    fn test_strip_site_suffix_extra_separators() {
+        let title = "Rebuilding the harbour wall after the storm :: Coast Weekly";
+        assert_eq!(strip_site_suffix(title, None, &[]), title);
+        assert_eq!(
+            strip_site_suffix(title, None, &[" :: ".to_string()]),
+            "Rebuilding the harbour wall after the storm"
+        );
+    }
+
     #[test]
     fn test_strip_og_title_with_suffix() {
         // Simulates og:title going through post_process_metadata
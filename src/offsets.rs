@@ -0,0 +1,172 @@
+//! Source offsets for `Options::track_offsets`.
+//!
+//! html5ever doesn't keep source positions, so spans are recovered by
+//! matching: the raw HTML is scanned into its visible text (tags, comments,
+//! and script/style bodies skipped, entities decoded) with the byte offset
+//! of every character, and each paragraph of `content_text` is then located
+//! in that text. Whitespace is ignored on both sides, since extraction
+//! reflows it.
+
+/// Elements whose raw text is never part of the content.
+const RAW_TEXT_ELEMENTS: &[&str] = &["script", "style", "title", "textarea"];
+
+/// Characters matched at each end of a paragraph whose full text isn't
+/// found verbatim (table cells joined with `|`, emphasis markers, ...).
+const ANCHOR_CHARS: usize = 24;
+
+/// Visible text of an HTML document with whitespace removed, and the
+/// source byte range of every byte of it.
+struct VisibleText {
+    text: String,
+    starts: Vec<usize>,
+    ends: Vec<usize>,
+}
+
+impl VisibleText {
+    fn push(&mut self, ch: char, start: usize, end: usize) {
+        if ch.is_whitespace() {
+            return;
+        }
+        self.text.push(ch);
+        for _ in 0..ch.len_utf8() {
+            self.starts.push(start);
+            self.ends.push(end);
+        }
+    }
+
+    /// Source range of `needle` (whitespace-free), searching from byte `from`.
+    fn find(&self, needle: &str, from: usize) -> Option<(usize, usize)> {
+        let index = from + self.text.get(from..)?.find(needle)?;
+        Some((index, index + needle.len()))
+    }
+}
+
+/// Byte ranges of `html` that each paragraph of `content_text` came from,
+/// in content order. Paragraphs that can't be located are skipped.
+pub(crate) fn source_spans(html: &str, content_text: &str) -> Vec<(usize, usize)> {
+    let visible = visible_text(html);
+    let mut spans = Vec::new();
+    let mut cursor = 0;
+
+    for paragraph in content_text.lines() {
+        let needle: String = paragraph.chars().filter(|c| !c.is_whitespace()).collect();
+        if needle.is_empty() {
+            continue;
+        }
+        let found = visible.find(&needle, cursor).or_else(|| {
+            // Fall back to the paragraph's first and last characters
+            let head: String = needle.chars().take(ANCHOR_CHARS).collect();
+            let tail: String = {
+                let chars: Vec<char> = needle.chars().collect();
+                chars[chars.len().saturating_sub(ANCHOR_CHARS)..].iter().collect()
+            };
+            let (start, _) = visible.find(&head, cursor)?;
+            let (_, end) = visible.find(&tail, start)?;
+            // A tail found far beyond the paragraph's length belongs elsewhere
+            (end - start <= needle.len() * 2).then_some((start, end))
+        });
+        if let Some((start, end)) = found {
+            spans.push((visible.starts[start], visible.ends[end - 1]));
+            cursor = end;
+        }
+    }
+
+    spans
+}
+
+/// Scan `html` into its visible text.
+fn visible_text(html: &str) -> VisibleText {
+    let mut visible = VisibleText {
+        text: String::with_capacity(html.len() / 2),
+        starts: Vec::with_capacity(html.len() / 2),
+        ends: Vec::with_capacity(html.len() / 2),
+    };
+    let bytes = html.as_bytes();
+    let mut pos = 0;
+
+    while pos < html.len() {
+        match bytes[pos] {
+            b'<' if html[pos..].starts_with("<!--") => {
+                pos = html[pos..].find("-->").map_or(html.len(), |end| pos + end + 3);
+            }
+            b'<' if bytes.get(pos + 1).is_some_and(|b| b.is_ascii_alphabetic() || matches!(b, b'/' | b'!' | b'?')) => {
+                let tag_end = html[pos..].find('>').map_or(html.len(), |end| pos + end + 1);
+                let name: String = html[pos + 1..tag_end]
+                    .chars()
+                    .take_while(char::is_ascii_alphanumeric)
+                    .collect::<String>()
+                    .to_ascii_lowercase();
+                pos = tag_end;
+                if RAW_TEXT_ELEMENTS.contains(&name.as_str()) {
+                    let close = format!("</{name}");
+                    pos = find_ascii_case_insensitive(&html[pos..], &close).map_or(html.len(), |end| pos + end);
+                }
+            }
+            b'&' => {
+                let (ch, len) = decode_entity(&html[pos..]).unwrap_or(('&', 1));
+                visible.push(ch, pos, pos + len);
+                pos += len;
+            }
+            _ => {
+                let ch = html[pos..].chars().next().unwrap_or(' ');
+                visible.push(ch, pos, pos + ch.len_utf8());
+                pos += ch.len_utf8();
+            }
+        }
+    }
+
+    visible
+}
+
+fn find_ascii_case_insensitive(haystack: &str, needle: &str) -> Option<usize> {
+    haystack
+        .as_bytes()
+        .windows(needle.len())
+        .position(|window| window.eq_ignore_ascii_case(needle.as_bytes()))
+}
+
+/// Decode the character reference at the start of `text`, returning the
+/// character and the reference's length in bytes.
+fn decode_entity(text: &str) -> Option<(char, usize)> {
+    let end = text.find(';').filter(|&end| end <= 10)?;
+    let name = &text[1..end];
+    let ch = if let Some(hex) = name.strip_prefix("#x").or_else(|| name.strip_prefix("#X")) {
+        char::from_u32(u32::from_str_radix(hex, 16).ok()?)?
+    } else if let Some(decimal) = name.strip_prefix('#') {
+        char::from_u32(decimal.parse().ok()?)?
+    } else {
+        match name {
+            "amp" => '&',
+            "lt" => '<',
+            "gt" => '>',
+            "quot" => '"',
+            "apos" => '\'',
+            "nbsp" => '\u{a0}',
+            "mdash" => '—',
+            "ndash" => '–',
+            "hellip" => '…',
+            "lsquo" => '‘',
+            "rsquo" => '’',
+            "ldquo" => '“',
+            "rdquo" => '”',
+            "copy" => '©',
+            _ => return None,
+        }
+    };
+    Some((ch, end + 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_source_spans_cover_paragraphs_across_inline_tags() {
+        let html = "<html><head><title>One</title></head><body><p>One <b>two</b> &amp; three.</p>\n<!-- x --><p>Four</p></body></html>";
+        let spans = source_spans(html, "One two & three.\nFour");
+
+        assert_eq!(spans.len(), 2);
+        assert_eq!(&html[spans[0].0..spans[0].1], "One <b>two</b> &amp; three.");
+        assert_eq!(&html[spans[1].0..spans[1].1], "Four");
+    }
+}
@@ -3,6 +3,10 @@
 //! The `Options` struct controls extraction behavior, allowing users to
 //! tune the precision/recall tradeoff and enable/disable specific features.
 
+use std::sync::Arc;
+
+use crate::scorer::ContentScorer;
+
 /// Configuration options for content extraction.
 ///
 /// All fields are public for easy configuration. Use `Default::default()`
@@ -43,6 +47,9 @@ pub struct Options {
 
     /// Preserve link URLs in extracted content.
     ///
+    /// Elements that in-document links (`href="#section-2"`) point at keep
+    /// their `id`, so a table of contents still works in `content_html`.
+    ///
     /// Default: `false`
     pub include_links: bool,
 
@@ -75,6 +82,16 @@ pub struct Options {
     /// Default: `None`
     pub target_language: Option<String>,
 
+    /// Keep only one language when the page carries parallel translations.
+    ///
+    /// Sibling blocks with the same structure but different `lang`
+    /// attributes (e.g. bilingual government pages) are treated as
+    /// translations of each other; the version in `target_language`, or
+    /// else the document language, is kept and the others are dropped.
+    ///
+    /// Default: `false`
+    pub deduplicate_translations: bool,
+
     /// Source URL of the document for hostname extraction.
     ///
     /// When provided, the hostname is extracted from this URL and stored
@@ -92,6 +109,16 @@ pub struct Options {
     /// Default: `None`
     pub author_blacklist: Option<Vec<String>>,
 
+    /// Reduce bylines to the author name.
+    ///
+    /// Strips "By"/"Written by" lead-ins, email addresses, and trailing
+    /// roles, affiliations, and dates ("Jane Smith, Staff Writer" becomes
+    /// "Jane Smith"). The original string is kept in
+    /// [`Metadata::author_raw`](crate::Metadata::author_raw).
+    ///
+    /// Default: `false`
+    pub clean_author_names: bool,
+
     /// Remove duplicate text segments and sections.
     ///
     /// When enabled, uses an LRU cache to track seen text and skip
@@ -124,6 +151,15 @@ pub struct Options {
     /// Default: `1000000` (1M chars)
     pub max_extracted_len: usize,
 
+    /// Keep only the first N non-empty paragraphs of the main content.
+    ///
+    /// Summary mode for previews: content is cut at a paragraph boundary
+    /// (headings don't count towards N). Metadata is still extracted from
+    /// the whole page. Unlike `max_extracted_len`, which cuts by characters.
+    ///
+    /// Default: `None` (no limit)
+    pub max_paragraphs: Option<usize>,
+
     /// Minimum number of words in extracted output.
     ///
     /// Default: `50`
@@ -206,6 +242,22 @@ pub struct Options {
     /// Default: `1000`
     pub dedup_cache_size: usize,
 
+    /// Minimum text length (in characters) for a segment to be checked for
+    /// duplicates. Shorter segments are never treated as duplicates.
+    ///
+    /// Only used when `deduplicate = true`.
+    ///
+    /// Default: `100`
+    pub dedup_min_length: usize,
+
+    /// Number of times a segment may repeat before further copies are
+    /// dropped as duplicates.
+    ///
+    /// Only used when `deduplicate = true`.
+    ///
+    /// Default: `2`
+    pub dedup_max_repeats: i32,
+
     /// Include title element in output.
     ///
     /// When false, title is only in metadata, not content.
@@ -230,6 +282,511 @@ pub struct Options {
     ///
     /// Default: `None`
     pub page_type: Option<crate::page_type::PageType>,
+
+    /// Move photo captions and credit lines out of the main text.
+    ///
+    /// When enabled, caption/credit elements inside the page (`<figcaption>`,
+    /// class/id patterns such as `caption`, `credit`, `wp-caption-text`, and
+    /// short text blocks paired with a single image) are removed before
+    /// extraction and returned in `ExtractResult.captions` instead.
+    ///
+    /// Default: `false`
+    pub separate_captions: bool,
+
+    /// Keep `class` and `id` attributes on elements in `content_html`.
+    ///
+    /// By default these are stripped along with other presentational
+    /// attributes. `style` and event handler attributes are always dropped.
+    ///
+    /// Default: `false`
+    pub preserve_content_attributes: bool,
+
+    /// Keep `<aside>` elements nested inside `<article>` or `<main>`.
+    ///
+    /// Pull-quotes and info boxes within the article body are extracted as
+    /// content unless they fail link-density filtering. Page-level sidebars
+    /// are always dropped.
+    ///
+    /// Default: `false`
+    pub include_asides: bool,
+
+    /// Merge sibling `<article>` elements into one result.
+    ///
+    /// For pages that render each section as its own `<article>` (e.g. live
+    /// blogs with one article per update). When the selected content sits in
+    /// an `<article>` with substantial sibling articles, all of them are
+    /// concatenated in document order instead of keeping only the first.
+    ///
+    /// Default: `false`
+    pub merge_articles: bool,
+
+    /// Pair footnote references with their definitions.
+    ///
+    /// In-text references (`<sup><a href="#fn1">1</a></sup>`) are matched to
+    /// their target elements and returned in `ExtractResult.footnotes`. The
+    /// definitions are removed from the body.
+    ///
+    /// Default: `false`
+    pub extract_footnotes: bool,
+
+    /// Rewrite paired footnote references as `[1]` in the output.
+    ///
+    /// Only applies with `extract_footnotes`; otherwise references keep their
+    /// original marker text.
+    ///
+    /// Default: `false`
+    pub inline_footnote_markers: bool,
+
+    /// Compute lexical quality signals for the extracted text.
+    ///
+    /// Populates `ExtractResult.quality_signals` with average sentence
+    /// length, type-token ratio, and keyword-stuffing ratio.
+    ///
+    /// Default: `false`
+    pub compute_quality_signals: bool,
+
+    /// Measure the link density of the extracted content.
+    ///
+    /// Populates `ExtractResult.link_density`. Costs an extra parse of
+    /// `content_html`, so it is off unless you need the signal.
+    ///
+    /// Default: `false`
+    pub compute_link_density: bool,
+
+    /// Record how long each extraction stage takes in `ExtractResult::timings`.
+    ///
+    /// For profiling slow pages; adds a few clock reads per call.
+    ///
+    /// Default: `false`
+    pub collect_timings: bool,
+
+    /// Map `content_text` back to the input in `ExtractResult::source_spans`.
+    ///
+    /// Each paragraph gets the byte range of the HTML it was extracted
+    /// from, for highlighting or annotating the source. Spans are found by
+    /// text matching and are approximate.
+    ///
+    /// Default: `false`
+    pub track_offsets: bool,
+
+    /// Paragraphs to remove from `content_text`, e.g. `"This article was
+    /// originally published on Example News."`.
+    ///
+    /// Whole paragraphs are matched, ignoring case and trailing punctuation.
+    /// End a phrase with `*` to drop every paragraph starting with it
+    /// (`"This article was originally published*"`).
+    ///
+    /// Default: empty (nothing removed)
+    pub boilerplate_phrases: Vec<String>,
+
+    /// Drop trailing taxonomy lines from the content.
+    ///
+    /// Short closing lines starting with "Tags:", "Filed under:",
+    /// "Categories:", or "Posted in:" are removed from every output format.
+    /// The tags they list are still collected into
+    /// [`Metadata::tags`](crate::Metadata::tags).
+    ///
+    /// Default: `false`
+    pub strip_taxonomy_lines: bool,
+
+    /// Keep paragraphs that contain no letters or digits.
+    ///
+    /// By default, decorative paragraphs made only of symbols, emoji,
+    /// box-drawing characters, or punctuation (e.g. `✦ ✦ ✦`) are dropped.
+    ///
+    /// Default: `false`
+    pub keep_symbol_paragraphs: bool,
+
+    /// Mark each image's position in `content_text` with `{{image:N}}`.
+    ///
+    /// `N` indexes into [`ExtractResult::images`](crate::ExtractResult::images),
+    /// so text and images can be re-interleaved downstream. Markers are
+    /// stripped from `content_html`. Only applies when `include_images` is set.
+    ///
+    /// Default: `false`
+    pub inline_media_markers: bool,
+
+    /// Split the content at `<hr>` into
+    /// [`ExtractResult::sections`](crate::ExtractResult::sections).
+    ///
+    /// `content_text` still holds the whole content; `content_html` keeps
+    /// the `<hr>` elements between sections.
+    ///
+    /// Default: `false`
+    pub split_on_hr: bool,
+
+    /// Collect links from "related articles" widgets into
+    /// [`Metadata::related_links`](crate::Metadata::related_links).
+    ///
+    /// The widgets are still excluded from content; this keeps their link
+    /// targets for crawl expansion. Relative URLs are resolved against the
+    /// page URL.
+    ///
+    /// Default: `false`
+    pub collect_related_links: bool,
+
+    /// Collect the publisher's social profile links and feed URL into
+    /// [`Metadata::social_links`](crate::Metadata::social_links) and
+    /// [`Metadata::feed_url`](crate::Metadata::feed_url).
+    ///
+    /// Profiles are gathered from the header and footer before doc cleaning
+    /// drops them; share buttons are ignored.
+    ///
+    /// Default: `false`
+    pub collect_social_links: bool,
+
+    /// Extra element names to score as main-content candidates.
+    ///
+    /// Extends the built-in `div`/`section`/`article`/`main` list, for sites
+    /// that wrap content in custom elements (e.g. `article-body`,
+    /// `story-text`). Names are matched as lowercase tag names; empty or
+    /// invalid names are ignored.
+    ///
+    /// Default: `[]` (empty)
+    pub content_candidate_tags: Vec<String>,
+
+    /// Custom scorer for main-content candidates.
+    ///
+    /// Replaces the built-in heuristic ([`DefaultScorer`](crate::DefaultScorer))
+    /// when no `<article>`/`<main>` element identifies the content and
+    /// candidates are ranked by score. `None` uses the default.
+    ///
+    /// Default: `None`
+    pub content_scorer: Option<Arc<dyn ContentScorer>>,
+
+    /// Report malformed input in [`ExtractResult::warnings`](crate::ExtractResult::warnings).
+    ///
+    /// Adds a warning when the parser had to repair the document structure
+    /// (misnested or unclosed tags, stray table text) or when page content
+    /// sits inside `<noscript>`. Extraction itself is unchanged.
+    ///
+    /// Default: `false`
+    pub report_parse_errors: bool,
+
+    /// Label blocks in `content_html` with a `data-block-type` attribute.
+    ///
+    /// Keeps a light trace of structure after the original `class`/`id`
+    /// attributes are stripped: `heading`, `body`, `quote`, `code`, `list`,
+    /// `table`, or `figure`.
+    ///
+    /// Default: `false`
+    pub annotate_structure: bool,
+
+    /// Merge `metadata.tags` that differ only by case.
+    ///
+    /// Tags are trimmed of surrounding punctuation and deduplicated
+    /// case-insensitively, keeping the first-seen casing for display.
+    ///
+    /// Default: `false`
+    pub normalize_tags: bool,
+
+    /// CSS selectors for subtrees to drop from the extracted content.
+    ///
+    /// Applied to the body before content finding and before the fallback
+    /// copy of the document is taken, so the subtrees stay out whichever
+    /// extraction path wins (e.g. `.author-bio` blocks the heuristics keep).
+    /// Invalid selectors are ignored.
+    ///
+    /// Default: `[]` (empty)
+    pub exclude_selectors: Vec<String>,
+
+    /// Collect data tables from the content into `ExtractResult.tables`.
+    ///
+    /// Each table keeps its header cells (from `<th>`, or the first row) and
+    /// its body rows as cell text. The text and HTML renderings are unchanged.
+    /// Requires `include_tables`.
+    ///
+    /// Default: `false`
+    pub tables_structured: bool,
+
+    /// Tag each paragraph of `content_text` with its detected language.
+    ///
+    /// Populates `ExtractResult.language_spans` using a script and stopword
+    /// heuristic, for pages that mix languages (e.g. an English article
+    /// quoting a French source).
+    ///
+    /// Default: `false`
+    pub detect_paragraph_languages: bool,
+
+    /// Only keep images whose URL has one of these file extensions.
+    ///
+    /// Matched case-insensitively, with or without the leading dot
+    /// (e.g. `["jpg", "png", "webp"]`). When set, images without a file
+    /// extension are dropped too. Data URIs are governed by
+    /// `drop_data_uri_images` instead. `None` keeps every extension.
+    ///
+    /// Default: `None`
+    pub allowed_image_extensions: Option<Vec<String>>,
+
+    /// Drop images served from these hosts (e.g. tracking-pixel CDNs).
+    ///
+    /// A host also blocks its subdomains: `pixel.example.com` blocks
+    /// `a.pixel.example.com`. Relative image URLs are never blocked.
+    ///
+    /// Default: `[]` (empty)
+    pub blocked_image_hosts: Vec<String>,
+
+    /// Drop images whose source is an inline `data:` URI.
+    ///
+    /// Default: `false`
+    pub drop_data_uri_images: bool,
+
+    /// Cap on the number of images returned in `ExtractResult.images`.
+    ///
+    /// The first images in document order are kept, and the hero image is
+    /// always among them (it replaces the last kept image when it comes
+    /// later), so gallery pages with hundreds of images stay manageable.
+    /// `None` keeps every image.
+    ///
+    /// Default: `None`
+    pub max_images: Option<usize>,
+
+    /// Collect `<dt>`/`<dd>` pairs from the content into `ExtractResult.definitions`.
+    ///
+    /// For spec sheets and infoboxes marked up as description lists. Several
+    /// `<dd>` for one term are joined with `"; "`. The text and HTML
+    /// renderings are unchanged.
+    ///
+    /// Default: `false`
+    pub extract_definition_pairs: bool,
+
+    /// Collect machine-readable `<data value>`, `<meter>`, and `<progress>`
+    /// values into `ExtractResult.data_values` as `(label, value)` pairs.
+    ///
+    /// For data-oriented pages where the visible text is abbreviated
+    /// (`<data value="1000000">1M</data>` gives `("1M", "1000000")`).
+    /// Values inside navigation, headers, footers, and forms are skipped.
+    ///
+    /// Default: `false`
+    pub extract_data_values: bool,
+
+    /// URL schemes allowed in link `href`s in `content_html` and `clean_html`.
+    ///
+    /// Links with any other scheme (`javascript:`, `vbscript:`, `data:`, ...)
+    /// keep their text but lose the `href`. Relative links are always kept.
+    /// Compared case-insensitively, without the trailing `:`.
+    ///
+    /// Default: `["http", "https", "mailto", "tel"]`
+    pub allowed_link_schemes: Vec<String>,
+
+    /// Keep only the first link to each href in the content.
+    ///
+    /// Later links with the same href (e.g. a "Read the full story" link
+    /// repeated after every section) become plain text. Only applies with
+    /// `include_links`.
+    ///
+    /// Default: `false`
+    pub dedup_links: bool,
+
+    /// Keep link `title` attributes (tooltips) when `include_links` is set.
+    ///
+    /// Titles stay on the `<a>` in `content_html` and become
+    /// `[text](url "title")` in `content_markdown`.
+    ///
+    /// Default: `false`
+    pub preserve_link_titles: bool,
+
+    /// Start the content with the metadata title as a top-level heading.
+    ///
+    /// Adds an `<h1>` to `content_html`, a `# ` heading to
+    /// `content_markdown`, and a title line to `content_text`, for
+    /// self-contained exports. Skipped when the content already opens with a
+    /// heading matching the title, or when no title was found. Added after
+    /// the length checks, `content_hash` and language detection, so it
+    /// doesn't change them.
+    ///
+    /// Default: `false`
+    pub prepend_title: bool,
+
+    /// Serialize `content_html` as strict XHTML, e.g. for EPUB chapters.
+    ///
+    /// Void elements are self-closed (`<br/>`), every other element is
+    /// closed, tags are lowercase, and attributes are quoted. `<math>` gets
+    /// the MathML namespace; wrap the fragment in an `<html>` root carrying
+    /// the XHTML namespace.
+    ///
+    /// Default: `false`
+    pub xhtml_output: bool,
+
+    /// Keep math instead of dropping it.
+    ///
+    /// `<math>` elements stay in `content_html`; `content_text` and
+    /// `content_markdown` get the LaTeX source as `$...$` (`$$...$$` for
+    /// display math), or `[math]` when there is none. KaTeX and MathJax
+    /// output and `<span class="math">\(...\)</span>` LaTeX are recognised,
+    /// preferring the LaTeX annotation over the rendered markup.
+    ///
+    /// Default: `false`
+    pub preserve_math: bool,
+
+    /// Keep the `<title>` of inline SVGs as text at the SVG's position.
+    ///
+    /// Inline SVG is normally dropped; with this set, an accessible icon
+    /// such as `<svg><title>Download</title>...</svg>` contributes the word
+    /// "Download". SVGs without a title are still dropped.
+    ///
+    /// Default: `false`
+    pub svg_titles_as_text: bool,
+
+    /// Extract the content of declarative shadow DOM.
+    ///
+    /// Web components can render their content from a
+    /// `<template shadowrootmode="open">`, which HTML parsing keeps as an
+    /// inert template. With this set, each such template is inlined into its
+    /// host element before extraction, ahead of the host's own children.
+    ///
+    /// Default: `false`
+    pub parse_shadow_dom: bool,
+
+    /// Treat long image alt text as content.
+    ///
+    /// Infographics on data-journalism pages often carry their narrative in
+    /// the `alt` attribute. With this set, alt text of 200+ characters and
+    /// at least two sentences is added to the content as a paragraph after
+    /// the image. Short, decorative alt text is never included.
+    ///
+    /// Default: `false`
+    pub include_long_alt: bool,
+
+    /// Drop navigation lists made only of icon links.
+    ///
+    /// Icon-font links (`<a><i class="fa fa-home"></i></a>`) and single
+    /// emoji links carry no words for the boilerplate filters to catch and
+    /// leave stray symbols in the content. With this set, `<nav>`, `<ul>`,
+    /// `<ol>` and `<menu>` lists whose every item is such a link are removed.
+    ///
+    /// Default: `false`
+    pub remove_icon_navigation: bool,
+
+    /// Apply the WordPress block-editor profile to WordPress pages.
+    ///
+    /// Pages whose `<meta name="generator">` names WordPress have their
+    /// Gutenberg `wp-block-*` markup normalized before cleaning: button,
+    /// search and navigation blocks are removed, group and column wrappers
+    /// unwrapped, and quote/code/list blocks given their semantic elements.
+    ///
+    /// Default: `true`
+    pub wordpress_profile: bool,
+
+    /// Keep ruby annotations (e.g. Japanese furigana) in `content_text` as
+    /// `base(annotation)`.
+    ///
+    /// By default only the base text of `<ruby>` is kept, so
+    /// `<ruby>漢字<rt>かんじ</rt></ruby>` becomes "漢字" rather than
+    /// "漢字かんじ"; with this set it becomes "漢字(かんじ)".
+    ///
+    /// Default: `false`
+    pub keep_ruby_annotations: bool,
+
+    /// Mark emphasis in `content_text`: `*bold*` for `<strong>`/`<b>` and
+    /// `_italic_` for `<em>`/`<i>`.
+    ///
+    /// A lightweight alternative to `output_markdown` for terminal display.
+    /// Markup nested inside an emphasized element is flattened to its text.
+    ///
+    /// Default: `false`
+    pub text_emphasis_markers: bool,
+
+    /// Time budget for a single extraction.
+    ///
+    /// Checked cooperatively at stage boundaries (after document cleaning,
+    /// after main content extraction, and after fallback extraction), so a
+    /// single slow stage can overrun the budget before `Error::Timeout` is
+    /// returned. `None` disables the check.
+    ///
+    /// Default: `None`
+    pub max_duration: Option<std::time::Duration>,
+
+    /// Fail instead of returning a partial result when too little content
+    /// is found.
+    ///
+    /// Where the lenient default returns `Ok` with an `Insufficient` or
+    /// `Failed` status and a warning, strict mode returns
+    /// `Error::InsufficientContent` with the word and char counts, so
+    /// batch jobs can route such pages elsewhere without matching warnings.
+    ///
+    /// Default: `false`
+    pub strict: bool,
+
+    /// Keywords that mark extracted text as navigation rather than content.
+    ///
+    /// When three or more appear in the first 100 characters of the
+    /// extraction, a warning is added and the fallback extractors are
+    /// tried. `None` picks a built-in set from the document language
+    /// (English, German, French, Spanish, Italian, Portuguese, and Dutch),
+    /// falling back to English.
+    ///
+    /// Default: `None`
+    pub navigation_keywords: Option<Vec<String>>,
+
+    /// Write nested blockquote depth email-style in `content_markdown`
+    /// (threaded email or forum quoting).
+    ///
+    /// Each level adds one `>` with no space between markers (`> reply`,
+    /// `>> quoted reply`) instead of `> > quoted reply`. Nested quotes stay
+    /// nested in `content_html` either way.
+    ///
+    /// Default: `false`
+    pub preserve_quote_nesting: bool,
+
+    /// Generate `Metadata::description` from the content when the page
+    /// declares none.
+    ///
+    /// The opening sentences of the first paragraph are used, up to about
+    /// 160 characters and cut at a sentence boundary, and
+    /// `Metadata::description_generated` is set.
+    ///
+    /// Default: `false`
+    pub auto_description: bool,
+
+    /// Extra separators for stripping a site name from the page title, e.g.
+    /// `[" :: ", " ~ "]` for "Article :: Site".
+    ///
+    /// Tried after the built-in separators (`" | "`, `" • "`, `" » "`,
+    /// `" - "`, `" – "`, `" — "`), which are always used. Separators are
+    /// matched verbatim, so include the surrounding spaces.
+    ///
+    /// Default: empty
+    pub title_separators: Vec<String>,
+
+    /// Trailing-slash handling for extracted links, so `/foo` and `/foo/`
+    /// compare equal when deduplicating.
+    ///
+    /// Applied to links in `content_html` and `content_markdown`, to
+    /// `Metadata::related_links` and `social_links`, and to the metadata
+    /// URLs (`url`, `author_url`, `print_url`, `pdf_url`, `feed_url`).
+    /// Root paths and file-like paths (`/feed.xml`) are never changed.
+    ///
+    /// Default: `TrailingSlashPolicy::Preserve`
+    pub link_trailing_slash: TrailingSlashPolicy,
+
+    /// Line terminator used in `content_text`, `comments_text`, and
+    /// `content_markdown`.
+    ///
+    /// Default: `LineEnding::Lf`
+    pub line_ending: LineEnding,
+}
+
+/// How the trailing slash of extracted link paths is normalized.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TrailingSlashPolicy {
+    /// Keep links as written.
+    #[default]
+    Preserve,
+    /// Append a trailing slash (`/foo` becomes `/foo/`).
+    Add,
+    /// Drop the trailing slash (`/foo/` becomes `/foo`).
+    Remove,
+}
+
+/// Line terminator for text output.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LineEnding {
+    /// Unix-style `\n`.
+    #[default]
+    Lf,
+    /// Windows-style `\r\n`.
+    Crlf,
 }
 
 impl Default for Options {
@@ -242,13 +799,16 @@ impl Default for Options {
             favor_precision: false,
             favor_recall: false,
             target_language: None,
+            deduplicate_translations: false,
             url: None,
             author_blacklist: None,
+            clean_author_names: false,
             deduplicate: false,
             min_extracted_size: 200,
             // Story 6-1: Additional threshold defaults (from go-trafilatura settings.go)
             min_extracted_len: 200,
             max_extracted_len: 1_000_000,
+            max_paragraphs: None,
             min_output_size: 50,
             min_output_comm_size: 10,
             min_score: 1000,
@@ -261,10 +821,64 @@ impl Default for Options {
             min_word_length: 2,
             use_fallback_extraction: true,
             dedup_cache_size: 1000,
+            dedup_min_length: 100,
+            dedup_max_repeats: 2,
             include_title_in_content: false,
             // EPIC-02: Markdown output
             output_markdown: false,
             page_type: None,
+            separate_captions: false,
+            preserve_content_attributes: false,
+            include_asides: false,
+            merge_articles: false,
+            extract_footnotes: false,
+            inline_footnote_markers: false,
+            compute_quality_signals: false,
+            compute_link_density: false,
+            collect_timings: false,
+            track_offsets: false,
+            boilerplate_phrases: Vec::new(),
+            strip_taxonomy_lines: false,
+            keep_symbol_paragraphs: false,
+            inline_media_markers: false,
+            split_on_hr: false,
+            collect_related_links: false,
+            collect_social_links: false,
+            content_candidate_tags: Vec::new(),
+            content_scorer: None,
+            report_parse_errors: false,
+            annotate_structure: false,
+            normalize_tags: false,
+            exclude_selectors: Vec::new(),
+            tables_structured: false,
+            detect_paragraph_languages: false,
+            allowed_image_extensions: None,
+            blocked_image_hosts: Vec::new(),
+            drop_data_uri_images: false,
+            max_images: None,
+            extract_definition_pairs: false,
+            extract_data_values: false,
+            allowed_link_schemes: ["http", "https", "mailto", "tel"].map(String::from).to_vec(),
+            dedup_links: false,
+            preserve_link_titles: false,
+            prepend_title: false,
+            xhtml_output: false,
+            preserve_math: false,
+            svg_titles_as_text: false,
+            parse_shadow_dom: false,
+            include_long_alt: false,
+            remove_icon_navigation: false,
+            wordpress_profile: true,
+            keep_ruby_annotations: false,
+            text_emphasis_markers: false,
+            max_duration: None,
+            strict: false,
+            navigation_keywords: None,
+            preserve_quote_nesting: false,
+            auto_description: false,
+            title_separators: Vec::new(),
+            link_trailing_slash: TrailingSlashPolicy::Preserve,
+            line_ending: LineEnding::Lf,
         }
     }
 }
@@ -293,6 +907,7 @@ mod tests {
         // Story 6-1: New threshold fields
         assert_eq!(opts.min_extracted_len, 200);
         assert_eq!(opts.max_extracted_len, 1_000_000);
+        assert!(opts.max_paragraphs.is_none());
         assert_eq!(opts.min_output_size, 50);
         assert_eq!(opts.min_output_comm_size, 10);
         assert_eq!(opts.min_score, 1000);
@@ -305,9 +920,47 @@ mod tests {
         assert_eq!(opts.min_word_length, 2);
         assert!(opts.use_fallback_extraction);
         assert_eq!(opts.dedup_cache_size, 1000);
+        assert_eq!(opts.dedup_min_length, 100);
+        assert_eq!(opts.dedup_max_repeats, 2);
         assert!(!opts.include_title_in_content);
         // EPIC-02: Markdown output
         assert!(!opts.output_markdown);
+        assert!(!opts.separate_captions);
+        assert!(!opts.preserve_content_attributes);
+        assert!(!opts.include_asides);
+        assert!(!opts.merge_articles);
+        assert!(!opts.extract_footnotes);
+        assert!(!opts.inline_footnote_markers);
+        assert!(!opts.compute_quality_signals);
+        assert!(!opts.compute_link_density);
+        assert!(!opts.keep_symbol_paragraphs);
+        assert!(!opts.inline_media_markers);
+        assert!(!opts.collect_related_links);
+        assert!(opts.content_candidate_tags.is_empty());
+        assert!(!opts.report_parse_errors);
+        assert!(!opts.annotate_structure);
+        assert!(!opts.normalize_tags);
+        assert!(opts.exclude_selectors.is_empty());
+        assert!(!opts.tables_structured);
+        assert!(!opts.detect_paragraph_languages);
+        assert!(opts.allowed_image_extensions.is_none());
+        assert!(opts.blocked_image_hosts.is_empty());
+        assert!(!opts.drop_data_uri_images);
+        assert!(opts.max_images.is_none());
+        assert!(!opts.extract_definition_pairs);
+        assert!(opts.max_duration.is_none());
+        assert!(!opts.strict);
+        assert!(opts.navigation_keywords.is_none());
+        assert!(!opts.include_long_alt);
+        assert!(!opts.remove_icon_navigation);
+        assert!(opts.wordpress_profile);
+        assert!(!opts.text_emphasis_markers);
+        assert!(!opts.parse_shadow_dom);
+        assert!(!opts.preserve_quote_nesting);
+        assert!(!opts.auto_description);
+        assert!(opts.title_separators.is_empty());
+        assert_eq!(opts.link_trailing_slash, TrailingSlashPolicy::Preserve);
+        assert_eq!(opts.line_ending, LineEnding::Lf);
     }
 
     #[test]
@@ -6,7 +6,8 @@
 //! 2. **HTML-based signals** — structured data (JSON-LD, OpenGraph) for ambiguous URLs
 //!
 //! The detected page type can be used to select extraction strategies optimized
-//! for each content type.
+//! for each content type. [`classify_page`] runs the classification on its own,
+//! without extracting.
 //!
 //! # Example
 //!
@@ -1441,6 +1442,69 @@ fn contains_any(haystack: &str, needles: &[&str]) -> bool {
     needles.iter().any(|needle| haystack.contains(needle))
 }
 
+// ---------------------------------------------------------------------------
+// Stage 3: Combined classification
+// ---------------------------------------------------------------------------
+
+/// Classify a page from its HTML alone, without running extraction.
+///
+/// Runs the same HTML-signal and ML stages as [`extract`](crate::extract)
+/// (JSON-LD `@type`, `og:type`, forum markup, repeated product/listing
+/// items), so callers can route or skip pages cheaply. No URL is known, so
+/// the URL heuristics stage is neutral.
+///
+/// # Example
+///
+/// ```rust
+/// use rs_trafilatura::page_type::{classify_page, PageType};
+///
+/// let html = r#"<html><head>
+///     <script type="application/ld+json">{"@type": "Product", "name": "Widget"}</script>
+/// </head><body><h1>Widget</h1><button>Add to cart</button></body></html>"#;
+/// assert_eq!(classify_page(html), PageType::Product);
+/// ```
+#[must_use]
+pub fn classify_page(html: &str) -> PageType {
+    let doc = Document::from(html);
+    let metadata = crate::metadata::extract_metadata(&doc, &crate::Options::default());
+    classify_document(&doc, &metadata, "").0
+}
+
+/// Classify a parsed document: URL heuristics, then HTML signals, then the
+/// ML classifier. Returns the type with its classification confidence.
+pub(crate) fn classify_document(doc: &Document, metadata: &Metadata, url: &str) -> (PageType, Option<f64>) {
+    // Stage 1: URL heuristics (fast, high-precision for forum/docs)
+    let url_type = classify_url(url);
+
+    // Stage 2: HTML signal refinement (for ambiguous URLs)
+    let html_signals = extract_html_signals(doc, metadata);
+    let refined = refine_with_html_signals(url_type, &html_signals);
+
+    // Stage 3: ML classifier (final authority for remaining ambiguous pages)
+    // The ML sees URL and HTML features too (f[0]-f[13], f[34]-f[39]),
+    // so it can confirm or override the heuristic stages.
+    let ml_features = ml::extract_ml_features(doc, metadata, url);
+    let title_meta = format!(
+        "{} {}",
+        metadata.title.as_deref().unwrap_or(""),
+        metadata.description.as_deref().unwrap_or("")
+    );
+    let (ml_type, ml_conf) = ml::classify_ml(&ml_features, &title_meta);
+
+    // Use heuristic result when it's high-confidence (non-Article from URL)
+    // and the ML doesn't strongly disagree. Otherwise use ML.
+    if url_type != PageType::Article && ml_type == url_type {
+        // URL heuristics and ML agree — high confidence
+        (url_type, Some(1.0))
+    } else if refined != PageType::Article && ml_type == refined {
+        // HTML signals and ML agree
+        (refined, Some(0.95))
+    } else {
+        // Stages disagree — trust the ML (it sees all features)
+        (ml_type, Some(ml_conf))
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -2083,6 +2147,25 @@ mod tests {
         let refined = refine_with_html_signals(url_type, &signals);
         assert_eq!(refined, PageType::Product);
     }
+
+    #[test]
+    fn test_classify_page_from_html_only() {
+        let product = r#"<html><head>
+            <meta property="og:type" content="product">
+            <script type="application/ld+json">{"@type": "Product", "name": "Staple Tee", "offers": {"price": "25.00"}}</script>
+            </head><body><h1>Staple Tee</h1><p>Soft cotton tee.</p>
+            <span class="price">$25.00</span><button>Add to cart</button></body></html>"#;
+        assert_eq!(classify_page(product), PageType::Product);
+
+        let article = r#"<html><head>
+            <meta property="og:type" content="article">
+            <script type="application/ld+json">{"@type": "NewsArticle", "headline": "Harbour plans approved"}</script>
+            </head><body><article><h1>Harbour plans approved</h1>
+            <p>The council approved the harbour redevelopment on Tuesday after a long consultation.</p>
+            <p>Work on the new quay walls is expected to begin next spring and last two years.</p>
+            </article></body></html>"#;
+        assert_eq!(classify_page(article), PageType::Article);
+    }
 }
 
 
@@ -96,6 +96,13 @@ pub static COMMENT_ID: LazyLock<Regex> = LazyLock::new(|| {
         .expect("COMMENT_ID regex")
 });
 
+/// Matches `aria-label` text of `<article>` elements that are not the page's
+/// main article (comments, teasers, related-story cards).
+pub static SECONDARY_ARTICLE_LABEL: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\b(comments?|repl(?:y|ies)|responses?|discussion|teasers?|related|recommended|more\s+stories|read\s+next|up\s+next|sponsored|promoted|advertisement)\b")
+        .expect("SECONDARY_ARTICLE_LABEL regex")
+});
+
 // =============================================================================
 // Metadata Extraction Patterns
 // =============================================================================
@@ -132,6 +139,20 @@ pub static MULTIPLE_NEWLINES: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new(r"\n{3,}").expect("MULTIPLE_NEWLINES regex")
 });
 
+/// Matches the private-use sentinel standing in for a `{{image:N}}` marker
+/// of `Options::inline_media_markers` during extraction (so page text that
+/// happens to read `{{image:N}}` is left alone), capturing the whitespace
+/// before it and the index.
+pub static MEDIA_SENTINEL: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(\s*)\x{E000}image:(\d+)\x{E001}").expect("MEDIA_SENTINEL regex")
+});
+
+/// Matches a whole [`MEDIA_SENTINEL`] span as inserted into the HTML, with
+/// the spaces on both sides, so it can be removed from HTML outputs.
+pub static MEDIA_SENTINEL_SPAN: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r" ?\x{E000}image:\d+\x{E001} ?").expect("MEDIA_SENTINEL_SPAN regex")
+});
+
 /// Matches common separators used between article title and site name.
 pub static TITLE_SEPARATOR: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new(r"\s*[\|–—\-:]\s*").expect("TITLE_SEPARATOR regex")
@@ -0,0 +1,82 @@
+//! Text quality signals for thin/spun content detection.
+//!
+//! Cheap lexical statistics over the extracted text, intended as features
+//! for downstream spam or content-quality classifiers.
+
+use std::collections::HashMap;
+
+use crate::result::QualitySignals;
+
+/// Words shorter than this are ignored for the keyword-stuffing ratio, so
+/// function words ("the", "and", "of") don't dominate it.
+const MIN_KEYWORD_LEN: usize = 4;
+
+/// Computes quality signals for `text`, or `None` if it contains no words.
+///
+/// - Words are runs of alphanumeric characters (apostrophes included), lowercased.
+/// - Sentences are split on `.`, `!`, `?`, and line breaks.
+pub(crate) fn compute_quality_signals(text: &str) -> Option<QualitySignals> {
+    let words: Vec<String> = text
+        .split(|c: char| !(c.is_alphanumeric() || c == '\'' || c == '\u{2019}'))
+        .map(|w| w.trim_matches(|c| c == '\'' || c == '\u{2019}').to_lowercase())
+        .filter(|w| !w.is_empty())
+        .collect();
+    if words.is_empty() {
+        return None;
+    }
+
+    let sentence_count = text
+        .split(['.', '!', '?', '\n'])
+        .filter(|s| s.chars().any(char::is_alphanumeric))
+        .count()
+        .max(1);
+
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for word in &words {
+        *counts.entry(word.as_str()).or_insert(0) += 1;
+    }
+    let top_keyword_count = counts
+        .iter()
+        .filter(|(word, _)| word.chars().count() >= MIN_KEYWORD_LEN)
+        .map(|(_, count)| *count)
+        .max()
+        .unwrap_or(0);
+
+    let word_count = words.len() as f64;
+    Some(QualitySignals {
+        avg_sentence_length: word_count / sentence_count as f64,
+        type_token_ratio: counts.len() as f64 / word_count,
+        keyword_stuffing_ratio: top_keyword_count as f64 / word_count,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_text_has_no_signals() {
+        assert!(compute_quality_signals("  ... !!").is_none());
+    }
+
+    #[test]
+    fn signals_for_natural_text() {
+        let signals = compute_quality_signals("The cat sat on the mat. It was warm!")
+            .expect("text has words");
+
+        assert!((signals.avg_sentence_length - 4.5).abs() < 1e-9);
+        // 9 tokens, 8 distinct ("the" repeats)
+        assert!((signals.type_token_ratio - 8.0 / 9.0).abs() < 1e-9);
+        // Only "warm" qualifies as a keyword, once
+        assert!((signals.keyword_stuffing_ratio - 1.0 / 9.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn repeated_keyword_raises_stuffing_ratio() {
+        let signals = compute_quality_signals("Cheap shoes. Buy cheap shoes now. Cheap shoes online, cheap shoes today.")
+            .expect("text has words");
+
+        assert!((signals.keyword_stuffing_ratio - 4.0 / 12.0).abs() < 1e-9);
+        assert!(signals.type_token_ratio < 0.6);
+    }
+}
@@ -3,6 +3,9 @@
 //! This module defines the structured output from content extraction,
 //! including the main content and associated metadata.
 
+use std::collections::HashMap;
+use std::time::Duration;
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
@@ -26,6 +29,157 @@ pub struct ImageData {
 
     /// Whether this is the main/hero image for the page.
     pub is_hero: bool,
+
+    /// Art-direction `<source>` candidates when the image sits in a `<picture>`,
+    /// in document order. Empty for plain `<img>` elements.
+    pub sources: Vec<ImageSource>,
+}
+
+/// A `<source>` candidate of a `<picture>` element.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImageSource {
+    /// Image URL (the widest candidate of the `srcset` attribute).
+    pub src: String,
+
+    /// Media query from the `media` attribute, e.g. `(min-width: 1024px)`.
+    pub media: Option<String>,
+
+    /// MIME type from the `type` attribute, e.g. `image/webp`.
+    pub mime_type: Option<String>,
+}
+
+/// Video metadata declared through Open Graph `og:video*` properties.
+///
+/// Relative video URLs are resolved against the document URL when one is known.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VideoMetadata {
+    /// Video URL (from `og:video` or `og:video:url`).
+    pub url: Option<String>,
+
+    /// HTTPS video URL (from `og:video:secure_url`).
+    pub secure_url: Option<String>,
+
+    /// MIME type of the video (from `og:video:type`), e.g. `video/mp4`.
+    pub mime_type: Option<String>,
+
+    /// Player width in pixels (from `og:video:width`).
+    pub width: Option<u32>,
+
+    /// Player height in pixels (from `og:video:height`).
+    pub height: Option<u32>,
+
+    /// Duration in seconds (from `og:video:duration` or `video:duration`).
+    pub duration: Option<u64>,
+}
+
+/// Aggregate rating of the page subject (product, recipe, business, ...).
+///
+/// Parsed from Schema.org `AggregateRating` data.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Rating {
+    /// Average rating (`ratingValue`).
+    pub value: f64,
+
+    /// Best possible rating (`bestRating`), e.g. `5`.
+    pub best: Option<f64>,
+
+    /// Number of reviews (`reviewCount`, falling back to `ratingCount`).
+    pub count: Option<u64>,
+}
+
+/// Details of an event page (concert, meetup, exhibition, ...).
+///
+/// Parsed from Schema.org `Event` JSON-LD, including subtypes such as
+/// `MusicEvent` or `Festival`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EventData {
+    /// Event name (`name`).
+    pub name: Option<String>,
+
+    /// Start time (`startDate`), normalized to UTC.
+    pub start: Option<DateTime<Utc>>,
+
+    /// End time (`endDate`), normalized to UTC.
+    pub end: Option<DateTime<Utc>>,
+
+    /// Venue name (`location.name`, or `location` when it is plain text).
+    pub location_name: Option<String>,
+
+    /// Venue address (`location.address`), `PostalAddress` parts joined with `, `.
+    pub location_address: Option<String>,
+
+    /// Performer names (`performer`), in document order.
+    pub performers: Vec<String>,
+}
+
+/// Lexical quality signals computed from `content_text`.
+///
+/// Cheap indicators of thin, spun, or keyword-stuffed content.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct QualitySignals {
+    /// Average number of words per sentence.
+    pub avg_sentence_length: f64,
+
+    /// Distinct words divided by total words (vocabulary diversity, 0.0-1.0).
+    pub type_token_ratio: f64,
+
+    /// Share of all words taken by the most frequent word of 4+ characters (0.0-1.0).
+    pub keyword_stuffing_ratio: f64,
+}
+
+/// A data table from the extracted content.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExtractedTable {
+    /// Header cell text, from the `<th>` row or else the first row.
+    pub headers: Vec<String>,
+
+    /// Cell text of the remaining rows, in document order.
+    pub rows: Vec<Vec<String>>,
+}
+
+/// A paragraph of the content with its detected language.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LanguageSpan {
+    /// Paragraph text, as it appears in `content_text`.
+    pub text: String,
+
+    /// Detected language (ISO 639-1 code), or `None` if undetermined.
+    pub language: Option<String>,
+}
+
+/// One timestamped entry of a live blog.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LiveUpdate {
+    /// When the update was posted, from its `<time datetime>` (or a
+    /// parseable timestamp text); `None` if it can't be read.
+    pub timestamp: Option<DateTime<Utc>>,
+
+    /// Text of the update, one line per paragraph or heading.
+    pub text: String,
+}
+
+/// Outcome of an extraction, as a programmatic counterpart to the warnings.
+///
+/// When several apply, the most severe wins: `Failed`, `Insufficient`,
+/// `Truncated`, `FallbackUsed`, then `Ok`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ExtractionStatus {
+    /// Main content extracted and above the size thresholds.
+    #[default]
+    Ok,
+
+    /// Content came from a fallback or alternative source (baseline
+    /// extraction, JSON-LD, Discourse, Next.js, or SPA state data).
+    FallbackUsed,
+
+    /// Content was cut to `max_extracted_len`.
+    Truncated,
+
+    /// Content is below `min_output_size` or `min_extracted_len`.
+    Insufficient,
+
+    /// No content was extracted (metadata-only result).
+    Failed,
 }
 
 /// Result of content extraction from an HTML document.
@@ -55,6 +209,14 @@ pub struct ExtractResult {
     /// Images found in content with metadata (if `include_images` enabled).
     pub images: Vec<ImageData>,
 
+    /// Photo captions and credit lines separated from the main text
+    /// (if `separate_captions` enabled).
+    pub captions: Vec<String>,
+
+    /// Footnotes as `(marker, definition)` pairs in reference order
+    /// (if `extract_footnotes` enabled).
+    pub footnotes: Vec<(String, String)>,
+
     /// Extracted metadata about the document.
     pub metadata: Metadata,
 
@@ -74,6 +236,60 @@ pub struct ExtractResult {
     /// Pages scoring below ~0.6 are candidates for LLM fallback extraction.
     pub extraction_quality: f64,
 
+    /// Share of the extracted text that is link text (0.0 - 1.0).
+    ///
+    /// Computed on the final `content_html` when `compute_link_density` is
+    /// enabled (otherwise 0.0). Values above ~0.5 suggest a navigation or
+    /// listing region was extracted rather than an article.
+    pub link_density: f32,
+
+    /// Stable fingerprint of the normalized `content_text`.
+    ///
+    /// 64-bit FNV-1a hash computed by [`crate::content_hash`]. Identical text
+    /// always yields the same value, so it can be stored and compared across
+    /// crawl snapshots to detect unchanged content.
+    pub content_hash: u64,
+
+    /// Lexical quality signals of `content_text` (if `compute_quality_signals` enabled).
+    pub quality_signals: Option<QualitySignals>,
+
+    /// Data tables from the content (if `tables_structured` enabled).
+    pub tables: Vec<ExtractedTable>,
+
+    /// Description-list `(term, description)` pairs from the content
+    /// (if `extract_definition_pairs` enabled).
+    pub definitions: Vec<(String, String)>,
+
+    /// `(label, value)` pairs from `<data value>`, `<meter>`, and
+    /// `<progress>` elements (if `extract_data_values` enabled).
+    pub data_values: Vec<(String, String)>,
+
+    /// Paragraphs of `content_text` tagged with their detected language
+    /// (if `detect_paragraph_languages` enabled).
+    pub language_spans: Vec<LanguageSpan>,
+
+    /// Text of each `<hr>`-delimited section of the content, in order
+    /// (if `split_on_hr` enabled). Empty sections are skipped.
+    pub sections: Vec<String>,
+
+    /// Timestamped entries, in page order, when the page is a live blog;
+    /// empty otherwise. `content_text` still holds the whole stream.
+    pub live_updates: Vec<LiveUpdate>,
+
+    /// Byte ranges of the input HTML that each paragraph of `content_text`
+    /// came from, in order (if `track_offsets` enabled).
+    ///
+    /// Ranges are recovered by matching text, so they are approximate:
+    /// paragraphs whose text was rewritten beyond recognition are missing.
+    /// For [`extract_bytes`](crate::extract_bytes) they index the decoded
+    /// UTF-8 text, not the raw bytes.
+    pub source_spans: Vec<(usize, usize)>,
+
+    /// Overall outcome of the extraction; see [`ExtractionStatus`].
+    ///
+    /// Details are in `warnings`.
+    pub status: ExtractionStatus,
+
     /// Warnings encountered during extraction.
     ///
     /// Non-fatal issues that occurred during extraction, such as:
@@ -81,6 +297,13 @@ pub struct ExtractResult {
     /// - Individual metadata fields failed to extract
     /// - Recoverable parsing errors
     pub warnings: Vec<String>,
+
+    /// Wall-clock time per pipeline stage (if `collect_timings` enabled).
+    ///
+    /// Keys: `parse`, `metadata`, `classify`, `clean`, `images`,
+    /// `main_extraction`, `fallback`, `comments`, `postprocess`, `markdown`,
+    /// `validate`, and `total` for the whole call.
+    pub timings: Option<HashMap<String, Duration>>,
 }
 
 /// Metadata extracted from an HTML document.
@@ -95,6 +318,14 @@ pub struct Metadata {
     /// Author name(s).
     pub author: Option<String>,
 
+    /// The author string as found, before `Options::clean_author_names`
+    /// reduced it to a name. `None` when cleaning is off.
+    pub author_raw: Option<String>,
+
+    /// Author profile URL, from a URL-valued `article:author` (or other
+    /// author meta tag), resolved against the page URL.
+    pub author_url: Option<String>,
+
     /// Original URL of the document.
     pub url: Option<String>,
 
@@ -104,12 +335,28 @@ pub struct Metadata {
     /// Page description (meta description).
     pub description: Option<String>,
 
+    /// Whether `description` was generated from the content
+    /// (`Options::auto_description`) rather than declared by the page.
+    pub description_generated: bool,
+
     /// Site name (e.g., "New York Times").
     pub sitename: Option<String>,
 
     /// Publication or modification date.
     pub date: Option<DateTime<Utc>>,
 
+    /// Last-modified date, when declared separately (JSON-LD `dateModified`,
+    /// `article:modified_time`, `og:updated_time`).
+    pub date_modified: Option<DateTime<Utc>>,
+
+    /// Machine-readable value of the `<time datetime>` element that shows
+    /// `date`, as written (e.g. `2021-08-15`).
+    pub date_iso: Option<String>,
+
+    /// Human-readable text of that `<time>` element (e.g. "August 15,
+    /// 2021"), when it differs from `date_iso`.
+    pub date_display: Option<String>,
+
     /// Content categories.
     pub categories: Vec<String>,
 
@@ -120,6 +367,8 @@ pub struct Metadata {
     pub id: Option<String>,
 
     /// Content fingerprint/hash.
+    ///
+    /// Hex-encoded form of `ExtractResult::content_hash` (16 lowercase digits).
     pub fingerprint: Option<String>,
 
     /// License information.
@@ -128,9 +377,72 @@ pub struct Metadata {
     /// Detected content language (ISO 639-1 code).
     pub language: Option<String>,
 
+    /// Content locale from `og:locale`, region kept (e.g. `en_US`).
+    pub locale: Option<String>,
+
+    /// Alternate locales the page is available in, from `og:locale:alternate`
+    /// (normalized like [`Metadata::locale`], deduplicated, in document order).
+    pub alternates: Vec<String>,
+
     /// Main image URL.
     pub image: Option<String>,
 
+    /// Alt text of the in-content `<img>` showing [`Metadata::image`], if
+    /// the page has one. Filled in whether or not `include_images` is set.
+    pub image_alt: Option<String>,
+
+    /// Caption (`<figcaption>`) of the in-content image showing
+    /// [`Metadata::image`]. Filled in whether or not `include_images` is set.
+    pub image_caption: Option<String>,
+
     /// Page type classification (article, product, etc.).
     pub page_type: Option<String>,
+
+    /// Open Graph video metadata (`og:video*` properties).
+    pub video: Option<VideoMetadata>,
+
+    /// Aggregate rating from JSON-LD `AggregateRating` or `itemprop` microdata.
+    pub rating: Option<Rating>,
+
+    /// Event details from JSON-LD `Event` data; `None` on non-event pages.
+    pub event: Option<EventData>,
+
+    /// Brand color from `<meta name="theme-color">` (hex or `rgb()`/`rgba()`).
+    pub theme_color: Option<String>,
+
+    /// Site logo URL (JSON-LD publisher logo, else a masthead `<img>`).
+    pub logo: Option<String>,
+
+    /// Indexing directives from `<meta name="robots">` and `<meta name="googlebot">`,
+    /// lowercased (e.g. `noindex`, `nofollow`, `max-snippet:-1`).
+    pub robots: Vec<String>,
+
+    /// Absolute link targets from "related articles" / "recommended" widgets,
+    /// in document order. Only populated with `Options::collect_related_links`.
+    pub related_links: Vec<String>,
+
+    /// Absolute URL of the publisher's print version (`<a class="print-link">`,
+    /// `<link rel="alternate" media="print">`).
+    pub print_url: Option<String>,
+
+    /// Absolute URL of a PDF version (`<link rel="alternate"
+    /// type="application/pdf">`, `citation_pdf_url`, PDF download links).
+    pub pdf_url: Option<String>,
+
+    /// Digital Object Identifier of a scholarly article (`citation_doi`),
+    /// without a `doi:` or `https://doi.org/` prefix.
+    pub doi: Option<String>,
+
+    /// Publisher's social profile links (Twitter/X, Facebook, LinkedIn,
+    /// YouTube, ...) from the header and footer, in document order. Only
+    /// populated with `Options::collect_social_links`.
+    pub social_links: Vec<String>,
+
+    /// Absolute URL of the site's RSS/Atom feed. Only populated with
+    /// `Options::collect_social_links`.
+    pub feed_url: Option<String>,
+
+    /// Reading time as declared by the publisher (e.g. `"5 minutes"`), from a
+    /// `twitter:labelN`/`twitter:dataN` pair or a reading-time element.
+    pub declared_reading_time: Option<String>,
 }
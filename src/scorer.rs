@@ -0,0 +1,68 @@
+//! Pluggable scoring of main-content candidates.
+//!
+//! When no `<article>`/`<main>` (or profile) selector matches, the extractor
+//! scores every `div`/`section`/`article`/`main` element and keeps the best
+//! one. [`ContentScorer`] lets callers replace that scoring through
+//! [`Options::content_scorer`](crate::Options::content_scorer);
+//! [`DefaultScorer`] is the built-in heuristic.
+
+use std::fmt;
+
+use dom_query::{Document, Selection};
+
+/// Per-candidate data computed by the extractor before scoring.
+#[derive(Clone, Copy)]
+pub struct ScoringContext<'a> {
+    /// The whole parsed document.
+    pub document: &'a Document,
+    /// Whitespace-normalized text of the candidate node.
+    pub text: &'a str,
+    /// Length of `text` in bytes.
+    pub text_len: i64,
+    /// Number of ancestors between the candidate and `<body>`.
+    pub depth: i64,
+}
+
+/// Scores a candidate node; the highest-scoring node becomes the main content.
+///
+/// The best score must still clear a fixed threshold (500 with
+/// `favor_recall`, 1000 by default, 5000 with `favor_precision`), so custom
+/// scorers should return values on a similar scale.
+///
+/// # Example
+///
+/// ```rust
+/// use std::sync::Arc;
+/// use dom_query::Selection;
+/// use rs_trafilatura::{ContentScorer, Options, ScoringContext};
+///
+/// /// Scores by text length alone.
+/// #[derive(Debug)]
+/// struct TextLengthScorer;
+///
+/// impl ContentScorer for TextLengthScorer {
+///     fn score(&self, _node: &Selection, ctx: &ScoringContext) -> i64 {
+///         ctx.text_len
+///     }
+/// }
+///
+/// let options = Options {
+///     content_scorer: Some(Arc::new(TextLengthScorer)),
+///     ..Options::default()
+/// };
+/// ```
+pub trait ContentScorer: fmt::Debug + Send + Sync {
+    /// Score `node`; higher is more likely to be the main content.
+    fn score(&self, node: &Selection, ctx: &ScoringContext) -> i64;
+}
+
+/// The built-in heuristic: text length, paragraph/heading/sentence counts,
+/// class and id names, and a link-density penalty.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DefaultScorer;
+
+impl ContentScorer for DefaultScorer {
+    fn score(&self, node: &Selection, ctx: &ScoringContext) -> i64 {
+        crate::extract::score_content_node(node, ctx.text, ctx.text_len, ctx.document, ctx.depth)
+    }
+}
@@ -108,6 +108,55 @@ pub fn calculate_fscore(extracted: &str, expected: &str) -> FScore {
     FScore::new(precision, recall, fscore)
 }
 
+/// Word-level difference between extracted and expected text.
+///
+/// Uses the same tokenization as [`calculate_fscore`]: `missed` holds the
+/// false negatives and `spurious` the false positives that go into its
+/// recall and precision. Each token appears once, in order of first
+/// occurrence in its source text.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TokenDiff {
+    /// Expected (ground-truth) tokens missing from the extracted text.
+    pub missed: Vec<String>,
+    /// Extracted tokens that do not appear in the expected text.
+    pub spurious: Vec<String>,
+}
+
+/// List the tokens behind a low F-Score.
+///
+/// # Examples
+///
+/// ```
+/// use rs_trafilatura::scoring::diff_tokens;
+///
+/// let diff = diff_tokens("Share this The quick fox", "The quick brown fox");
+/// assert_eq!(diff.missed, vec!["brown"]);
+/// assert_eq!(diff.spurious, vec!["share", "this"]);
+/// ```
+#[must_use]
+pub fn diff_tokens(extracted: &str, expected: &str) -> TokenDiff {
+    let extracted_words = tokenize(extracted);
+    let expected_words = tokenize(expected);
+
+    let extracted_set: HashSet<&String> = extracted_words.iter().collect();
+    let expected_set: HashSet<&String> = expected_words.iter().collect();
+
+    TokenDiff {
+        missed: unique_not_in(&expected_words, &extracted_set),
+        spurious: unique_not_in(&extracted_words, &expected_set),
+    }
+}
+
+/// Tokens of `words` absent from `other`, deduplicated, in original order.
+fn unique_not_in(words: &[String], other: &HashSet<&String>) -> Vec<String> {
+    let mut seen = HashSet::new();
+    words
+        .iter()
+        .filter(|word| !other.contains(word) && seen.insert(*word))
+        .cloned()
+        .collect()
+}
+
 /// Tokenize text into words.
 ///
 /// Splits on whitespace, converts to lowercase, and filters out empty strings.
@@ -213,6 +262,27 @@ mod tests {
         assert_eq!(score.fscore, 1.0);
     }
 
+    #[test]
+    fn diff_tokens_lists_missed_and_spurious_in_order() {
+        let diff = diff_tokens(
+            "Menu Home the quick fox home jumps",
+            "The quick brown fox jumps over the lazy dog",
+        );
+        assert_eq!(diff.missed, vec!["brown", "over", "lazy", "dog"]);
+        assert_eq!(diff.spurious, vec!["menu", "home"]);
+    }
+
+    #[test]
+    fn diff_tokens_matches_fscore_counts() {
+        let extracted = "The quick brown fox";
+        let expected = "The quick brown fox jumps over the lazy dog";
+        let diff = diff_tokens(extracted, expected);
+        assert!(diff.spurious.is_empty());
+        // recall 0.5 = 4 found / (4 + 4 missed)
+        assert_eq!(diff.missed.len(), 4);
+        assert_eq!(diff_tokens(expected, expected), TokenDiff::default());
+    }
+
     #[test]
     fn tokenize_splits_and_lowercases() {
         let tokens = tokenize("The QUICK Brown Fox");
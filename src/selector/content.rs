@@ -7,11 +7,13 @@ use std::collections::HashSet;
 use dom_query::{NodeId, Selection};
 use crate::selector::utils::{contains, starts_with, lower, id, class, attr, tag};
 use crate::selector::Rule;
+use crate::patterns::SECONDARY_ARTICLE_LABEL;
 
 /// Content selector rules in priority order
 /// First match wins - check in order
 pub static CONTENT_RULES: &[Rule] = &[
     content_rule_1,
+    content_rule_data_marker,
     content_rule_2,
     content_rule_3,
     content_rule_4,
@@ -70,6 +72,8 @@ pub fn content_rule_1(sel: &Selection) -> bool {
         || contains(&id, "article__body")
         || contains(&class, "article__body")
         || item_prop == "articleBody"
+        // Microformats2 entry body
+        || class.split_whitespace().any(|token| token == "e-content")
         || contains(&lower(&id), "articlebody")
         || contains(&lower(&class), "articlebody")
         || id == "articleContent"
@@ -117,6 +121,55 @@ pub fn content_rule_1(sel: &Selection) -> bool {
         || contains(&id, "bodyContent")
 }
 
+/// Component-name attributes that React/Vue sites use in place of semantic classes.
+const DATA_MARKER_ATTRIBUTES: &[&str] = &["data-testid", "data-test-id", "data-component", "data-qa"];
+
+/// Rule 1b: Test-id / component-name article body markers
+///
+/// Matches: `data-testid="article-body"`, `data-component="ArticleBody"`,
+/// `data-qa="story-content"`, or a bare `article`/`story`/`content`/`body` value.
+/// Tags: article, div, main, section
+///
+/// Checked right after rule 1: these names are as specific as an `articleBody` class.
+#[must_use]
+pub fn content_rule_data_marker(sel: &Selection) -> bool {
+    if !matches!(tag(sel).as_str(), "article" | "div" | "main" | "section") {
+        return false;
+    }
+
+    let marked = DATA_MARKER_ATTRIBUTES.iter().any(|name| {
+        // "ArticleBody", "article-body", "article_body" all become "articlebody"
+        let value: String = lower(&attr(sel, name)).chars().filter(char::is_ascii_alphanumeric).collect();
+        if is_comment_name(&value) {
+            return false;
+        }
+        let names_subject = ["article", "story", "post", "entry"].iter().any(|s| value.contains(s));
+        let names_body = ["body", "content", "text"].iter().any(|s| value.contains(s));
+        (names_subject && names_body) || matches!(value.as_str(), "article" | "story" | "content" | "body")
+    });
+
+    // A "post-body" inside a comment thread is a comment, not the article
+    marked && !in_comment_scope(sel)
+}
+
+/// Whether a lowercased attribute value names a comment or reply.
+fn is_comment_name(value: &str) -> bool {
+    value.contains("comment") || value.contains("reply")
+}
+
+/// Whether any ancestor's class, id or data marker names a comment thread.
+fn in_comment_scope(sel: &Selection) -> bool {
+    let Some(node) = sel.nodes().first() else {
+        return false;
+    };
+    node.ancestors_it(None).any(|ancestor| {
+        ["class", "id"]
+            .iter()
+            .chain(DATA_MARKER_ATTRIBUTES)
+            .any(|name| ancestor.attr(name).is_some_and(|value| is_comment_name(&value.to_lowercase())))
+    })
+}
+
 /// Rule 2: Article/Story tag
 ///
 /// Matches `<article>` and `<story>` elements (story is used by some news sites)
@@ -171,6 +224,8 @@ pub fn content_rule_3(sel: &Selection) -> bool {
         || contains(&class, "field-body")
         || contains(&lower(&class), "fulltext")
         || role == "article"
+        // Microformats entry (h-entry, or hAtom's hentry)
+        || class.split_whitespace().any(|token| matches!(token, "h-entry" | "hentry"))
 }
 
 /// Rule 4: Generic content markers
@@ -284,6 +339,27 @@ pub fn content_rule_6(sel: &Selection) -> bool {
     true
 }
 
+/// Whether an element's accessible label (`aria-label`, else the text of the
+/// elements named by `aria-labelledby` within `root`) marks it as a comment,
+/// teaser, or related-story card rather than the main content.
+#[must_use]
+pub fn has_secondary_label(root: &Selection, sel: &Selection) -> bool {
+    use crate::dom;
+
+    let label = attr(sel, "aria-label");
+    let label = if label.trim().is_empty() {
+        let ids = attr(sel, "aria-labelledby");
+        ids.split_whitespace()
+            .filter(|id| !id.contains(['"', '\\']))
+            .map(|id| dom::text_content(&root.select(&format!("[id=\"{id}\"]"))).to_string())
+            .collect::<Vec<_>>()
+            .join(" ")
+    } else {
+        label
+    };
+    SECONDARY_ARTICLE_LABEL.is_match(&label)
+}
+
 /// Minimum text length for a content container to be considered valid.
 /// If a matched element has less text than this, we try its parent.
 /// Set to 1000 to better distinguish navigation menus from actual article content.
@@ -626,12 +702,19 @@ pub fn find_content<'a>(root: &Selection<'a>) -> Option<Selection<'a>> {
         // Get ALL elements matching this rule, not just the first
         let matches = query_all(root, *rule);
 
+        // Comment/teaser-labelled matches only qualify when nothing else matched
+        let has_primary = matches.iter().any(|el| !has_secondary_label(root, el));
+
         for element in matches {
             // Skip elements inside header/nav/aside (O(1) lookup per ancestor)
             if boilerplate_cache.is_inside_boilerplate(&element) {
                 continue;
             }
 
+            if has_primary && has_secondary_label(root, &element) {
+                continue;
+            }
+
             // Skip wrapper elements that contain more specific nested content elements.
             // These are layout wrappers (e.g., outer <div> wrapping sidebar + inner
             // <article class="content_main">).
@@ -719,6 +802,26 @@ mod tests {
         assert!(!content_rule_1(&span)); // span not in allowed tags
     }
 
+    #[test]
+    fn test_content_rule_data_marker() {
+        let doc = dom::parse(
+            r#"<div data-testid="article-body">a</div><section data-component="StoryContent">b</section>
+            <main data-qa="content">c</main><div data-testid="comments-body">d</div>
+            <div data-component="PromoCard">e</div><span data-testid="article-body">f</span>
+            <div data-testid="comment-post-body">g</div>
+            <section class="comments"><div data-testid="post-body">h</div></section>"#,
+        );
+        let matched: Vec<bool> = doc.select("body *").iter().map(|el| content_rule_data_marker(&el)).collect();
+        assert_eq!(matched, vec![true, true, true, false, false, false, false, false, false]);
+    }
+
+    #[test]
+    fn test_content_rule_1_microformats_e_content() {
+        let doc = dom::parse(r#"<div class="e-content">a</div><div class="summary e-contents">b</div>"#);
+        let matched: Vec<bool> = doc.select("div").iter().map(|el| content_rule_1(&el)).collect();
+        assert_eq!(matched, vec![true, false]);
+    }
+
     #[test]
     fn test_content_rule_2_article() {
         let doc = dom::parse("<article>content</article>");
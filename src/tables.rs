@@ -0,0 +1,237 @@
+//! Structured table output.
+//!
+//! Reads the data tables and description lists kept in `content_html` back
+//! into cells and key-value pairs, so callers don't have to re-parse the
+//! rendered HTML or text. Machine-readable `<data>`/`<meter>`/`<progress>`
+//! values are read from the page itself, since cleaning drops them.
+
+use crate::dom::{self, Document, Selection};
+use crate::result::ExtractedTable;
+
+/// Collects every table in `content_html` as header and row cell text.
+///
+/// - Headers come from the first row that contains `<th>` cells, or else
+///   the first row.
+/// - Rows of nested tables belong to the nested table, not the outer one.
+/// - Empty rows are skipped, as are tables without any cell text.
+pub(crate) fn extract_structured_tables(content_html: &str) -> Vec<ExtractedTable> {
+    let doc = dom::parse(content_html);
+    let mut tables = Vec::new();
+
+    for table_node in doc.select("table").nodes() {
+        let mut rows: Vec<(bool, Vec<String>)> = Vec::new();
+        for tr_node in Selection::from(*table_node).select("tr").nodes() {
+            if !nearest_table_is(tr_node, table_node) {
+                continue;
+            }
+            let mut has_th = false;
+            let mut cells = Vec::new();
+            for cell_node in Selection::from(*tr_node).select("td, th").nodes() {
+                if cell_node.parent().is_some_and(|p| p.id != tr_node.id) {
+                    continue;
+                }
+                has_th |= cell_node.has_name("th");
+                cells.push(cell_text(&Selection::from(*cell_node)));
+            }
+            if cells.iter().any(|c| !c.is_empty()) {
+                rows.push((has_th, cells));
+            }
+        }
+        if rows.is_empty() {
+            continue;
+        }
+
+        let header_idx = rows.iter().position(|(has_th, _)| *has_th).unwrap_or(0);
+        let headers = rows.remove(header_idx).1;
+        tables.push(ExtractedTable {
+            headers,
+            rows: rows.into_iter().map(|(_, cells)| cells).collect(),
+        });
+    }
+
+    tables
+}
+
+/// Collects `<dt>`/`<dd>` pairs from every description list in `content_html`.
+///
+/// - Several `<dd>` for one term are joined with `"; "`.
+/// - Consecutive `<dt>` share the `<dd>` that follow them.
+/// - Terms without a description, and descriptions without a term, are skipped.
+pub(crate) fn extract_definition_pairs(content_html: &str) -> Vec<(String, String)> {
+    let doc = dom::parse(content_html);
+    let mut pairs = Vec::new();
+
+    for dl_node in doc.select("dl").nodes() {
+        let mut terms: Vec<String> = Vec::new();
+        let mut values: Vec<String> = Vec::new();
+        for item in Selection::from(*dl_node).select("dt, dd").nodes() {
+            if !nearest_ancestor_is(item, dl_node, "dl") {
+                continue;
+            }
+            let text = cell_text(&Selection::from(*item));
+            if text.is_empty() {
+                continue;
+            }
+            if item.has_name("dt") {
+                if !values.is_empty() {
+                    flush_definitions(&mut pairs, &mut terms, &mut values);
+                }
+                terms.push(text);
+            } else if !terms.is_empty() {
+                values.push(text);
+            }
+        }
+        flush_definitions(&mut pairs, &mut terms, &mut values);
+    }
+
+    pairs
+}
+
+/// Pairs each pending term with the joined descriptions and resets both.
+fn flush_definitions(pairs: &mut Vec<(String, String)>, terms: &mut Vec<String>, values: &mut Vec<String>) {
+    if !values.is_empty() {
+        let value = values.join("; ");
+        pairs.extend(terms.drain(..).map(|term| (term, value.clone())));
+    }
+    terms.clear();
+    values.clear();
+}
+
+/// Page chrome whose values (cart counters, reading progress bars) aren't content.
+const DATA_VALUE_CHROME: &str = "nav, header, footer, aside, form, script, template";
+
+/// Collects `(label, value)` pairs from `<data value>`, `<meter>`, and
+/// `<progress>` elements outside page chrome.
+///
+/// - `<data>` is kept only when its `value` differs from its text
+///   (`<data value="1000000">1M</data>`); the label is the text.
+/// - `<meter>`/`<progress>` are labelled by their `<label for>`,
+///   `aria-label`, `title`, or fallback text, in that order.
+/// - Elements without a `value` are skipped.
+pub(crate) fn extract_data_values(doc: &Document) -> Vec<(String, String)> {
+    let mut pairs = Vec::new();
+
+    for node in doc.select("data[value], meter[value], progress[value]").nodes() {
+        let el = Selection::from(*node);
+        if el.ancestors(None).is(DATA_VALUE_CHROME) {
+            continue;
+        }
+        let value = el.attr("value").map(|v| v.trim().to_string()).unwrap_or_default();
+        let text = cell_text(&el);
+        if value.is_empty() {
+            continue;
+        }
+
+        let label = if node.has_name("data") {
+            if text.is_empty() || text == value {
+                continue;
+            }
+            text
+        } else {
+            let for_label = el
+                .attr("id")
+                .map(|id| cell_text(&doc.select(&format!("label[for=\"{}\"]", id.replace('"', "\\\"")))))
+                .filter(|label| !label.is_empty());
+            let Some(label) = for_label
+                .or_else(|| el.attr("aria-label").map(|l| l.trim().to_string()))
+                .or_else(|| el.attr("title").map(|t| t.trim().to_string()))
+                .filter(|label| !label.is_empty())
+                .or_else(|| (!text.is_empty()).then_some(text))
+            else {
+                continue;
+            };
+            label
+        };
+        pairs.push((label, value));
+    }
+
+    pairs
+}
+
+/// Element text with whitespace runs collapsed.
+fn cell_text(sel: &Selection) -> String {
+    dom::text_content(sel).split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Whether the closest `<table>` ancestor of `node` is `table`.
+fn nearest_table_is(node: &dom_query::NodeRef, table: &dom_query::NodeRef) -> bool {
+    nearest_ancestor_is(node, table, "table")
+}
+
+/// Whether the closest `tag` ancestor of `node` is `ancestor`.
+fn nearest_ancestor_is(node: &dom_query::NodeRef, ancestor: &dom_query::NodeRef, tag: &str) -> bool {
+    let mut current = node.parent();
+    while let Some(parent) = current {
+        if parent.has_name(tag) {
+            return parent.id == ancestor.id;
+        }
+        current = parent.parent();
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_row_is_header_without_th() {
+        let tables = extract_structured_tables(
+            "<table><tr><td>Name</td><td>Age</td></tr><tr><td>Ada</td><td>36</td></tr></table>",
+        );
+
+        assert_eq!(tables.len(), 1);
+        assert_eq!(tables[0].headers, vec!["Name", "Age"]);
+        assert_eq!(tables[0].rows, vec![vec!["Ada".to_string(), "36".to_string()]]);
+    }
+
+    #[test]
+    fn data_values_skip_chrome_and_matching_text() {
+        let doc = dom::parse(
+            r#"<nav><progress value="3" max="10">3 of 10</progress></nav>
+            <p>Raised <data value="1000000">1M</data> from <data value="12">12</data> donors.</p>
+            <label for="disk">Disk usage</label><meter id="disk" value="0.6">60%</meter>
+            <progress value="40" max="100" title="Upload"></progress>"#,
+        );
+
+        assert_eq!(
+            extract_data_values(&doc),
+            vec![
+                ("1M".to_string(), "1000000".to_string()),
+                ("Disk usage".to_string(), "0.6".to_string()),
+                ("Upload".to_string(), "40".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn definition_terms_share_following_descriptions() {
+        let pairs = extract_definition_pairs(
+            "<dl><dt>Color</dt><dt>Colour</dt><dd>Red</dd><dt>Size</dt><dd>S</dd><dd>M</dd>\
+             <dt>Orphan</dt></dl>",
+        );
+
+        assert_eq!(
+            pairs,
+            vec![
+                ("Color".to_string(), "Red".to_string()),
+                ("Colour".to_string(), "Red".to_string()),
+                ("Size".to_string(), "S; M".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn nested_table_rows_stay_with_nested_table() {
+        let tables = extract_structured_tables(
+            "<table><tr><th>Outer</th></tr><tr><td><table><tr><th>Inner</th></tr>\
+             <tr><td>x</td></tr></table></td></tr></table>",
+        );
+
+        assert_eq!(tables.len(), 2);
+        assert_eq!(tables[0].headers, vec!["Outer"]);
+        assert_eq!(tables[0].rows.len(), 1);
+        assert_eq!(tables[1].headers, vec!["Inner"]);
+        assert_eq!(tables[1].rows, vec![vec!["x".to_string()]]);
+    }
+}
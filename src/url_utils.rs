@@ -6,6 +6,8 @@
 
 use url::Url;
 
+use crate::options::TrailingSlashPolicy;
+
 /// Check if a string is a valid absolute URL.
 ///
 /// Go equivalent: `isAbsoluteURL(s)` (lines 15-35)
@@ -181,6 +183,27 @@ pub fn parse_url(url_str: &str) -> Option<Url> {
     }
 }
 
+/// Check whether a link target may be kept given an allowlist of schemes.
+///
+/// Relative URLs (no scheme) are always allowed. Schemes are compared
+/// case-insensitively, after dropping the whitespace and control characters
+/// browsers ignore, so `java\tscript:` is still recognised as `javascript:`.
+#[must_use]
+pub fn scheme_allowed(url_str: &str, allowed: &[String]) -> bool {
+    let url: String = url_str.chars().filter(|c| !c.is_ascii_whitespace() && !c.is_ascii_control()).collect();
+    let Some(colon) = url.find(':') else {
+        return true;
+    };
+    let scheme = &url[..colon];
+    let is_scheme = scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+        && scheme.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+    if !is_scheme {
+        // A ':' after a path, query, or fragment delimiter is not a scheme
+        return true;
+    }
+    allowed.iter().any(|a| a.eq_ignore_ascii_case(scheme))
+}
+
 /// Normalize a URL by removing fragments and normalizing path.
 #[must_use]
 pub fn normalize_url(url_str: &str) -> String {
@@ -200,6 +223,52 @@ pub fn normalize_url(url_str: &str) -> String {
     url.to_string()
 }
 
+/// Add or remove the trailing slash of a link's path according to `policy`.
+///
+/// Only http(s) and relative links are touched; the root path, paths whose
+/// last segment looks like a file (`/feed.xml`), and fragment-only links are
+/// left as they are. Query and fragment are kept.
+#[must_use]
+pub fn apply_trailing_slash(url_str: &str, policy: TrailingSlashPolicy) -> String {
+    if policy == TrailingSlashPolicy::Preserve || url_str.starts_with('#') {
+        return url_str.to_string();
+    }
+    let lower = url_str.to_ascii_lowercase();
+    let authority_start = if lower.starts_with("http://") || lower.starts_with("https://") {
+        url_str.find("//").map_or(0, |i| i + 2)
+    } else if lower.starts_with("//") {
+        2
+    } else if !scheme_allowed(url_str, &[]) {
+        // Some other scheme (mailto:, tel:, ...)
+        return url_str.to_string();
+    } else {
+        0
+    };
+
+    let suffix_start = url_str[authority_start..].find(['?', '#']).map_or(url_str.len(), |i| authority_start + i);
+    let path_start = if authority_start == 0 {
+        0
+    } else {
+        url_str[authority_start..suffix_start].find('/').map_or(suffix_start, |i| authority_start + i)
+    };
+    let (head, suffix) = url_str.split_at(suffix_start);
+    let path = &head[path_start..];
+
+    match policy {
+        TrailingSlashPolicy::Remove if path.len() > 1 && path.ends_with('/') => {
+            format!("{}{suffix}", head.trim_end_matches('/'))
+        }
+        TrailingSlashPolicy::Add if !path.ends_with('/') && !path.rsplit('/').next().unwrap_or("").contains('.') => {
+            if path.is_empty() && authority_start == 0 {
+                // A bare query or empty relative link has no path to extend
+                return url_str.to_string();
+            }
+            format!("{head}/{suffix}")
+        }
+        _ => url_str.to_string(),
+    }
+}
+
 /// Check if two URLs point to the same page (ignoring fragments).
 #[must_use]
 pub fn urls_match(url1: &str, url2: &str) -> bool {
@@ -280,6 +349,21 @@ pub fn filenames_match(url1: &str, url2: &str) -> bool {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_apply_trailing_slash() {
+        use TrailingSlashPolicy::{Add, Preserve, Remove};
+
+        assert_eq!(apply_trailing_slash("https://example.com/foo/?a=1#x", Remove), "https://example.com/foo?a=1#x");
+        assert_eq!(apply_trailing_slash("https://example.com/foo?a=1", Add), "https://example.com/foo/?a=1");
+        assert_eq!(apply_trailing_slash("https://example.com", Add), "https://example.com/");
+        assert_eq!(apply_trailing_slash("https://example.com/", Remove), "https://example.com/");
+        assert_eq!(apply_trailing_slash("/docs/guide", Add), "/docs/guide/");
+        assert_eq!(apply_trailing_slash("/feed.xml", Add), "/feed.xml");
+        assert_eq!(apply_trailing_slash("#top", Add), "#top");
+        assert_eq!(apply_trailing_slash("mailto:a@example.com", Add), "mailto:a@example.com");
+        assert_eq!(apply_trailing_slash("/foo/", Preserve), "/foo/");
+    }
+
     #[test]
     fn test_is_absolute_url_valid() {
         let (is_abs, url) = is_absolute_url("https://example.com/path");
@@ -620,4 +704,17 @@ mod tests {
         assert!(!filenames_match("https://example.com/", "https://example.com/"));
         assert!(!filenames_match("", ""));
     }
+
+    #[test]
+    fn test_scheme_allowed() {
+        let allowed = ["http", "https", "mailto", "tel"].map(String::from).to_vec();
+        assert!(scheme_allowed("https://example.com/", &allowed));
+        assert!(scheme_allowed("MAILTO:team@example.com", &allowed));
+        assert!(scheme_allowed("/docs/page?q=a:b", &allowed));
+        assert!(scheme_allowed("#section", &allowed));
+        assert!(!scheme_allowed("javascript:alert(1)", &allowed));
+        assert!(!scheme_allowed(" java\tscript:alert(1)", &allowed));
+        assert!(!scheme_allowed("data:text/html,<b>x</b>", &allowed));
+        assert!(!scheme_allowed("vbscript:msgbox", &allowed));
+    }
 }
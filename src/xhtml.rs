@@ -0,0 +1,141 @@
+//! Strict XHTML serialization of `content_html` for `Options::xhtml_output`.
+//!
+//! `content_html` is assembled from several sources (the filtered emitter,
+//! fallback extractors, Discourse HTML, post-processing passes), so rather
+//! than teaching each one XML rules, the finished fragment is re-parsed and
+//! written out once more as XHTML.
+
+use dom_query::{Document, NodeRef};
+
+/// HTML void elements, written self-closed (`<br/>`).
+const VOID_ELEMENTS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source",
+    "track", "wbr",
+];
+
+const MATHML_NAMESPACE: &str = "http://www.w3.org/1998/Math/MathML";
+const SVG_NAMESPACE: &str = "http://www.w3.org/2000/svg";
+
+/// Re-serialize an HTML fragment as well-formed XHTML.
+///
+/// Tags and attribute names are lowercased, attribute values quoted and
+/// escaped, void elements self-closed, and every other element explicitly
+/// closed. `<math>`/`<svg>` get their namespaces; the XHTML namespace itself
+/// belongs on the `<html>` root the caller wraps the fragment in. Comments
+/// and characters XML forbids are dropped.
+pub(crate) fn to_xhtml(html: &str) -> String {
+    let doc = Document::from(html);
+    let mut out = String::with_capacity(html.len() + html.len() / 8);
+    if let Some(body) = doc.select("body").nodes().first() {
+        for child in body.children() {
+            push_node(&child, &mut out);
+        }
+    }
+    out
+}
+
+fn push_node(node: &NodeRef, out: &mut String) {
+    if node.is_text() {
+        push_escaped(&node.text(), out);
+        return;
+    }
+    if !node.is_element() {
+        return;
+    }
+    let Some(name) = node.node_name() else {
+        return;
+    };
+    let name = name.to_ascii_lowercase();
+    if !is_xml_name(&name) {
+        // Unrepresentable tag: keep its content
+        for child in node.children() {
+            push_node(&child, out);
+        }
+        return;
+    }
+
+    out.push('<');
+    out.push_str(&name);
+    let mut has_xmlns = false;
+    for attr in node.attrs() {
+        let key = attr.name.local.to_ascii_lowercase();
+        if !is_xml_name(&key) {
+            continue;
+        }
+        has_xmlns |= &*key == "xmlns";
+        out.push(' ');
+        out.push_str(&key);
+        out.push_str("=\"");
+        push_escaped(&attr.value, out);
+        out.push('"');
+    }
+    let namespace = match name.as_str() {
+        "math" => Some(MATHML_NAMESPACE),
+        "svg" => Some(SVG_NAMESPACE),
+        _ => None,
+    };
+    if let (Some(namespace), false) = (namespace, has_xmlns) {
+        out.push_str(" xmlns=\"");
+        out.push_str(namespace);
+        out.push('"');
+    }
+
+    if VOID_ELEMENTS.contains(&name.as_str()) {
+        out.push_str("/>");
+        return;
+    }
+    out.push('>');
+    for child in node.children() {
+        push_node(&child, out);
+    }
+    out.push_str("</");
+    out.push_str(&name);
+    out.push('>');
+}
+
+/// Escape text or an attribute value, dropping characters invalid in XML 1.0.
+fn push_escaped(text: &str, out: &mut String) {
+    for ch in text.chars() {
+        match ch {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\t' | '\n' | '\r' => out.push(ch),
+            c if c.is_control() || matches!(c, '\u{fffe}' | '\u{ffff}') => {}
+            c => out.push(c),
+        }
+    }
+}
+
+/// Conservative XML name check (ASCII letters, digits, `-`, `_`, `.`).
+///
+/// Prefixed names like `fb:like` are rejected: their prefix has no namespace
+/// declaration, which XML parsers treat as an error.
+fn is_xml_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_xhtml_closes_voids_and_quotes_attributes() {
+        let xhtml = to_xhtml(r#"<P CLASS=lead>One<BR>two &amp; <img src=a.png alt='x "y"'></p><ul><li>open"#);
+        assert_eq!(
+            xhtml,
+            r#"<p class="lead">One<br/>two &amp; <img src="a.png" alt="x &quot;y&quot;"/></p><ul><li>open</li></ul>"#
+        );
+    }
+
+    #[test]
+    fn test_to_xhtml_adds_mathml_namespace() {
+        assert_eq!(
+            to_xhtml("<p><math><mi>x</mi></math></p>"),
+            format!(r#"<p><math xmlns="{MATHML_NAMESPACE}"><mi>x</mi></math></p>"#)
+        );
+    }
+}
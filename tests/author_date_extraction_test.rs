@@ -1,6 +1,6 @@
 use chrono::{TimeZone, Utc};
 
-use rs_trafilatura::extract;
+use rs_trafilatura::{extract, extract_with_options, Options};
 
 #[test]
 fn author_from_meta_is_extracted_and_cleaned() {
@@ -38,6 +38,27 @@ fn author_from_article_author_meta_is_extracted() {
     }
 }
 
+#[test]
+fn url_valued_article_author_goes_to_author_url() {
+    let html = r#"
+        <html>
+          <head>
+            <meta property="article:author" content="https://example.com/author/jane-doe" />
+          </head>
+          <body><article><p>Body</p></article></body>
+        </html>
+    "#;
+
+    let result = extract(html);
+    match result {
+        Ok(result) => {
+            assert_eq!(result.metadata.author_url.as_deref(), Some("https://example.com/author/jane-doe"));
+            assert_eq!(result.metadata.author.as_deref(), Some("Jane Doe"));
+        }
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+}
+
 #[test]
 fn author_from_class_selector_is_extracted_and_cleaned() {
     let html = r#"
@@ -255,3 +276,161 @@ fn date_is_none_when_time_element_is_empty() {
         Err(err) => panic!("expected Ok(_), got Err({err:?})"),
     }
 }
+
+#[test]
+fn modified_date_before_published_date_is_warned() {
+    let html = r#"
+        <html>
+          <head>
+            <meta property="article:published_time" content="2023-05-01T09:00:00Z" />
+            <meta property="article:modified_time" content="2021-02-03T09:00:00Z" />
+          </head>
+          <body><article><p>Body</p></article></body>
+        </html>
+    "#;
+
+    let result = extract(html);
+    match result {
+        Ok(result) => {
+            assert_eq!(
+                result.metadata.date_modified,
+                Some(Utc.with_ymd_and_hms(2021, 2, 3, 9, 0, 0).unwrap())
+            );
+            assert!(result
+                .warnings
+                .iter()
+                .any(|w| w == "Modified date 2021-02-03 precedes published date 2023-05-01"));
+        }
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+}
+
+#[test]
+fn implausible_dates_are_warned() {
+    let html = r#"
+        <html>
+          <head>
+            <script type="application/ld+json">
+              {"@type": "NewsArticle", "datePublished": "1970-01-01", "dateModified": "2999-01-01"}
+            </script>
+          </head>
+          <body><article><p>Body</p></article></body>
+        </html>
+    "#;
+
+    let result = extract(html);
+    match result {
+        Ok(result) => {
+            assert!(result.warnings.iter().any(|w| w == "Implausible published date: 1970-01-01 is before 1995"));
+            assert!(result.warnings.iter().any(|w| w == "Implausible modified date: 2999-01-01 is in the future"));
+        }
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+}
+
+#[test]
+fn author_names_are_cleaned_when_requested() {
+    let options = Options {
+        clean_author_names: true,
+        ..Options::default()
+    };
+
+    for (byline, expected) in [("By Jane Smith", "Jane Smith"), ("Jane Smith, Staff Writer", "Jane Smith")] {
+        let html = format!(
+            r#"<html><head><meta name="author" content="{byline}" /></head>
+            <body><article><p>Body</p></article></body></html>"#
+        );
+        match extract_with_options(&html, &options) {
+            Ok(result) => {
+                assert_eq!(result.metadata.author.as_deref(), Some(expected));
+                assert_eq!(result.metadata.author_raw.as_deref(), Some(byline));
+            }
+            Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+        }
+    }
+}
+
+#[test]
+fn h_entry_microformats_supply_content_and_metadata() {
+    let html = r#"
+        <html>
+          <body>
+            <nav><a href="/">Home</a> <a href="/notes">Notes</a></nav>
+            <div class="h-entry">
+              <h1 class="p-name">Growing tomatoes on a balcony</h1>
+              <p>by <a class="p-author h-card" href="https://jane.example">Jane Doe</a>
+                 on <time class="dt-published" datetime="2023-04-05">April 5th</time></p>
+              <div class="e-content">
+                <p>Tomatoes need at least six hours of direct sun a day, so a south-facing balcony is ideal for them.</p>
+                <p>Choose compact determinate varieties and large containers with good drainage holes at the bottom.</p>
+              </div>
+            </div>
+          </body>
+        </html>
+    "#;
+
+    let result = match extract_with_options(html, &Options::default()) {
+        Ok(result) => result,
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    };
+    assert_eq!(result.metadata.title.as_deref(), Some("Growing tomatoes on a balcony"));
+    assert_eq!(result.metadata.author.as_deref(), Some("Jane Doe"));
+    assert_eq!(result.metadata.date, Some(Utc.with_ymd_and_hms(2023, 4, 5, 0, 0, 0).unwrap()));
+    assert!(result.content_text.contains("six hours of direct sun"));
+    assert!(!result.content_text.contains("Home"));
+}
+
+#[test]
+fn citation_meta_tags_feed_scholarly_metadata() {
+    let html = r#"
+        <html>
+          <head>
+            <meta name="citation_title" content="Sediment transport in tidal estuaries" />
+            <meta name="citation_author" content="Okafor, Ada" />
+            <meta name="citation_author" content="Lindqvist, Erik" />
+            <meta name="citation_publication_date" content="2021-03-15" />
+            <meta name="citation_journal_title" content="Journal of Coastal Research" />
+            <meta name="citation_doi" content="10.1016/j.coastal.2021.0042" />
+          </head>
+          <body><article><p>Body</p></article></body>
+        </html>
+    "#;
+
+    let result = match extract(html) {
+        Ok(result) => result,
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    };
+    assert_eq!(result.metadata.title.as_deref(), Some("Sediment transport in tidal estuaries"));
+    assert_eq!(result.metadata.author.as_deref(), Some("Ada Okafor, Erik Lindqvist"));
+    assert_eq!(result.metadata.date, Some(Utc.with_ymd_and_hms(2021, 3, 15, 0, 0, 0).unwrap()));
+    assert_eq!(result.metadata.sitename.as_deref(), Some("Journal of Coastal Research"));
+    assert_eq!(result.metadata.doi.as_deref(), Some("10.1016/j.coastal.2021.0042"));
+}
+
+#[test]
+fn time_element_keeps_machine_value_and_display_text() {
+    let html = r#"
+        <html>
+          <body><article>
+            <p>Posted <time datetime="2021-08-15">August 15, 2021</time></p>
+            <p>Body</p>
+          </article></body>
+        </html>
+    "#;
+
+    let result = match extract(html) {
+        Ok(result) => result,
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    };
+    assert_eq!(result.metadata.date, Some(Utc.with_ymd_and_hms(2021, 8, 15, 0, 0, 0).unwrap()));
+    assert_eq!(result.metadata.date_iso.as_deref(), Some("2021-08-15"));
+    assert_eq!(result.metadata.date_display.as_deref(), Some("August 15, 2021"));
+
+    let same = r#"<html><body><article><time datetime="2021-08-15">2021-08-15</time><p>Body</p></article></body></html>"#;
+    let result = match extract(same) {
+        Ok(result) => result,
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    };
+    assert_eq!(result.metadata.date_iso.as_deref(), Some("2021-08-15"));
+    assert_eq!(result.metadata.date_display, None);
+}
@@ -1,4 +1,4 @@
-use rs_trafilatura::extract;
+use rs_trafilatura::{extract, extract_with_options, Options};
 
 /// Padding to ensure content extraction threshold is met (avoids fallback path)
 const PADDING: &str = "<p>Additional paragraph with enough content to ensure the extraction algorithm finds sufficient text density to extract this article content properly.</p><p>Second padding paragraph adding more sentences to satisfy the minimum scoring threshold required for content extraction to succeed.</p>";
@@ -77,6 +77,39 @@ fn aside_is_excluded_even_inside_article() {
     }
 }
 
+#[test]
+fn include_asides_keeps_article_pull_quote_but_drops_page_sidebar() {
+    let html = format!(r#"
+        <html>
+          <body>
+            <main>
+              <article>
+                <p>ARTICLE_BODY</p>
+                <aside><p>PULL_QUOTE_TEXT: "We rebuilt the harbour one stone at a time."</p></aside>
+                {PADDING}
+              </article>
+            </main>
+            <aside><p>PAGE_SIDEBAR_TEXT about our newsletter and upcoming events.</p></aside>
+          </body>
+        </html>
+    "#);
+    let options = Options {
+        include_asides: true,
+        ..Options::default()
+    };
+
+    let result = extract_with_options(&html, &options);
+    match result {
+        Ok(result) => {
+            assert!(result.content_text.contains("ARTICLE_BODY"));
+            assert!(result.content_text.contains("PULL_QUOTE_TEXT"),
+                "in-article aside should be kept; content_text={:?}", result.content_text);
+            assert!(!result.content_text.contains("PAGE_SIDEBAR_TEXT"));
+        }
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+}
+
 #[test]
 fn related_and_recommended_sections_are_excluded_by_class() {
     let html = format!(r#"
@@ -651,3 +684,162 @@ fn nested_aside_is_excluded() {
         Err(err) => panic!("expected Ok(_), got Err({err:?})"),
     }
 }
+
+#[test]
+fn related_links_are_collected_without_entering_content() {
+    let html = format!(r##"
+        <html>
+          <body>
+            <article>
+              <p>ARTICLE_BODY</p>
+              {PADDING}
+            </article>
+            <div class="related-articles">
+              <h3>RELATED_HEADING</h3>
+              <ul>
+                <li><a href="/news/harbour-plans">Harbour plans approved</a></li>
+                <li><a href="https://other.example.org/story">Elsewhere</a></li>
+                <li><a href="/news/harbour-plans">Harbour plans approved</a></li>
+                <li><a href="#top">Back to top</a></li>
+              </ul>
+            </div>
+          </body>
+        </html>
+    "##);
+    let options = Options {
+        collect_related_links: true,
+        url: Some("https://example.com/news/today".to_string()),
+        ..Options::default()
+    };
+
+    match extract_with_options(&html, &options) {
+        Ok(result) => {
+            assert!(result.content_text.contains("ARTICLE_BODY"));
+            assert!(!result.content_text.contains("RELATED_HEADING"));
+            assert_eq!(
+                result.metadata.related_links,
+                vec![
+                    "https://example.com/news/harbour-plans".to_string(),
+                    "https://other.example.org/story".to_string(),
+                ]
+            );
+        }
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+
+    match extract(&html) {
+        Ok(result) => assert!(result.metadata.related_links.is_empty()),
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+}
+
+#[test]
+fn clean_html_removes_boilerplate_without_extracting() {
+    let html = r#"<html><head><title>Harbour plans</title></head><body>
+        <nav class="menu"><a href="/">Home</a> <a href="/news">News</a></nav>
+        <div class="cookie-banner">We use cookies. Accept?</div>
+        <main>
+            <article><p>The council approved the harbour plans on Tuesday.</p>
+            <p>Read the <a href="/report.pdf">full report</a>.</p></article>
+            <aside class="sidebar">Trending now</aside>
+        </main>
+        <footer>Copyright 2024</footer>
+        <script>trackPageView();</script>
+    </body></html>"#;
+    let options = Options {
+        include_links: true,
+        url: Some("https://example.com/news/harbour".to_string()),
+        ..Options::default()
+    };
+
+    let cleaned = rs_trafilatura::clean_html(html, &options);
+
+    assert!(cleaned.contains("The council approved the harbour plans on Tuesday."));
+    assert!(cleaned.contains(r#"<a href="https://example.com/report.pdf">full report</a>"#), "{cleaned}");
+    for boilerplate in ["Home", "We use cookies", "Copyright 2024", "trackPageView", "Trending now"] {
+        assert!(!cleaned.contains(boilerplate), "{boilerplate} survived: {cleaned}");
+    }
+}
+
+#[test]
+fn social_links_and_feed_are_collected_from_footer() {
+    let html = format!(r##"
+        <html>
+          <head>
+            <link rel="alternate" type="application/rss+xml" title="Coastal Press" href="/feed.xml">
+          </head>
+          <body>
+            <article>
+              <p>ARTICLE_BODY</p>
+              {PADDING}
+            </article>
+            <footer>
+              <p>FOOTER_TEXT</p>
+              <a href="https://twitter.com/coastalpress">Follow us on Twitter</a>
+              <a href="/feed.xml">RSS</a>
+            </footer>
+          </body>
+        </html>
+    "##);
+    let options = Options {
+        collect_social_links: true,
+        url: Some("https://example.com/news/today".to_string()),
+        ..Options::default()
+    };
+
+    match extract_with_options(&html, &options) {
+        Ok(result) => {
+            assert!(result.content_text.contains("ARTICLE_BODY"));
+            assert!(!result.content_text.contains("FOOTER_TEXT"));
+            assert_eq!(result.metadata.social_links, vec!["https://twitter.com/coastalpress".to_string()]);
+            assert_eq!(result.metadata.feed_url.as_deref(), Some("https://example.com/feed.xml"));
+        }
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+
+    match extract(&html) {
+        Ok(result) => {
+            assert!(result.metadata.social_links.is_empty());
+            assert!(result.metadata.feed_url.is_none());
+        }
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+}
+
+#[test]
+fn icon_font_navigation_does_not_pollute_content() {
+    let html = format!(r#"
+        <html>
+          <body>
+            <article>
+              <ul class="article-tools">
+                <li><a href="/"><i class="fa fa-home"></i></a></li>
+                <li><a href="/search">🔍</a></li>
+                <li><a href="/menu"><span class="icon">☰</span></a></li>
+                <li><a href="/top"><i class="fa fa-arrow-up" aria-hidden="true"></i> ↑</a></li>
+              </ul>
+              <p>ARTICLE_BODY begins here with the story of the harbour.</p>
+              {PADDING}
+            </article>
+          </body>
+        </html>
+    "#);
+
+    let options = Options {
+        remove_icon_navigation: true,
+        ..Options::default()
+    };
+    match extract_with_options(&html, &options) {
+        Ok(result) => {
+            assert!(result.content_text.contains("ARTICLE_BODY"));
+            for symbol in ["🔍", "☰", "↑"] {
+                assert!(!result.content_text.contains(symbol), "{symbol} in {}", result.content_text);
+                let content_html = result.content_html.as_deref().unwrap_or_default();
+                assert!(!content_html.contains(symbol), "{symbol} in {content_html}");
+            }
+            let content_html = result.content_html.as_deref().unwrap_or_default();
+            assert!(!content_html.contains("<li></li>"), "{content_html}");
+        }
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+}
@@ -0,0 +1,84 @@
+use rs_trafilatura::{extract_with_options, Options};
+
+const ARTICLE: &str = r#"
+    <html>
+      <body>
+        <article>
+          <p>The old lighthouse on the northern cape has been restored after a decade of neglect.</p>
+          <div class="image-wrap"><img src="/img/lighthouse.jpg" alt="Lighthouse"><span>Photo: Maria Lopez / Coastal Press</span></div>
+          <p>Volunteers raised the funds through local events and a national appeal last winter.</p>
+          <p class="caption">The restored lantern room, seen from the keeper's cottage, now houses a small museum about the cape.</p>
+          <p>The site will open to visitors every weekend from the start of May until the end of September.</p>
+        </article>
+      </body>
+    </html>
+"#;
+
+#[test]
+fn captions_stay_in_body_by_default() {
+    let result = extract_with_options(ARTICLE, &Options::default());
+    match result {
+        Ok(result) => {
+            assert!(result.captions.is_empty());
+            let html = result.content_html.expect("content html should be present");
+            assert!(html.contains("Maria Lopez"));
+        }
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+}
+
+#[test]
+fn separate_captions_moves_caption_and_credit_text() {
+    let options = Options {
+        separate_captions: true,
+        ..Options::default()
+    };
+
+    let result = extract_with_options(ARTICLE, &options);
+    match result {
+        Ok(result) => {
+            assert!(!result.content_text.contains("Maria Lopez"), "{:?}", result.content_text);
+            assert!(!result.content_text.contains("restored lantern room"));
+            assert!(result.content_text.contains("Volunteers raised the funds"));
+            assert_eq!(
+                result.captions,
+                vec![
+                    "Photo: Maria Lopez / Coastal Press".to_string(),
+                    "The restored lantern room, seen from the keeper's cottage, now houses a small museum about the cape.".to_string(),
+                ]
+            );
+        }
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+}
+
+#[test]
+fn separate_captions_keeps_image_figcaptions() {
+    let html = r#"
+        <html>
+          <body>
+            <article>
+              <p>The exhibition brings together more than two hundred works from private collections.</p>
+              <figure><img src="https://example.com/painting.jpg" alt="Painting"><figcaption>Untitled, 1962, oil on canvas.</figcaption></figure>
+              <p>It runs until the end of the year and tickets are available from the museum website.</p>
+            </article>
+          </body>
+        </html>
+    "#;
+    let options = Options {
+        separate_captions: true,
+        include_images: true,
+        ..Options::default()
+    };
+
+    let result = extract_with_options(html, &options);
+    match result {
+        Ok(result) => {
+            assert_eq!(result.captions, vec!["Untitled, 1962, oil on canvas.".to_string()]);
+            assert!(!result.content_text.contains("oil on canvas"));
+            let image = result.images.first().expect("image should be extracted");
+            assert_eq!(image.caption.as_deref(), Some("Untitled, 1962, oil on canvas."));
+        }
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+}
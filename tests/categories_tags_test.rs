@@ -1,4 +1,4 @@
-use rs_trafilatura::extract;
+use rs_trafilatura::{extract, extract_with_options, Options};
 
 #[test]
 fn tags_collect_all_article_tag_meta_values() {
@@ -50,6 +50,29 @@ fn tags_parse_keywords_meta_comma_separated() {
     }
 }
 
+#[test]
+fn tags_merge_keywords_with_article_tags() {
+    let html = r#"
+        <html>
+          <head>
+            <meta property="article:tag" content="Rust" />
+            <meta property="article:tag" content="Web" />
+            <meta name="keywords" content="rust; Scraping, Web;" />
+            <meta name="news_keywords" content="Parsing" />
+          </head>
+          <body><article><p>Body</p></article></body>
+        </html>
+    "#;
+
+    let result = extract(html);
+    match result {
+        Ok(result) => {
+            assert_eq!(result.metadata.tags, vec!["Rust", "Web", "rust", "Scraping", "Parsing"]);
+        }
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+}
+
 #[test]
 fn categories_extract_article_section() {
     let html = r#"
@@ -162,3 +185,50 @@ fn tags_deduplicate_across_article_tag_and_keywords() {
         Err(err) => panic!("expected Ok(_), got Err({err:?})"),
     }
 }
+
+#[test]
+fn normalize_tags_collapses_case_duplicates() {
+    let html = r#"
+        <html>
+          <head>
+            <meta name="keywords" content="Politics, politics, &quot;Elections&quot;, POLITICS, elections" />
+          </head>
+          <body><article><p>Body</p></article></body>
+        </html>
+    "#;
+    let options = Options {
+        normalize_tags: true,
+        ..Options::default()
+    };
+
+    match extract_with_options(html, &options) {
+        Ok(result) => assert_eq!(result.metadata.tags, vec!["Politics", "Elections"]),
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+
+    match extract(html) {
+        Ok(result) => assert_eq!(result.metadata.tags.len(), 5),
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+}
+
+#[test]
+fn tags_merge_rel_tag_links_with_keywords() {
+    let html = r#"
+        <html>
+          <head>
+            <meta name="keywords" content="rust, programming" />
+          </head>
+          <body><article><p>Body</p>
+            <footer>Tags: <a rel="tag" href="/t/rust">Rust</a> <a rel="tag" href="/t/wasm">WebAssembly</a></footer>
+          </article>
+          <aside><h3>Tag cloud</h3><a rel="tag" href="/t/go">Go</a> <a rel="tag" href="/t/zig">Zig</a></aside>
+          </body>
+        </html>
+    "#;
+
+    match extract(html) {
+        Ok(result) => assert_eq!(result.metadata.tags, vec!["rust", "programming", "WebAssembly"]),
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+}
@@ -1,4 +1,4 @@
-use rs_trafilatura::{extract, extract_with_options, Options};
+use rs_trafilatura::{extract, extract_comments_only, extract_with_options, Options};
 
 /// Sufficient comment text to pass the minimum comment word count threshold (>= 10 words)
 const COMMENTS_CONTENT: &str = "<p>First comment with sufficient words to pass the minimum threshold requirement for comment extraction.</p><p>Second comment adding more content to ensure the word count is adequate for comment detection.</p>";
@@ -185,3 +185,61 @@ fn extract_detects_comment_list_class_via_regex_fallback() {
         Err(err) => panic!("expected Ok(_), got Err({err:?})"),
     }
 }
+
+#[test]
+fn extract_comments_only_matches_full_extraction() {
+    let html = format!(r#"
+        <html><body>
+            <article>{ARTICLE_CONTENT}</article>
+            <section class="comments">
+                {COMMENTS_CONTENT}
+            </section>
+        </body></html>
+    "#);
+
+    let options = Options {
+        include_comments: true,
+        ..Options::default()
+    };
+    let full = extract_with_options(&html, &options).expect("extraction failed");
+
+    match extract_comments_only(&html, &Options::default()) {
+        Ok((text, comments_html)) => {
+            let text = text.expect("expected comments text");
+            assert!(text.contains("First comment"));
+            assert!(!text.contains("Main article content"));
+            assert_eq!(Some(text), full.comments_text);
+            assert_eq!(comments_html, full.comments_html);
+        }
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+
+    match extract_comments_only(&format!("<html><body><article>{ARTICLE_CONTENT}</article></body></html>"), &Options::default()) {
+        Ok(comments) => assert_eq!(comments, (None, None)),
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+}
+
+#[test]
+fn extract_split_on_hr_leaves_no_markers_in_comments() {
+    let html = format!(r#"
+        <html><body>
+            <article>{ARTICLE_CONTENT}<hr>{ARTICLE_CONTENT}</article>
+            <section class="comments">
+                {COMMENTS_CONTENT}<hr>{COMMENTS_CONTENT}
+            </section>
+        </body></html>
+    "#);
+
+    let options = Options {
+        include_comments: true,
+        split_on_hr: true,
+        ..Options::default()
+    };
+
+    let result = extract_with_options(&html, &options).unwrap();
+    let comments_text = result.comments_text.expect("comments should be extracted");
+    assert!(comments_text.contains("First comment"));
+    assert!(!comments_text.contains("{{"), "{comments_text:?}");
+    assert!(!result.comments_html.unwrap_or_default().contains("{{"));
+}
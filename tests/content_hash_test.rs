@@ -0,0 +1,61 @@
+use rs_trafilatura::{content_hash, extract};
+
+const ARTICLE: &str = r#"
+    <html>
+      <body>
+        <article>
+          <p>The city council approved the new transit plan on Tuesday after months of debate.</p>
+          <p>Construction of the first line is expected to begin next spring and finish by 2028.</p>
+        </article>
+      </body>
+    </html>
+"#;
+
+#[test]
+fn content_hash_matches_normalized_text() {
+    let result = extract(ARTICLE);
+    match result {
+        Ok(result) => {
+            assert_eq!(result.content_hash, content_hash(&result.content_text));
+            assert_eq!(
+                result.metadata.fingerprint,
+                Some(format!("{:016x}", result.content_hash))
+            );
+        }
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+}
+
+#[test]
+fn content_hash_is_stable_across_extractions() {
+    let first = extract(ARTICLE).map(|r| r.content_hash);
+    let second = extract(ARTICLE).map(|r| r.content_hash);
+    match (first, second) {
+        (Ok(a), Ok(b)) => assert_eq!(a, b),
+        (a, b) => panic!("expected Ok(_) twice, got {a:?} and {b:?}"),
+    }
+}
+
+#[test]
+fn content_hash_ignores_markup_only_changes() {
+    let reformatted = ARTICLE.replace("<article>", "<article class=\"story\">\n\n");
+
+    let original = extract(ARTICLE).map(|r| r.content_hash);
+    let changed = extract(&reformatted).map(|r| r.content_hash);
+    match (original, changed) {
+        (Ok(a), Ok(b)) => assert_eq!(a, b),
+        (a, b) => panic!("expected Ok(_) twice, got {a:?} and {b:?}"),
+    }
+}
+
+#[test]
+fn content_hash_changes_with_text() {
+    let edited = ARTICLE.replace("Tuesday", "Wednesday");
+
+    let original = extract(ARTICLE).map(|r| r.content_hash);
+    let changed = extract(&edited).map(|r| r.content_hash);
+    match (original, changed) {
+        (Ok(a), Ok(b)) => assert_ne!(a, b),
+        (a, b) => panic!("expected Ok(_) twice, got {a:?} and {b:?}"),
+    }
+}
@@ -1,4 +1,4 @@
-use rs_trafilatura::extract;
+use rs_trafilatura::{extract, extract_with_options, Options};
 
 #[test]
 fn description_from_meta_description() {
@@ -173,3 +173,61 @@ fn language_from_meta_name_language() {
         Err(err) => panic!("expected Ok(_), got Err({err:?})"),
     }
 }
+
+#[test]
+fn auto_description_is_generated_from_first_paragraph_when_missing() {
+    let html = r#"
+        <html>
+          <body><article>
+            <h1>Harbour plan approved</h1>
+            <p>The council approved the new harbour plan on Tuesday. Work on the sea wall starts in spring. The project is expected to take two years and cost the city far more than first estimated.</p>
+            <p>Residents had campaigned for better flood protection since the storms of 2019 damaged dozens of homes.</p>
+          </article></body>
+        </html>
+    "#;
+    let options = Options {
+        auto_description: true,
+        ..Options::default()
+    };
+
+    match extract_with_options(html, &options) {
+        Ok(result) => {
+            assert_eq!(
+                result.metadata.description.as_deref(),
+                Some("The council approved the new harbour plan on Tuesday. Work on the sea wall starts in spring.")
+            );
+            assert!(result.metadata.description_generated);
+        }
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+
+    match extract(html) {
+        Ok(result) => {
+            assert_eq!(result.metadata.description, None);
+            assert!(!result.metadata.description_generated);
+        }
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+}
+
+#[test]
+fn auto_description_keeps_declared_description() {
+    let html = r#"
+        <html>
+          <head><meta name="description" content="Meta description" /></head>
+          <body><article><p>The council approved the new harbour plan on Tuesday after a long debate.</p></article></body>
+        </html>
+    "#;
+    let options = Options {
+        auto_description: true,
+        ..Options::default()
+    };
+
+    match extract_with_options(html, &options) {
+        Ok(result) => {
+            assert_eq!(result.metadata.description.as_deref(), Some("Meta description"));
+            assert!(!result.metadata.description_generated);
+        }
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+}
@@ -1,4 +1,4 @@
-use rs_trafilatura::{extract, Error};
+use rs_trafilatura::{extract, extract_url, extract_with_cleaned_doc, extract_with_options, Error, Options};
 
 #[test]
 fn extract_prefers_article_over_main() {
@@ -190,3 +190,567 @@ fn extract_article_nested_in_nav_uses_fallback() {
         Err(err) => panic!("expected Ok(_) or Err(NoContent), got Err({err:?})"),
     }
 }
+
+#[test]
+fn extract_uses_next_data_when_dom_is_client_rendered() {
+    let paragraph = "Researchers at the university have published a new study on coastal erosion. \
+        The team measured shoreline changes at forty sites over a period of ten years. \
+        Their results show that erosion rates have doubled since the previous survey. ";
+    let body = paragraph.repeat(4);
+    let html = format!(
+        r#"
+        <html>
+          <body>
+            <div id="__next"><div class="spinner">Loading...</div></div>
+            <script id="__NEXT_DATA__" type="application/json">
+              {{"props": {{"pageProps": {{"post": {{"title": "Coastal erosion", "content": "{body}"}}}}}}}}
+            </script>
+          </body>
+        </html>
+    "#
+    );
+
+    let result = extract(&html);
+    match result {
+        Ok(result) => {
+            assert!(result.content_text.contains("erosion rates have doubled"),
+                "content should come from __NEXT_DATA__: {:?}", result.content_text);
+            assert!(result.warnings.iter().any(|w| w.contains("Next.js")));
+        }
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+}
+
+#[test]
+fn extract_uses_apollo_state_when_dom_is_sparse() {
+    let paragraph = "The city library reopened its doors after a two-year renovation project. \
+        Visitors can now use a larger reading room and a new children's wing. \
+        Librarians said the number of daily visitors has already doubled. ";
+    let body = paragraph.repeat(4);
+    let html = format!(
+        r#"
+        <html>
+          <body>
+            <div id="root"><div class="spinner">Loading...</div></div>
+            <script>window.__APOLLO_STATE__ = {{"Article:42": {{"title": "Library reopens", "bodyText": "{body}"}}}};</script>
+          </body>
+        </html>
+    "#
+    );
+
+    let result = extract(&html);
+    match result {
+        Ok(result) => {
+            assert!(result.content_text.contains("number of daily visitors has already doubled"),
+                "content should come from the Apollo state: {:?}", result.content_text);
+            assert!(result.warnings.iter().any(|w| w.contains("JSON state")));
+        }
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+}
+
+#[test]
+fn extract_preserves_content_attributes_when_enabled() {
+    let html = r#"
+        <html>
+          <body>
+            <article>
+              <p class="lead" id="intro" style="font-weight:bold">The council approved the new budget on Tuesday after a long debate.</p>
+              <p class="body-text">Spending on libraries and parks will rise by ten percent next year, while road maintenance keeps last year's level.</p>
+              <p class="body-text">Several members argued for a larger housing fund, but the amendment was defeated by a narrow margin of two votes.</p>
+              <p class="body-text">The mayor said the final plan balances long-term investment with the need to keep local taxes stable for residents.</p>
+            </article>
+          </body>
+        </html>
+    "#;
+    let options = Options {
+        preserve_content_attributes: true,
+        ..Options::default()
+    };
+
+    let result = extract_with_options(html, &options);
+    match result {
+        Ok(result) => {
+            let content_html = result.content_html.expect("content html should be present");
+            assert!(content_html.contains(r#"class="lead""#), "{content_html}");
+            assert!(content_html.contains(r#"id="intro""#), "{content_html}");
+            assert!(content_html.contains(r#"class="body-text""#), "{content_html}");
+            assert!(!content_html.contains("style="), "{content_html}");
+        }
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+}
+
+#[test]
+fn extract_merges_live_blog_articles_when_enabled() {
+    let update = |marker: &str, time: &str| {
+        let detail = "<p>Reporters across the region are sending in observations from polling stations, \
+            with volunteers, officials and voters describing a steady flow of people throughout the period.</p>";
+        format!(
+            "<article><h2>{time} Update</h2><p>{marker}: Polling continues across the region as turnout figures are published.</p>{}</article>",
+            detail.repeat(6)
+        )
+    };
+    let html = format!(
+        "<html><body><main>{}{}{}</main></body></html>",
+        update("UPDATE_ONE", "09:05"),
+        update("UPDATE_TWO", "12:30"),
+        update("UPDATE_THREE", "18:45"),
+    );
+    let options = Options {
+        merge_articles: true,
+        ..Options::default()
+    };
+
+    let result = extract_with_options(&html, &options);
+    match result {
+        Ok(result) => {
+            let text = &result.content_text;
+            let one = text.find("UPDATE_ONE").expect("first update should be extracted");
+            let two = text.find("UPDATE_TWO").expect("second update should be extracted");
+            let three = text.find("UPDATE_THREE").expect("third update should be extracted");
+            assert!(one < two && two < three, "updates should keep document order: {text:?}");
+        }
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+}
+
+#[test]
+fn extract_merges_non_ascii_articles_by_chars() {
+    let update = |marker: &str| {
+        let detail = "<p>Корреспонденты со всего региона сообщают с избирательных участков: \
+            волонтёры, чиновники и избиратели описывают ровный поток людей весь день.</p>";
+        format!(
+            "<article><h2>{marker}</h2><p>{marker}: Голосование продолжается по всему региону.</p>{}</article>",
+            detail.repeat(8)
+        )
+    };
+    // Over the minimum in bytes, under it in chars
+    let short = "<article><p>SHORT_UPDATE: Участки закрываются в восемь вечера, подсчёт голосов начнётся сразу после закрытия.</p></article>";
+    let html = format!(
+        "<html><body><main>{}{}{short}{}</main></body></html>",
+        update("UPDATE_ONE"),
+        update("UPDATE_TWO"),
+        update("UPDATE_THREE"),
+    );
+    let options = Options {
+        merge_articles: true,
+        ..Options::default()
+    };
+
+    let merged = extract_with_options(&html, &options).unwrap().content_text;
+    assert!(merged.contains("UPDATE_TWO"), "{merged:?}");
+    assert!(merged.contains("UPDATE_THREE"), "{merged:?}");
+    assert!(!merged.contains("SHORT_UPDATE"), "{merged:?}");
+}
+
+#[test]
+fn content_candidate_tags_find_custom_element_container() {
+    let story: String = (1..=6)
+        .map(|i| {
+            format!(
+                "<p>Paragraph {i} of the story explains how the river flooded the lower town overnight, \
+                 and how volunteers worked until morning to move families to higher ground.</p>"
+            )
+        })
+        .collect();
+    let html = format!(
+        r#"<html><body>
+          <div class="top"><span>Edition: UK</span><span>Sign in to your account to manage newsletters and saved stories for later reading.</span></div>
+          <story-text>{story}</story-text>
+        </body></html>"#
+    );
+
+    let default_result = match extract(&html) {
+        Ok(result) => result,
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    };
+    assert!(default_result.content_text.contains("Sign in to your account"));
+
+    let options = Options {
+        content_candidate_tags: vec!["story-text".to_string()],
+        ..Options::default()
+    };
+    match extract_with_options(&html, &options) {
+        Ok(result) => {
+            assert!(result.content_text.contains("Paragraph 1 of the story"));
+            assert!(result.content_text.contains("Paragraph 6 of the story"));
+            assert!(!result.content_text.contains("Sign in to your account"));
+        }
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+
+    // Names that don't parse are skipped rather than panicking
+    let options = Options {
+        content_candidate_tags: ["", "story[", "1abc", "story-text"].map(String::from).to_vec(),
+        ..Options::default()
+    };
+    match extract_with_options(&html, &options) {
+        Ok(result) => assert!(result.content_text.contains("Paragraph 6 of the story")),
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+}
+
+#[test]
+fn exclude_selectors_drop_subtrees_from_content() {
+    let html = r#"
+        <html>
+          <body>
+            <article>
+              <p>The council approved the new cycle lanes after a long public consultation that drew hundreds of responses.</p>
+              <p>Work on the first section is expected to begin in the spring and finish before the end of the year.</p>
+              <div class="author-bio"><p>AUTHOR_BIO Jane covers transport and planning for the paper and has written about cities for a decade.</p></div>
+              <div class="editors-note"><p>EDITORS_NOTE This story was updated on Tuesday to include comments from the council leader.</p></div>
+              <p>Local businesses said they hoped the lanes would bring more customers to the high street.</p>
+            </article>
+          </body>
+        </html>
+    "#;
+
+    let default_result = match extract(html) {
+        Ok(result) => result,
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    };
+    assert!(default_result.content_text.contains("EDITORS_NOTE"));
+
+    let options = Options {
+        exclude_selectors: vec![".author-bio".to_string(), ".editors-note".to_string(), "[[invalid".to_string()],
+        ..Options::default()
+    };
+    match extract_with_options(html, &options) {
+        Ok(result) => {
+            assert!(!result.content_text.contains("AUTHOR_BIO"));
+            assert!(!result.content_text.contains("EDITORS_NOTE"));
+            assert!(!result.content_html.unwrap_or_default().contains("EDITORS_NOTE"));
+            assert!(result.content_text.contains("cycle lanes"));
+            assert!(result.content_text.contains("high street"));
+        }
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+}
+
+#[test]
+fn exclude_selectors_apply_when_the_fallback_path_wins() {
+    // No article or main element and short paragraphs, so the backup
+    // document and fallback extractors decide the content
+    let html = r#"
+        <html>
+          <body>
+            <div><span>Tide tables for the harbour are posted weekly.</span></div>
+            <div class="promo"><span>PROMO_BLOCK Subscribe for the weekly tide newsletter today.</span></div>
+            <div><span>Boats should check the gauge before leaving the quay.</span></div>
+          </body>
+        </html>
+    "#;
+
+    let options = Options {
+        exclude_selectors: vec![".promo".to_string()],
+        ..Options::default()
+    };
+    match extract_with_options(html, &options) {
+        Ok(result) => {
+            assert!(!result.content_text.contains("PROMO_BLOCK"));
+            assert!(!result.content_html.unwrap_or_default().contains("PROMO_BLOCK"));
+        }
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+}
+
+#[test]
+fn extract_picks_labelled_main_article_over_teasers_and_comments() {
+    let para = "The council approved the new transit plan after months of public hearings, \
+        committing funds for bus lanes, station upgrades and longer service hours across the city.";
+    let long_comment = "I attended every hearing and still think the plan ignores the northern suburbs, \
+        where residents have waited years for a reliable connection to the city centre.";
+    let rest = format!("<p>{para}</p>").repeat(6);
+    let html = format!(
+        r#"<html><body>
+            <article aria-label="Related story"><h3>TEASER_MARKER</h3><p>{para}</p></article>
+            <h1 id="headline">Council backs transit plan</h1>
+            <article aria-labelledby="headline">
+                <p>MAIN_ARTICLE_MARKER {para}</p>{rest}
+            </article>
+            <article aria-label="Comment by Sam"><p>COMMENT_MARKER {long_comment}</p><p>{long_comment}</p><p>{long_comment}</p></article>
+        </body></html>"#
+    );
+
+    let result = extract(&html);
+    match result {
+        Ok(result) => {
+            let text = &result.content_text;
+            assert!(text.contains("MAIN_ARTICLE_MARKER"), "{text:?}");
+            assert!(!text.contains("TEASER_MARKER"), "{text:?}");
+            assert!(!text.contains("COMMENT_MARKER"), "{text:?}");
+        }
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+}
+
+#[test]
+fn extract_uses_data_testid_content_container() {
+    let story: String = (1..=6)
+        .map(|i| {
+            format!(
+                "<p>Paragraph {i} of the report explains how the harbour authority plans to fund the new \
+                 breakwater, which contractors will bid, and when residents can expect the works to finish.</p>"
+            )
+        })
+        .collect();
+    let promos: String = (1..=12)
+        .map(|i| {
+            format!(
+                "<div><p>Promoted story {i}: a long teaser paragraph about an unrelated topic that the site \
+                 shows beside every article to keep readers clicking through to other pages.</p></div>"
+            )
+        })
+        .collect();
+    let html = format!(
+        r#"<html><body>
+        <div class="x1a2b"><div data-testid="article-body">{story}</div></div>
+        <div class="x9z8y">{promos}</div>
+        </body></html>"#
+    );
+
+    let result = extract(&html).expect("extraction should succeed");
+    assert!(result.content_text.contains("Paragraph 6 of the report"));
+    assert!(!result.content_text.contains("Promoted story"));
+}
+
+#[test]
+fn extract_collects_live_blog_updates() {
+    let html = r#"<html><head><title>Election night live</title></head><body>
+        <main>
+            <h1>Election night: live updates</h1>
+            <div class="liveblog">
+                <article class="live-update">
+                    <time datetime="2024-11-05T22:00:00Z">22:00</time>
+                    <h2>Polls close</h2>
+                    <p>Polling stations across the region have now closed and counting is under way.</p>
+                    <div class="share-tools"><a href="https://twitter.com/intent/tweet">Share</a></div>
+                </article>
+                <article class="live-update">
+                    <time datetime="2024-11-05T23:15:00Z">23:15</time>
+                    <p>The first result is in from the northern district, where turnout reached 68 percent.</p>
+                </article>
+                <article class="live-update">
+                    <time datetime="2024-11-06T00:40:00Z">00:40</time>
+                    <p>Officials expect the final count to be completed shortly after three in the morning.</p>
+                </article>
+            </div>
+        </main>
+    </body></html>"#;
+
+    let result = extract(html).expect("extraction failed");
+
+    assert_eq!(result.live_updates.len(), 3);
+    assert_eq!(
+        result.live_updates[0].text,
+        "Polls close\nPolling stations across the region have now closed and counting is under way."
+    );
+    assert_eq!(
+        result.live_updates[2].timestamp.map(|t| t.to_rfc3339()).as_deref(),
+        Some("2024-11-06T00:40:00+00:00")
+    );
+    assert!(result.content_text.contains("turnout reached 68 percent"));
+
+    let article = extract("<html><body><article><p>An ordinary article with a single paragraph of body text.</p></article></body></html>")
+        .expect("extraction failed");
+    assert!(article.live_updates.is_empty());
+}
+
+#[test]
+fn extract_with_cleaned_doc_returns_boilerplate_free_document() {
+    let html = r#"<html><body>
+        <nav><a href="/">Home</a> <a href="/about">About</a></nav>
+        <article>
+            <p>The harbour reopened on Monday after three weeks of repairs to the breakwater.</p>
+            <p>Fishing crews said the delay had cost them most of the spring season's catch.</p>
+        </article>
+        <div class="cookie-consent" role="dialog"><p>We use cookies.</p></div>
+        <script>track();</script>
+    </body></html>"#;
+
+    let (result, cleaned) = extract_with_cleaned_doc(html, &Options::default()).unwrap();
+    assert_eq!(result.content_text, extract(html).unwrap().content_text);
+
+    let body = cleaned.select("body");
+    assert!(body.text().contains("The harbour reopened on Monday"));
+    assert_eq!(cleaned.select("nav, script").length(), 0);
+    assert!(!body.text().contains("We use cookies."));
+
+    // Internal markers used during extraction don't leak into the returned document
+    let html = r##"<html><body><article>
+        <p><a href="#setup">Setup</a></p>
+        <h2 id="setup">Setup</h2>
+        <p>The harbour reopened on Monday after three weeks of repairs to the breakwater.</p>
+        <div><img src="boat.jpg"><span>Fishing boats back in the harbour.</span></div>
+    </article></body></html>"##;
+    let options = Options {
+        include_links: true,
+        separate_captions: true,
+        ..Options::default()
+    };
+    let (_, cleaned) = extract_with_cleaned_doc(html, &options).unwrap();
+    assert!(!cleaned.html().contains("data-rs-"), "{}", cleaned.html());
+}
+
+#[test]
+fn extract_strict_mode_errors_on_short_article() {
+    let html = "<html><body><article><p>Only a short teaser here.</p></article></body></html>";
+
+    let options = Options {
+        strict: true,
+        ..Options::default()
+    };
+    match extract_with_options(html, &options) {
+        Err(Error::InsufficientContent { words, chars }) => {
+            assert!(words < options.min_output_size);
+            assert!(chars < options.min_extracted_len);
+        }
+        other => panic!("expected Err(InsufficientContent), got {other:?}"),
+    }
+
+    // The lenient default still returns what it found
+    let result = extract(html).unwrap();
+    assert!(result.content_text.contains("Only a short teaser here."));
+}
+
+#[test]
+fn extract_length_limits_count_chars() {
+    // 100 CJK chars, 300 bytes: under the 200 minimum
+    let sentence = "港口城墙在冬季重建完成，第一批渔船本周回到内港。";
+    let text: String = sentence.chars().cycle().take(100).collect();
+    let html = format!("<html><body><article><p>{text}</p></article></body></html>");
+    let options = Options {
+        strict: true,
+        min_output_size: 0,
+        ..Options::default()
+    };
+    match extract_with_options(&html, &options) {
+        Err(Error::InsufficientContent { chars, .. }) => assert_eq!(chars, 100),
+        other => panic!("expected Err(InsufficientContent), got {other:?}"),
+    }
+
+    // The maximum cuts at exactly that many chars
+    let long: String = sentence.chars().cycle().take(400).collect();
+    let html = format!("<html><body><article><p>{long}</p></article></body></html>");
+    let options = Options {
+        max_extracted_len: 250,
+        ..Options::default()
+    };
+    let result = extract_with_options(&html, &options).unwrap();
+    assert_eq!(result.content_text, long.chars().take(250).collect::<String>());
+}
+
+#[test]
+fn extract_reads_declarative_shadow_dom_when_enabled() {
+    let html = r#"<html><body>
+        <nav><a href="/">Home</a></nav>
+        <blog-post>
+          <template shadowrootmode="open">
+            <article>
+              <h1>Why the river froze</h1>
+              <p>The river froze for the first time in forty years after a week of record low temperatures across the valley.</p>
+              <p>Residents skated between the two bridges while engineers monitored the ice thickness every few hours.</p>
+              <p>Meteorologists said a blocking high-pressure system kept cold air trapped over the region for eight days.</p>
+            </article>
+          </template>
+        </blog-post>
+    </body></html>"#;
+
+    let options = Options {
+        parse_shadow_dom: true,
+        ..Options::default()
+    };
+    let result = extract_with_options(html, &options).expect("extraction succeeds");
+    assert!(result.content_text.contains("record low temperatures"), "{}", result.content_text);
+    assert!(result.content_text.contains("blocking high-pressure system"), "{}", result.content_text);
+
+    let default = extract_with_options(html, &Options::default());
+    assert!(default.map_or(true, |r| !r.content_text.contains("record low temperatures")));
+}
+
+#[test]
+fn extract_url_uses_fetcher_and_resolves_against_url() {
+    let fetched = std::cell::RefCell::new(Vec::new());
+    let fetch = |url: &str| -> Result<Vec<u8>, String> {
+        fetched.borrow_mut().push(url.to_string());
+        Ok(br#"<html><body><article>
+            <p>The harbour plan was approved on Tuesday after months of public consultation.</p>
+            <p>Work on the sea wall starts in spring and should take two years to complete.</p>
+        </article>
+        <div class="related-articles"><ul><li><a href="/news/sea-wall">Sea wall budget</a></li></ul></div>
+        </body></html>"#
+            .to_vec())
+    };
+    let options = Options {
+        collect_related_links: true,
+        ..Options::default()
+    };
+
+    let result = extract_url("https://example.com/news/harbour", fetch, &options).expect("extraction succeeds");
+    assert_eq!(*fetched.borrow(), vec!["https://example.com/news/harbour".to_string()]);
+    assert_eq!(result.metadata.url.as_deref(), Some("https://example.com/news/harbour"));
+    assert!(result.content_text.contains("sea wall"));
+    assert_eq!(result.metadata.related_links, vec!["https://example.com/news/sea-wall".to_string()]);
+
+    let failing = |_: &str| -> Result<Vec<u8>, String> { Err("connection refused".to_string()) };
+    match extract_url("https://example.com/down", failing, &Options::default()) {
+        Err(Error::FetchError { url, message }) => {
+            assert_eq!(url, "https://example.com/down");
+            assert_eq!(message, "connection refused");
+        }
+        other => panic!("expected FetchError, got {other:?}"),
+    }
+}
+
+#[test]
+fn deduplicate_drops_repeated_paragraphs() {
+    let notice = "This story is part of our regional coverage, supported by readers who subscribe to the newsletter every week.";
+    let sections: String = (1..=5)
+        .map(|i| format!("<h2>Section {i}</h2><p>Report number {i} describes how the harbour crews spent their morning.</p><p>{notice}</p>"))
+        .collect();
+    let html = format!("<html><body><article><h1>Harbour diary</h1>{sections}</article></body></html>");
+
+    let plain = extract(&html).expect("extraction failed");
+    assert_eq!(plain.content_text.matches(notice).count(), 5);
+
+    let options = Options {
+        deduplicate: true,
+        ..Options::default()
+    };
+    let result = extract_with_options(&html, &options).expect("extraction failed");
+    // The first `dedup_max_repeats + 1` occurrences are kept
+    assert_eq!(result.content_text.matches(notice).count(), 3, "{}", result.content_text);
+    assert!(result.content_text.contains("Report number 5"));
+}
+
+#[test]
+fn extract_drops_editor_chrome_but_keeps_placeholder_images() {
+    let story = "<p>The harbour wall was rebuilt over the winter, and the first fishing boats \
+        returned to the inner basin this week after two years moored along the coast.</p>"
+        .repeat(4);
+    let html = format!(
+        r#"<html><body>
+          <div class="mce-tinymce mce-container"><div class="mce-toolbar">Bold Italic Underline</div></div>
+          <article>
+            <figure><img src="/photos/harbour.jpg" data-placeholder="blur" alt="Harbour wall"></figure>
+            <div class="mce-content-body" contenteditable="true">{story}<p>Type here...</p></div>
+          </article>
+        </body></html>"#
+    );
+    let options = Options {
+        include_images: true,
+        ..Options::default()
+    };
+
+    match extract_with_options(&html, &options) {
+        Ok(result) => {
+            assert!(result.content_text.contains("harbour wall was rebuilt"));
+            assert!(!result.content_text.contains("Bold Italic"));
+            assert!(!result.content_text.contains("Type here"));
+            assert!(result.images.iter().any(|image| image.src.ends_with("/photos/harbour.jpg")), "{:?}", result.images);
+        }
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+}
@@ -0,0 +1,70 @@
+use rs_trafilatura::{extract_with_options, Options};
+
+const ARTICLE: &str = r##"
+    <html>
+      <body>
+        <article>
+          <h1>Tidal energy in small harbours</h1>
+          <p>Small tidal turbines can supply a meaningful share of a harbour town's electricity demand.<sup><a href="#fn1" id="ref1">1</a></sup> Early trials in the north of the country showed stable output through the winter months.</p>
+          <p>Maintenance costs remain the main obstacle, although newer designs have cut them considerably.<sup><a href="#fn2" id="ref2">2</a></sup> Local councils are now studying joint procurement to reduce prices further.</p>
+          <p>Several harbour authorities have asked for a national framework to support the technology over the next decade.</p>
+          <ol class="notes">
+            <li id="fn1">Harbour Energy Survey, 2023, table 4. <a href="#ref1">↩</a></li>
+            <li id="fn2">Interview with the project engineer, March 2024. <a href="#ref2">↩</a></li>
+          </ol>
+        </article>
+      </body>
+    </html>
+"##;
+
+#[test]
+fn footnotes_are_not_collected_by_default() {
+    let result = extract_with_options(ARTICLE, &Options::default());
+    match result {
+        Ok(result) => assert!(result.footnotes.is_empty()),
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+}
+
+#[test]
+fn extract_footnotes_pairs_markers_with_definitions() {
+    let options = Options {
+        extract_footnotes: true,
+        inline_footnote_markers: true,
+        ..Options::default()
+    };
+
+    let result = extract_with_options(ARTICLE, &options);
+    match result {
+        Ok(result) => {
+            assert_eq!(
+                result.footnotes,
+                vec![
+                    ("1".to_string(), "Harbour Energy Survey, 2023, table 4.".to_string()),
+                    ("2".to_string(), "Interview with the project engineer, March 2024.".to_string()),
+                ]
+            );
+            assert!(result.content_text.contains("[1]"), "{:?}", result.content_text);
+            assert!(result.content_text.contains("[2]"), "{:?}", result.content_text);
+            assert!(!result.content_text.contains("Harbour Energy Survey"));
+        }
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+}
+
+#[test]
+fn footnote_markers_are_not_inlined_by_default() {
+    let options = Options {
+        extract_footnotes: true,
+        ..Options::default()
+    };
+
+    let result = extract_with_options(ARTICLE, &options);
+    match result {
+        Ok(result) => {
+            assert_eq!(result.footnotes.len(), 2);
+            assert!(!result.content_text.contains("[1]"), "{:?}", result.content_text);
+        }
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+}
@@ -1,4 +1,4 @@
-use rs_trafilatura::extract;
+use rs_trafilatura::{extract, extract_with_options, Options, TrailingSlashPolicy};
 
 const PADDING: &str = "<p>Additional paragraph content to ensure this document meets the minimum content threshold required for extraction to succeed.</p><p>Further padding paragraph with enough text to satisfy the scoring algorithm that evaluates content quality and density.</p>";
 
@@ -42,6 +42,28 @@ fn extract_preserves_inline_formatting_in_content_html() {
     }
 }
 
+#[test]
+fn extract_strips_disallowed_link_schemes_from_content_html() {
+    let html = format!(
+        r#"<article><p>See <a href="javascript:alert(1)">this</a>, <a href="JaVa&#9;Script:alert(2)">that</a>, <a href="mailto:team@example.com">mail us</a> and <a href="https://example.com/docs">the docs</a>.</p>{PADDING}</article>"#
+    );
+    let options = Options {
+        include_links: true,
+        ..Options::default()
+    };
+
+    let result = match extract_with_options(&html, &options) {
+        Ok(result) => result,
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    };
+    let content_html = result.content_html.expect("content html");
+
+    assert!(!content_html.to_ascii_lowercase().contains("script:"), "{content_html}");
+    assert!(content_html.contains("<a>this</a>"), "{content_html}");
+    assert!(content_html.contains(r#"<a href="mailto:team@example.com">mail us</a>"#), "{content_html}");
+    assert!(content_html.contains(r#"<a href="https://example.com/docs">the docs</a>"#), "{content_html}");
+}
+
 #[test]
 fn extract_preserves_list_structure_in_content_html() {
     let html = format!("<article><ul><li>Item 1</li><li>Item 2<ul><li>Nested</li></ul></li></ul>{PADDING}</article>");
@@ -149,3 +171,354 @@ fn extract_preserves_b_and_i_tags() {
         Err(err) => panic!("expected Ok(_), got Err({err:?})"),
     }
 }
+
+#[test]
+fn annotate_structure_labels_each_block() {
+    let html = format!(
+        r#"<html><body><article class="post">
+            <h2 class="section-title">Heading</h2>
+            <p class="lead">Para content here to be extracted.</p>
+            <blockquote class="pull">A quoted remark from the report.</blockquote>
+            <pre><code class="language-rust">fn main() {{}}</code></pre>
+            <ul class="facts"><li>First fact</li><li>Second fact</li></ul>
+            <table><tr><th>Quarter</th><th>Sales</th></tr><tr><td>Q1</td><td>120</td></tr><tr><td>Q2</td><td>135</td></tr></table>
+            <figure><img src="https://example.com/chart.png" alt="Chart"><figcaption>Quarterly figures</figcaption></figure>
+            {PADDING}
+        </article></body></html>"#
+    );
+    let options = Options {
+        annotate_structure: true,
+        include_images: true,
+        ..Options::default()
+    };
+
+    let result = match extract_with_options(&html, &options) {
+        Ok(result) => result,
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    };
+    let content_html = result.content_html.expect("content html");
+
+    assert!(content_html.contains(r#"<h2 data-block-type="heading">Heading</h2>"#));
+    assert!(content_html.contains(r#"<p data-block-type="body">Para content"#));
+    assert!(content_html.contains(r#"<blockquote data-block-type="quote">"#));
+    assert!(content_html.contains(r#"<pre data-block-type="code">"#));
+    assert!(content_html.contains(r#"<ul data-block-type="list">"#));
+    assert!(content_html.contains(r#"<table data-block-type="table">"#));
+    assert!(content_html.contains(r#"<figure data-block-type="figure">"#));
+    assert!(!content_html.contains("section-title"));
+    assert!(!content_html.contains("<li data-block-type"));
+
+    let plain = match extract(&html) {
+        Ok(result) => result,
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    };
+    assert!(!plain.content_html.unwrap_or_default().contains("data-block-type"));
+}
+
+#[test]
+fn extract_preserves_math_when_requested() {
+    let html = format!(
+        r#"<html><body><article>
+        <p>The identity <math><semantics><mrow><msup><mi>e</mi><mrow><mi>i</mi><mi>π</mi></mrow></msup><mo>+</mo><mn>1</mn><mo>=</mo><mn>0</mn></mrow><annotation encoding="application/x-tex">e^{{i\pi}} + 1 = 0</annotation></semantics></math> is famous.</p>
+        <p>Its series form is <span class="katex"><span class="katex-mathml"><math display="block"><semantics><mi>x</mi><annotation encoding="application/x-tex">\sum_n x^n</annotation></semantics></math></span><span class="katex-html">∑nxn</span></span> as well.</p>
+        {PADDING}
+        </article></body></html>"#
+    );
+    let options = Options {
+        preserve_math: true,
+        output_markdown: true,
+        ..Options::default()
+    };
+
+    let result = match extract_with_options(&html, &options) {
+        Ok(result) => result,
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    };
+    let content_html = result.content_html.as_deref().expect("content html");
+    assert!(content_html.contains("<math><semantics><mrow><msup><mi>e</mi>"));
+    assert!(result.content_text.contains(r"The identity $e^{i\pi} + 1 = 0$ is famous."));
+    assert!(result.content_text.contains(r"$$\sum_n x^n$$"));
+    assert!(!result.content_text.contains("∑nxn"));
+
+    let markdown = result.content_markdown.as_deref().expect("markdown");
+    assert!(markdown.contains(r"$e^{i\pi} + 1 = 0$"));
+    assert!(markdown.contains(r"$$\sum_n x^n$$"));
+
+    let plain = match extract(&html) {
+        Ok(result) => result,
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    };
+    assert!(!plain.content_html.unwrap_or_default().contains("<math"));
+}
+
+#[test]
+fn extract_dedup_links_keeps_first_occurrence_only() {
+    let html = format!(
+        r#"<html><body><article>
+        <p>Section one. <a href="https://example.com/full">Read the full story here</a></p>
+        <p>Section two. <a href="https://example.com/full">Read the full story here</a></p>
+        <p>Section three. <a href="https://example.com/full">Read the full story here</a></p>
+        {PADDING}
+        </article></body></html>"#
+    );
+    let options = Options {
+        include_links: true,
+        dedup_links: true,
+        ..Options::default()
+    };
+
+    let result = match extract_with_options(&html, &options) {
+        Ok(result) => result,
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    };
+    let content_html = result.content_html.as_deref().expect("content html");
+    assert_eq!(content_html.matches(r#"<a href="https://example.com/full">"#).count(), 1);
+    assert_eq!(content_html.matches("Read the full story here").count(), 3);
+    assert!(content_html.contains("<p>Section three. Read the full story here</p>"));
+
+    let options = Options {
+        include_links: true,
+        ..Options::default()
+    };
+    let result = match extract_with_options(&html, &options) {
+        Ok(result) => result,
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    };
+    let content_html = result.content_html.as_deref().expect("content html");
+    assert_eq!(content_html.matches(r#"<a href="https://example.com/full">"#).count(), 3);
+}
+
+#[test]
+fn extract_link_trailing_slash_policy_applies_to_all_links() {
+    let html = format!(
+        r#"<html><body><article>
+        <p>See <a href="https://example.com/guides/">the guides</a> and <a href="https://example.com/guides">the same guides</a>.</p>
+        {PADDING}
+        </article>
+        <div class="related-articles"><ul>
+          <li><a href="/news/harbour-plans/">Harbour plans approved</a></li>
+          <li><a href="/news/harbour-plans">Harbour plans approved</a></li>
+        </ul></div>
+        </body></html>"#
+    );
+
+    for (policy, expected) in [
+        (TrailingSlashPolicy::Remove, "https://example.com/guides"),
+        (TrailingSlashPolicy::Add, "https://example.com/guides/"),
+    ] {
+        let options = Options {
+            include_links: true,
+            dedup_links: true,
+            collect_related_links: true,
+            url: Some("https://example.com/news/today".to_string()),
+            link_trailing_slash: policy,
+            ..Options::default()
+        };
+        let result = match extract_with_options(&html, &options) {
+            Ok(result) => result,
+            Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+        };
+        let content_html = result.content_html.as_deref().expect("content html");
+        assert_eq!(content_html.matches("<a href=").count(), 1, "{content_html}");
+        assert!(content_html.contains(&format!(r#"<a href="{expected}">"#)), "{content_html}");
+
+        let related = format!("https://example.com/news/harbour-plans{}", if policy == TrailingSlashPolicy::Add { "/" } else { "" });
+        assert_eq!(result.metadata.related_links, vec![related]);
+        let page = format!("https://example.com/news/today{}", if policy == TrailingSlashPolicy::Add { "/" } else { "" });
+        assert_eq!(result.metadata.url.as_deref(), Some(page.as_str()));
+    }
+}
+
+#[test]
+fn extract_xhtml_output_parses_as_xml() {
+    let html = format!(
+        r#"<html><body><article>
+        <h2>Heading</h2>
+        <p>Line one<br>line two &amp; <a href="https://example.com/?a=1&b=2">a link</a></p>
+        <table><colgroup><col><col></colgroup><tr><th>Key</th><td>Value</td></tr></table>
+        <ul><li>Unclosed item<li>Another item</ul>
+        {PADDING}
+        </article></body></html>"#
+    );
+    let options = Options {
+        xhtml_output: true,
+        include_links: true,
+        include_tables: true,
+        ..Options::default()
+    };
+
+    let result = match extract_with_options(&html, &options) {
+        Ok(result) => result,
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    };
+    let content_html = result.content_html.as_deref().expect("content html");
+    assert!(content_html.contains("<br/>"), "{content_html}");
+    assert!(content_html.contains(r#"href="https://example.com/?a=1&amp;b=2""#), "{content_html}");
+
+    let document = format!(r#"<div xmlns="http://www.w3.org/1999/xhtml">{content_html}</div>"#);
+    if let Err(err) = roxmltree::Document::parse(&document) {
+        panic!("content_html is not well-formed XML ({err}): {content_html}");
+    }
+}
+
+#[test]
+fn extract_keeps_pre_whitespace_and_wraps_highlighted_code() {
+    let art = "  +--------+      +--------+\n  | client | ---> | server |\n  +--------+      +--------+";
+    let html = format!(
+        r#"<html><body><article>
+        <h2>Architecture</h2>
+        <p>The request flow between the two components looks like this.</p>
+        <pre>{art}</pre>
+        <pre class="language-rust">fn main() {{
+    println!("hello");
+}}</pre>
+        {PADDING}
+        </article></body></html>"#
+    );
+
+    let result = match extract(&html) {
+        Ok(result) => result,
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    };
+    let content_html = result.content_html.as_deref().expect("content html");
+
+    // ASCII art stays a plain <pre>, spacing intact
+    let escaped_art = art.replace('>', "&gt;");
+    assert!(content_html.contains(&format!("<pre>{escaped_art}</pre>")), "{content_html}");
+    assert!(
+        content_html.contains("<pre><code class=\"language-rust\">fn main() {\n    println!(&quot;hello&quot;);\n}</code></pre>"),
+        "{content_html}"
+    );
+}
+
+#[test]
+fn extract_keeps_link_titles_in_markdown() {
+    let html = format!(
+        r#"<html><body><article>
+        <p>Read the <a href="https://example.com/guide" title="Installation guide">setup notes</a> before upgrading.</p>
+        {PADDING}
+        </article></body></html>"#
+    );
+    let options = Options {
+        include_links: true,
+        output_markdown: true,
+        preserve_link_titles: true,
+        ..Options::default()
+    };
+
+    let result = match extract_with_options(&html, &options) {
+        Ok(result) => result,
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    };
+    let markdown = result.content_markdown.as_deref().expect("markdown");
+    assert!(
+        markdown.contains(r#"[setup notes](https://example.com/guide "Installation guide")"#),
+        "{markdown}"
+    );
+    let content_html = result.content_html.as_deref().expect("content html");
+    assert!(content_html.contains(r#"title="Installation guide""#), "{content_html}");
+}
+
+#[test]
+fn extract_keeps_table_of_contents_targets_with_links() {
+    let html = format!(
+        r##"<html><body><article>
+        <ul><li><a href="#setup">Setup</a></li><li><a href="#usage">Usage</a></li></ul>
+        <h2 id="setup">Setup</h2>
+        <p>Install the package with the usual tools and verify the checksum before running anything else.</p>
+        <h2><a name="usage"></a>Usage</h2>
+        <p>Run the binary with a config file path. It reads options and prints a report to standard output.</p>
+        <h2 id="unlinked">Notes</h2>
+        {PADDING}
+        </article></body></html>"##
+    );
+
+    let options = Options {
+        include_links: true,
+        url: Some("https://example.com/docs/tool".to_string()),
+        ..Options::default()
+    };
+    let result = extract_with_options(&html, &options).unwrap();
+    let content_html = result.content_html.unwrap();
+    assert!(content_html.contains(r##"<a href="#setup">Setup</a>"##), "{content_html}");
+    assert!(content_html.contains(r#"<h2 id="setup">Setup</h2>"#), "{content_html}");
+    assert!(content_html.contains(r#"<a id="usage"></a>Usage"#), "{content_html}");
+    assert!(!content_html.contains("unlinked"));
+    assert!(!content_html.contains("data-rs-"));
+
+    let default = extract(&html).unwrap().content_html.unwrap();
+    assert!(!default.contains("id="));
+}
+
+#[test]
+fn extract_normalizes_wordpress_gutenberg_blocks() {
+    let html = r#"<html><head><meta name="generator" content="WordPress 6.4.2"></head><body>
+        <article><div class="entry-content">
+        <!-- wp:paragraph --><p>The council approved the new cycle lanes on Tuesday after a long debate that ran late into the evening.</p><!-- /wp:paragraph -->
+        <!-- wp:quote --><div class="wp-block-quote is-style-large"><p>This is the safest option for children riding to school.</p></div><!-- /wp:quote -->
+        <!-- wp:group --><div class="wp-block-group is-style-well has-background"><div class="wp-block-group__inner-container">
+        <p>Construction starts in the spring and should take about four months to complete in full.</p>
+        </div></div><!-- /wp:group -->
+        <!-- wp:code --><div class="wp-block-code">lanes = 4</div><!-- /wp:code -->
+        <!-- wp:buttons --><div class="wp-block-buttons"><div class="wp-block-button"><a class="wp-block-button__link" href="/donate">Donate now</a></div></div><!-- /wp:buttons -->
+        <!-- wp:paragraph --><p>The final vote was seven to two, with one abstention from the transport committee chair.</p><!-- /wp:paragraph -->
+        </div></article></body></html>"#;
+
+    let result = extract_with_options(html, &Options::default()).unwrap();
+    let content_html = result.content_html.unwrap();
+    assert!(
+        content_html.contains("<blockquote><p>This is the safest option for children riding to school.</p></blockquote>"),
+        "{content_html}"
+    );
+    assert!(content_html.contains("<pre><code>lanes = 4</code></pre>"), "{content_html}");
+    // An `is-style-well` group is content, not a boilerplate "well"
+    assert!(result.content_text.contains("Construction starts in the spring"));
+    assert!(!result.content_text.contains("Donate now"));
+
+    let options = Options {
+        wordpress_profile: false,
+        ..Options::default()
+    };
+    let content_html = extract_with_options(html, &options).unwrap().content_html.unwrap();
+    assert!(!content_html.contains("<blockquote>"), "{content_html}");
+}
+
+#[test]
+fn extract_preserve_quote_nesting_marks_quote_depth_in_markdown() {
+    let html = format!(
+        r#"<html><body><article>
+        <p>Thursday works for me, I will update the invitation and let the whole team know today.</p>
+        <blockquote><p>Can we move the meeting to Thursday afternoon instead?</p>
+          <blockquote><p>The meeting is on Wednesday at ten in the main conference room.</p></blockquote>
+        </blockquote>
+        {PADDING}
+        </article></body></html>"#
+    );
+    let options = Options {
+        output_markdown: true,
+        preserve_quote_nesting: true,
+        ..Options::default()
+    };
+
+    let result = match extract_with_options(&html, &options) {
+        Ok(result) => result,
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    };
+    let content_html = result.content_html.as_deref().expect("content html");
+    assert_eq!(content_html.matches("<blockquote>").count(), 2, "{content_html}");
+    let markdown = result.content_markdown.as_deref().expect("content markdown");
+    assert!(markdown.contains("\n> Can we move the meeting to Thursday afternoon instead?"), "{markdown}");
+    assert!(markdown.contains("\n>> The meeting is on Wednesday at ten"), "{markdown}");
+
+    // Without the option the nesting is still there, in spaced markers
+    let options = Options {
+        output_markdown: true,
+        ..Options::default()
+    };
+    let result = match extract_with_options(&html, &options) {
+        Ok(result) => result,
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    };
+    let markdown = result.content_markdown.as_deref().expect("content markdown");
+    assert!(markdown.contains("> > The meeting is on Wednesday at ten"), "{markdown}");
+}
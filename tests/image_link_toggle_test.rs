@@ -1,4 +1,4 @@
-use rs_trafilatura::{extract_with_options, ImageData, Options};
+use rs_trafilatura::{count_content_images, extract_with_options, ImageData, Options};
 
 /// Helper to check if images contain a URL
 fn images_contain_src(images: &[ImageData], src: &str) -> bool {
@@ -416,6 +416,36 @@ fn figcaption_extracted_from_figure() {
     assert_eq!(img.caption, Some("This is the caption for the photo.".to_string()));
 }
 
+/// Test that a figcaption repeating the alt text is dropped
+#[test]
+fn figcaption_matching_alt_is_dropped() {
+    let html = r#"
+        <html><body>
+            <article>
+                <p>Article content.</p>
+                <figure>
+                    <img src="https://example.com/photo.jpg" alt="Sunset over the  harbour">
+                    <figcaption>
+                        sunset over the harbour
+                    </figcaption>
+                </figure>
+            </article>
+        </body></html>
+    "#;
+
+    let options = Options {
+        include_images: true,
+        ..Options::default()
+    };
+
+    let result = extract_with_options(html, &options).expect("extraction failed");
+
+    assert_eq!(result.images.len(), 1);
+    let img = &result.images[0];
+    assert_eq!(img.alt.as_deref(), Some("Sunset over the  harbour"));
+    assert_eq!(img.caption, None);
+}
+
 /// Test that figcaption whitespace is normalized
 #[test]
 fn figcaption_whitespace_normalized() {
@@ -820,3 +850,412 @@ fn figure_images_not_duplicated() {
     assert_eq!(result.images[0].src, "https://example.com/in-figure.jpg");
     assert_eq!(result.images[0].caption, Some("Figure caption".to_string()));
 }
+
+#[test]
+fn include_images_prefers_desktop_source_of_art_directed_picture() {
+    let html = r#"
+        <html>
+          <body>
+            <article>
+              <p>The new bridge opened to traffic this morning after three years of construction work.</p>
+              <picture>
+                <source media="(max-width: 599px)" srcset="https://example.com/bridge-mobile.jpg">
+                <source media="(min-width: 600px)" srcset="https://example.com/bridge-tablet.jpg">
+                <source media="(min-width: 1200px)" srcset="https://example.com/bridge-desktop.jpg 1200w, https://example.com/bridge-desktop-2x.jpg 2400w">
+                <img src="https://example.com/bridge-mobile.jpg" alt="Bridge">
+              </picture>
+              <p>Commuters said the crossing cut their journey time into the city by almost half.</p>
+            </article>
+          </body>
+        </html>
+    "#;
+    let options = Options {
+        include_images: true,
+        ..Options::default()
+    };
+
+    let result = extract_with_options(html, &options).expect("extraction should succeed");
+
+    assert_eq!(result.images.len(), 1);
+    let image = &result.images[0];
+    assert_eq!(image.src, "https://example.com/bridge-desktop-2x.jpg");
+    assert_eq!(image.alt.as_deref(), Some("Bridge"));
+    let media: Vec<Option<&str>> = image.sources.iter().map(|s| s.media.as_deref()).collect();
+    assert_eq!(
+        media,
+        vec![
+            Some("(max-width: 599px)"),
+            Some("(min-width: 600px)"),
+            Some("(min-width: 1200px)"),
+        ]
+    );
+    assert_eq!(image.sources[0].src, "https://example.com/bridge-mobile.jpg");
+}
+
+#[test]
+fn inline_media_markers_place_images_in_text() {
+    let html = r#"
+        <html>
+          <body>
+            <article>
+              <p>The expedition set off from the harbour at dawn, loaded with supplies for a month at sea.</p>
+              <img src="https://example.com/harbour.jpg" alt="Harbour">
+              <p>By the third week the crew had sighted the northern ice shelf for the first time.</p>
+              <img src="https://example.com/ice-shelf.jpg" alt="Ice shelf">
+              <p>They returned home in early autumn with samples that are still being studied today.</p>
+            </article>
+          </body>
+        </html>
+    "#;
+    let options = Options {
+        include_images: true,
+        inline_media_markers: true,
+        ..Options::default()
+    };
+
+    let result = extract_with_options(html, &options).expect("extraction should succeed");
+
+    assert_eq!(result.images.len(), 2);
+    assert_eq!(result.images[0].src, "https://example.com/harbour.jpg");
+    let text = &result.content_text;
+    let first = text.find("{{image:0}}").expect("marker for first image");
+    let second = text.find("{{image:1}}").expect("marker for second image");
+    assert!(text.find("harbour at dawn").unwrap() < first);
+    assert!(first < text.find("third week").unwrap());
+    assert!(text.find("ice shelf for").unwrap() < second);
+    assert!(second < text.find("early autumn").unwrap());
+    let content_html = result.content_html.expect("content html");
+    assert!(!content_html.contains("{{image:"));
+}
+
+#[test]
+fn inline_media_markers_off_by_default() {
+    let html = r#"
+        <html>
+          <body>
+            <article>
+              <p>The expedition set off from the harbour at dawn, loaded with supplies for a month at sea.</p>
+              <img src="https://example.com/harbour.jpg" alt="Harbour">
+              <p>By the third week the crew had sighted the northern ice shelf for the first time.</p>
+            </article>
+          </body>
+        </html>
+    "#;
+    let options = Options {
+        include_images: true,
+        ..Options::default()
+    };
+
+    let result = extract_with_options(html, &options).expect("extraction should succeed");
+
+    assert!(!result.content_text.contains("{{image:"));
+}
+
+#[test]
+fn inline_media_markers_leave_page_text_and_hash_alone() {
+    let html = r#"
+        <html>
+          <body>
+            <article>
+              <p>Templates reference pictures with placeholders such as {{image:7}} in their source files.</p>
+              <img src="https://example.com/template.jpg" alt="Template">
+              <p>The renderer swaps each placeholder for the matching upload when the page is built.</p>
+            </article>
+          </body>
+        </html>
+    "#;
+    let plain = Options {
+        include_images: true,
+        ..Options::default()
+    };
+    let marked = Options {
+        inline_media_markers: true,
+        ..plain.clone()
+    };
+
+    let without = extract_with_options(html, &plain).expect("extraction should succeed");
+    let with = extract_with_options(html, &marked).expect("extraction should succeed");
+
+    assert!(with.content_text.contains("{{image:0}}"), "{}", with.content_text);
+    let content_html = with.content_html.expect("content html");
+    assert!(content_html.contains("{{image:7}}"), "{content_html}");
+    assert!(!content_html.contains("{{image:0}}"), "{content_html}");
+    // The whole inserted span is removed, spaces included
+    assert_eq!(Some(content_html), without.content_html);
+    assert_eq!(with.content_hash, without.content_hash);
+}
+
+#[test]
+fn inline_media_markers_survive_max_paragraphs() {
+    let html = r#"
+        <html>
+          <body>
+            <article>
+              <p>The expedition set off from the harbour at dawn, loaded with supplies for a month at sea.</p>
+              <img src="https://example.com/harbour.jpg" alt="Harbour">
+              <p>By the third week the crew had sighted the northern ice shelf for the first time.</p>
+              <p>They returned home in early autumn with samples that are still being studied today.</p>
+            </article>
+          </body>
+        </html>
+    "#;
+    let options = Options {
+        include_images: true,
+        inline_media_markers: true,
+        max_paragraphs: Some(2),
+        ..Options::default()
+    };
+
+    let result = extract_with_options(html, &options).expect("extraction should succeed");
+
+    assert!(result.content_text.contains("{{image:0}}"), "{}", result.content_text);
+    assert!(!result.content_text.contains("early autumn"), "{}", result.content_text);
+    let content_html = result.content_html.expect("content html");
+    assert!(!content_html.contains('\u{E000}'), "{content_html}");
+}
+
+#[test]
+fn include_images_extracts_picture_without_img_fallback() {
+    let html = r#"
+        <html>
+          <body>
+            <article>
+              <p>The new bridge opened to traffic this morning after three years of construction work.</p>
+              <picture aria-label="The bridge at dusk">
+                <source media="(max-width: 599px)" srcset="https://example.com/bridge-mobile.webp" type="image/webp">
+                <source srcset="https://example.com/bridge-800.webp 800w, https://example.com/bridge-1600.webp 1600w" type="image/webp">
+              </picture>
+              <figure>
+                <picture><source srcset="https://example.com/deck.avif"></picture>
+                <figcaption>The upper deck</figcaption>
+              </figure>
+              <p>Commuters said the crossing cut their journey time into the city by almost half.</p>
+            </article>
+          </body>
+        </html>
+    "#;
+    let options = Options {
+        include_images: true,
+        ..Options::default()
+    };
+
+    let result = extract_with_options(html, &options).expect("extraction should succeed");
+
+    assert_eq!(result.images.len(), 2);
+    let deck = &result.images[0];
+    assert_eq!(deck.src, "https://example.com/deck.avif");
+    assert_eq!(deck.caption.as_deref(), Some("The upper deck"));
+    let bridge = &result.images[1];
+    assert_eq!(bridge.src, "https://example.com/bridge-1600.webp");
+    assert_eq!(bridge.filename, "bridge-1600.webp");
+    assert_eq!(bridge.alt.as_deref(), Some("The bridge at dusk"));
+    assert_eq!(bridge.sources.len(), 2);
+}
+
+/// Test that a hero figure just before the article supplies the full <picture>
+#[test]
+fn hero_figure_before_article_is_extracted() {
+    let html = r#"
+        <html>
+        <head>
+            <meta property="og:image" content="https://cdn.example.com/og/harbour-hero.jpg">
+        </head>
+        <body>
+            <figure class="hero">
+                <picture>
+                    <source media="(min-width: 1200px)" srcset="https://example.com/img/harbour-hero.jpg 1600w">
+                    <img src="https://example.com/img/harbour-hero-small.jpg" alt="The harbour at dawn">
+                </picture>
+            </figure>
+            <article>
+                <p>The harbour redevelopment was approved after a long consultation with residents and businesses.</p>
+                <img src="https://example.com/img/site-plan.png" alt="Site plan">
+                <p>Work on the new quay walls is expected to begin next spring and last two years.</p>
+            </article>
+        </body></html>
+    "#;
+
+    let options = Options {
+        include_images: true,
+        ..Options::default()
+    };
+
+    let result = extract_with_options(html, &options).expect("extraction failed");
+
+    assert_eq!(result.images.len(), 2);
+    let hero = &result.images[0];
+    assert!(hero.is_hero);
+    assert_eq!(hero.src, "https://example.com/img/harbour-hero.jpg");
+    assert_eq!(hero.alt, Some("The harbour at dawn".to_string()));
+    assert_eq!(hero.sources.len(), 1);
+    assert!(!result.images[1].is_hero);
+}
+
+/// Test that image URL filters drop unwanted extensions, hosts, and data URIs
+#[test]
+fn image_url_filters_drop_svg_tracking_hosts_and_data_uris() {
+    let html = r#"
+        <html><body>
+            <article>
+                <p>The harbour redevelopment was approved after a long consultation with residents.</p>
+                <img src="https://example.com/img/quay.jpg" alt="Quay">
+                <img src="https://example.com/img/logo.svg" alt="Logo">
+                <img src="https://px.tracker.example.net/pixel.gif?id=42" alt="">
+                <img src="data:image/png;base64,iVBORw0KGgo=" alt="Inline">
+                <figure>
+                    <img src="https://example.com/img/site-plan.PNG" alt="Site plan">
+                    <figcaption>The approved site plan</figcaption>
+                </figure>
+            </article>
+        </body></html>
+    "#;
+
+    let unfiltered = Options {
+        include_images: true,
+        ..Options::default()
+    };
+    let result = extract_with_options(html, &unfiltered).expect("extraction failed");
+    assert_eq!(result.images.len(), 5);
+
+    let options = Options {
+        include_images: true,
+        allowed_image_extensions: Some(vec!["jpg".to_string(), ".png".to_string(), "gif".to_string()]),
+        blocked_image_hosts: vec!["tracker.example.net".to_string()],
+        drop_data_uri_images: true,
+        ..Options::default()
+    };
+    let result = extract_with_options(html, &options).expect("extraction failed");
+
+    let srcs: Vec<&str> = result.images.iter().map(|img| img.src.as_str()).collect();
+    assert_eq!(
+        srcs,
+        vec!["https://example.com/img/site-plan.PNG", "https://example.com/img/quay.jpg"]
+    );
+}
+
+/// Test that the real image in <noscript> replaces a lazy-load placeholder
+#[test]
+fn noscript_image_replaces_lazy_placeholder() {
+    let html = r#"
+        <html><body>
+            <article>
+                <p>The harbour redevelopment was approved after a long consultation with residents.</p>
+                <figure>
+                    <img src="https://example.com/img/quay-blur-20px.jpg" class="lazyload"
+                         data-src="https://example.com/img/quay.jpg" alt="">
+                    <noscript>
+                        <img src="https://example.com/img/quay.jpg"
+                             srcset="https://example.com/img/quay-800.jpg 800w, https://example.com/img/quay-1600.jpg 1600w"
+                             alt="The new quay walls">
+                    </noscript>
+                    <figcaption>Work starts next spring</figcaption>
+                </figure>
+                <p>Work on the new quay walls is expected to begin next spring and last two years.</p>
+            </article>
+        </body></html>
+    "#;
+
+    let options = Options {
+        include_images: true,
+        ..Options::default()
+    };
+
+    let result = extract_with_options(html, &options).expect("extraction failed");
+
+    assert_eq!(result.images.len(), 1, "{:?}", result.images);
+    let image = &result.images[0];
+    assert_eq!(image.src, "https://example.com/img/quay.jpg");
+    assert_eq!(image.alt, Some("The new quay walls".to_string()));
+    assert_eq!(image.caption, Some("Work starts next spring".to_string()));
+}
+
+/// Hero alt text and caption land in metadata even without `include_images`
+#[test]
+fn hero_alt_and_caption_in_metadata_without_include_images() {
+    let html = r#"
+        <html>
+        <head>
+            <meta property="og:image" content="https://cdn.example.com/photos/harbour-1200.jpg">
+        </head>
+        <body>
+            <article>
+                <figure>
+                    <img src="https://example.com/photos/harbour-1200.jpg" alt="Fishing boats moored in the harbour">
+                    <figcaption>The harbour at dawn on Monday.</figcaption>
+                </figure>
+                <p>The harbour reopened on Monday after three weeks of repairs to the breakwater, and crews returned before sunrise.</p>
+                <p>Fishing crews said the delay had cost them most of the spring season's catch, though prices have since recovered.</p>
+            </article>
+        </body></html>
+    "#;
+
+    for include_images in [false, true] {
+        let options = Options {
+            include_images,
+            ..Options::default()
+        };
+        let result = extract_with_options(html, &options).expect("extraction failed");
+
+        assert_eq!(result.images.is_empty(), !include_images);
+        assert_eq!(result.metadata.image_alt.as_deref(), Some("Fishing boats moored in the harbour"));
+        assert_eq!(result.metadata.image_caption.as_deref(), Some("The harbour at dawn on Monday."));
+    }
+}
+
+/// `max_images` caps the list but never drops the hero
+#[test]
+fn max_images_keeps_hero_among_first_images() {
+    let gallery: String = (0..500)
+        .map(|i| format!(r#"<img src="https://example.com/gallery/photo-{i}.jpg" alt="Photo {i}">"#))
+        .collect();
+    let html = format!(
+        r#"
+        <html>
+        <head>
+            <meta property="og:image" content="https://example.com/gallery/photo-321.jpg">
+        </head>
+        <body>
+            <article>
+                <p>Our photographers spent a week documenting the festival, from the first rehearsals to the closing parade.</p>
+                {gallery}
+            </article>
+        </body></html>
+    "#
+    );
+
+    let options = Options {
+        include_images: true,
+        max_images: Some(10),
+        ..Options::default()
+    };
+    let result = extract_with_options(&html, &options).expect("extraction failed");
+
+    assert_eq!(result.images.len(), 10);
+    assert!(images_contain_src(&result.images, "https://example.com/gallery/photo-0.jpg"));
+    assert!(images_contain_src(&result.images, "https://example.com/gallery/photo-8.jpg"));
+    assert!(!images_contain_src(&result.images, "https://example.com/gallery/photo-9.jpg"));
+    assert!(result.images[9].is_hero);
+    assert_eq!(result.images[9].src, "https://example.com/gallery/photo-321.jpg");
+}
+
+#[test]
+fn count_content_images_counts_only_the_content_node() {
+    let html = r#"<html><body>
+        <header><img src="/logo.png" alt="Logo"></header>
+        <div class="entry-content">
+            <p>A gallery of the new harbour, photographed over the first week after it opened to the public.
+            Fishing boats, ferries, and a visiting tall ship all used the new berths, and crowds gathered
+            on the sea wall each evening to watch the lights come on along the pier.</p>
+            <figure><img src="/harbour-1.jpg"><figcaption>Dawn</figcaption></figure>
+            <figure><img src="/harbour-2.jpg"><figcaption>Noon</figcaption></figure>
+            <p><img src="/harbour-3.jpg"> The evening ferry leaves the new pier.</p>
+        </div>
+        <aside><img src="/ad.gif"><img src="/promo.gif"></aside>
+    </body></html>"#;
+
+    let doc = dom_query::Document::from(html);
+    assert_eq!(count_content_images(&doc), 3);
+
+    let empty = dom_query::Document::from("<html><body></body></html>");
+    assert_eq!(count_content_images(&empty), 0);
+}
@@ -340,3 +340,74 @@ fn language_filtering_doesnt_affect_metadata() {
     // Content should contain German text
     assert!(result.content_text.contains("Deutscher Inhalt"));
 }
+
+const BILINGUAL_NOTICE: &str = r#"
+    <body>
+        <main>
+            <div class="notice">
+                <section>
+                    <h2>Road closure notice</h2>
+                    <p>The bridge on King Street will be closed for repairs from Monday until the end of the month.</p>
+                    <p>Residents should use the detour signposted along Water Street during this period.</p>
+                    <p>Emergency vehicles will keep access to the bridge, and the pedestrian walkway stays open at all times.</p>
+                </section>
+                <section lang="fr">
+                    <h2>Avis de fermeture de route</h2>
+                    <p>Le pont de la rue King sera fermé pour réparations à partir de lundi jusqu'à la fin du mois.</p>
+                    <p>Les résidents doivent emprunter le détour indiqué le long de la rue Water pendant cette période.</p>
+                    <p>Les véhicules d'urgence conserveront l'accès au pont, et la passerelle piétonne reste ouverte en tout temps.</p>
+                </section>
+            </div>
+        </main>
+    </body>
+"#;
+
+/// Test that parallel translations are reduced to the document language
+#[test]
+fn deduplicate_translations_keeps_document_language() {
+    let html = format!(r#"<html lang="en-CA">{BILINGUAL_NOTICE}</html>"#);
+
+    let mixed = extract_with_options(&html, &Options::default()).expect("extraction failed");
+    assert!(mixed.content_text.contains("Avis de fermeture"));
+
+    let options = Options {
+        deduplicate_translations: true,
+        ..Options::default()
+    };
+    let result = extract_with_options(&html, &options).expect("extraction failed");
+
+    assert!(result.content_text.contains("Road closure notice"));
+    assert!(result.content_text.contains("detour signposted"));
+    assert!(!result.content_text.contains("Avis de fermeture"));
+    assert!(!result.content_text.contains("Le pont"));
+}
+
+/// Test that the primary language wins even when its version comes second
+#[test]
+fn deduplicate_translations_prefers_primary_language_over_order() {
+    let html = format!(r#"<html lang="fr">{}</html>"#, BILINGUAL_NOTICE.replace("<section>", r#"<section lang="en">"#));
+
+    let options = Options {
+        deduplicate_translations: true,
+        ..Options::default()
+    };
+    let result = extract_with_options(&html, &options).expect("extraction failed");
+
+    assert!(result.content_text.contains("Avis de fermeture"));
+    assert!(!result.content_text.contains("Road closure notice"));
+}
+
+/// Test that an untagged version inherits the nearest `lang`, not the outermost
+#[test]
+fn deduplicate_translations_inherits_nearest_language() {
+    let html = format!(r#"<html lang="fr">{}</html>"#, BILINGUAL_NOTICE.replace("<main>", r#"<main lang="en">"#));
+
+    let options = Options {
+        deduplicate_translations: true,
+        ..Options::default()
+    };
+    let result = extract_with_options(&html, &options).expect("extraction failed");
+
+    assert!(result.content_text.contains("Avis de fermeture"));
+    assert!(!result.content_text.contains("Road closure notice"));
+}
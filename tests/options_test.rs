@@ -122,3 +122,197 @@ fn options_implements_debug_and_clone() {
     assert_eq!(cloned.include_comments, options.include_comments);
     assert_eq!(cloned.include_tables, options.include_tables);
 }
+
+#[test]
+fn compute_quality_signals_is_opt_in() {
+    let html = "<html><body><article><p>The council approved the new budget on Tuesday. Spending on parks and libraries will rise next year. Several members argued for more housing funds.</p></article></body></html>";
+
+    let default_result = extract(html).expect("expected Ok(_)");
+    assert!(default_result.quality_signals.is_none());
+
+    let options = Options {
+        compute_quality_signals: true,
+        ..Options::default()
+    };
+    let result = extract_with_options(html, &options).expect("expected Ok(_)");
+    let signals = result.quality_signals.expect("signals should be computed");
+    assert!(signals.avg_sentence_length > 5.0);
+    assert!(signals.type_token_ratio > 0.8);
+    assert!(signals.keyword_stuffing_ratio < 0.1);
+}
+
+#[test]
+fn detect_paragraph_languages_tags_quoted_paragraphs() {
+    let html = "<html><body><article>\
+        <p>The minister said that the reform was necessary and that it would not be delayed for another year.</p>\
+        <p>Nous ne sommes pas prêts pour cette réforme, et les syndicats sont contre le projet.</p>\
+        <p>The unions have called for a strike, but the government says that talks are still open.</p>\
+        </article></body></html>";
+
+    let default_result = extract(html).expect("expected Ok(_)");
+    assert!(default_result.language_spans.is_empty());
+
+    let options = Options {
+        detect_paragraph_languages: true,
+        ..Options::default()
+    };
+    let result = extract_with_options(html, &options).expect("expected Ok(_)");
+    let languages: Vec<Option<&str>> = result
+        .language_spans
+        .iter()
+        .map(|span| span.language.as_deref())
+        .collect();
+    assert_eq!(languages, vec![Some("en"), Some("fr"), Some("en")]);
+    assert!(result.language_spans[1].text.starts_with("Nous ne sommes"));
+}
+
+#[test]
+fn max_paragraphs_keeps_opening_paragraphs() {
+    let paragraphs: String = (1..=10)
+        .map(|i| {
+            let heading = if i == 3 { "<h2>Later developments</h2>" } else { "" };
+            format!(
+                "{heading}<p>Paragraph {i} describes the harbour redevelopment project in some detail, \
+                 covering the budget, the timeline, and the concerns raised by local residents.</p>"
+            )
+        })
+        .collect();
+    let html = format!(
+        "<html><head><title>Harbour plans approved</title></head>\
+         <body><article><h1>Harbour plans approved</h1>{paragraphs}</article></body></html>"
+    );
+    let options = Options {
+        max_paragraphs: Some(2),
+        ..Options::default()
+    };
+
+    let result = match extract_with_options(&html, &options) {
+        Ok(result) => result,
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    };
+
+    assert!(result.content_text.contains("Paragraph 1 "));
+    assert!(result.content_text.trim_end().ends_with("concerns raised by local residents."));
+    assert!(result.content_text.contains("Paragraph 2 "));
+    assert!(!result.content_text.contains("Paragraph 3 "));
+    assert!(!result.content_text.contains("Later developments"));
+    let content_html = result.content_html.expect("content html");
+    assert_eq!(content_html.matches("<p>").count(), 2);
+    assert_eq!(result.metadata.title.as_deref(), Some("Harbour plans approved"));
+
+    let full = match extract(&html) {
+        Ok(result) => result,
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    };
+    assert!(full.content_text.contains("Paragraph 10 "));
+}
+
+#[test]
+fn prepend_title_adds_heading_once() {
+    let paragraphs: String = (1..=4)
+        .map(|i| {
+            format!(
+                "<p>Paragraph {i} describes the harbour redevelopment project in some detail, \
+                 covering the budget, the timeline, and the concerns raised by local residents.</p>"
+            )
+        })
+        .collect();
+    let options = Options {
+        prepend_title: true,
+        output_markdown: true,
+        ..Options::default()
+    };
+
+    let untitled = format!(
+        "<html><head><title>Harbour plans approved</title></head>\
+         <body><article>{paragraphs}</article></body></html>"
+    );
+    let result = match extract_with_options(&untitled, &options) {
+        Ok(result) => result,
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    };
+    assert!(result.content_text.starts_with("Harbour plans approved\n\nParagraph 1 "));
+    assert!(result.content_html.as_deref().unwrap_or_default().starts_with("<h1>Harbour plans approved</h1>"));
+    assert!(result.content_markdown.as_deref().unwrap_or_default().starts_with("# Harbour plans approved"));
+
+    let titled = format!(
+        "<html><head><title>Harbour plans approved | Example News</title></head>\
+         <body><article><h1>Harbour plans approved</h1>{paragraphs}</article></body></html>"
+    );
+    let result = match extract_with_options(&titled, &options) {
+        Ok(result) => result,
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    };
+    assert_eq!(result.content_text.matches("Harbour plans approved").count(), 1);
+    assert_eq!(result.content_html.as_deref().unwrap_or_default().matches("<h1>").count(), 1);
+    assert_eq!(result.content_markdown.as_deref().unwrap_or_default().matches("# Harbour").count(), 1);
+
+    let plain = match extract(&untitled) {
+        Ok(result) => result,
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    };
+    assert!(plain.content_text.starts_with("Paragraph 1 "));
+
+    // The heading is added after hashing, so it doesn't change deduplication
+    let prepended = match extract_with_options(&untitled, &options) {
+        Ok(result) => result,
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    };
+    assert_eq!(prepended.content_hash, plain.content_hash);
+}
+
+#[test]
+fn collect_timings_reports_each_stage() {
+    let html = "<html><head><title>Harbour plans approved</title></head><body><article>\
+        <p>The council approved the harbour redevelopment plan after a long consultation with residents.</p>\
+        <p>Work on the new breakwater is expected to start in the spring and take two years.</p>\
+        </article></body></html>";
+    let options = Options {
+        collect_timings: true,
+        output_markdown: true,
+        ..Options::default()
+    };
+
+    let result = match extract_with_options(html, &options) {
+        Ok(result) => result,
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    };
+    let timings = result.timings.expect("timings should be collected");
+    for stage in ["parse", "metadata", "clean", "main_extraction", "fallback", "markdown", "total"] {
+        assert!(timings.contains_key(stage), "missing {stage}: {timings:?}");
+    }
+    let stages: std::time::Duration = timings.iter().filter(|(k, _)| *k != "total").map(|(_, d)| *d).sum();
+    assert!(stages <= timings["total"]);
+
+    match extract(html) {
+        Ok(result) => assert!(result.timings.is_none()),
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+}
+
+#[test]
+fn navigation_keywords_follow_the_document_language() {
+    let para = "Die Stadtverwaltung hat den neuen Radweg nach einer langen öffentlichen Anhörung genehmigt, \
+        an der sich Hunderte Bürgerinnen und Bürger beteiligt haben.";
+    let page = |lang: &str| {
+        format!(
+            "<html lang=\"{lang}\"><head><title>Radweg genehmigt</title></head><body>\
+             <article><p>Startseite Über uns Kontakt Impressum Datenschutz</p>\
+             <p>{para}</p><p>{para}</p><p>{para}</p></article></body></html>"
+        )
+    };
+    let flagged = |html: &str, options: &Options| match extract_with_options(html, options) {
+        Ok(result) => result.warnings.iter().any(|w| w.contains("navigation block")),
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    };
+
+    // German keywords only apply to German documents
+    assert!(flagged(&page("de"), &Options::default()));
+    assert!(!flagged(&page("en"), &Options::default()));
+
+    let custom = Options {
+        navigation_keywords: Some(vec!["Startseite".into(), "Kontakt".into(), "Impressum".into()]),
+        ..Options::default()
+    };
+    assert!(flagged(&page("en"), &custom));
+}
@@ -1,4 +1,4 @@
-use rs_trafilatura::{extract, Error};
+use rs_trafilatura::{extract, extract_with_options, Error, ExtractionStatus, Options};
 use std::time::{Duration, Instant};
 
 #[test]
@@ -119,3 +119,123 @@ fn extract_handles_null_bytes_gracefully() {
     let result = extract(html);
     assert!(matches!(result, Ok(_) | Err(Error::NoContent)));
 }
+
+#[test]
+fn extract_returns_timeout_when_max_duration_is_exceeded() {
+    let html = "<html><body><article><p>Some article text that would normally be extracted.</p></article></body></html>";
+
+    let expired = Options {
+        max_duration: Some(Duration::ZERO),
+        ..Options::default()
+    };
+    match extract_with_options(html, &expired) {
+        Err(Error::Timeout) => {}
+        other => panic!("expected Err(Timeout), got {other:?}"),
+    }
+
+    let generous = Options {
+        max_duration: Some(Duration::from_secs(60)),
+        ..Options::default()
+    };
+    match extract_with_options(html, &generous) {
+        Ok(result) => assert!(result.content_text.contains("Some article text")),
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+}
+
+#[test]
+fn report_parse_errors_flags_malformed_structure() {
+    let article = "<p>The council approved the new cycle lanes after a long public consultation that \
+                   drew hundreds of responses from residents and local businesses.</p>";
+    let malformed = format!(
+        "<!DOCTYPE html><html><body><article><div><p>Opening <b>bold<div>{article}</span></article></body></html>"
+    );
+    let well_formed = format!("<html><body><article>{article}</article></body></html>");
+    let options = Options {
+        report_parse_errors: true,
+        ..Options::default()
+    };
+
+    let result = match extract_with_options(&malformed, &options) {
+        Ok(result) => result,
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    };
+    assert!(result.warnings.iter().any(|w| w.starts_with("Malformed HTML: parser repaired")));
+
+    // Missing DOCTYPE alone is not reported
+    let result = match extract_with_options(&well_formed, &options) {
+        Ok(result) => result,
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    };
+    assert!(!result.warnings.iter().any(|w| w.starts_with("Malformed HTML")));
+
+    // Off by default
+    let result = match extract(&malformed) {
+        Ok(result) => result,
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    };
+    assert!(!result.warnings.iter().any(|w| w.starts_with("Malformed HTML")));
+}
+
+#[test]
+fn extract_handles_bare_fragment_without_html_or_body() {
+    let result = match extract("<p>text</p><p>more</p>") {
+        Ok(result) => result,
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    };
+    assert!(result.content_text.contains("text"));
+    assert!(result.content_text.contains("more"));
+    let content_html = result.content_html.expect("fragment content should have HTML");
+    assert!(content_html.contains("<p>text</p><p>more</p>"), "{content_html}");
+}
+
+#[test]
+fn extract_builds_body_for_frameset_documents() {
+    let html = "<html><head><title>Harbour plans</title></head><frameset><frame src=\"nav.html\">\
+                <noframes><p>The council approved the new harbour budget on Tuesday after a long debate.</p>\
+                <p>Work on the quay walls will start next spring and take two years.</p></noframes>\
+                </frameset></html>";
+
+    let result = match extract(html) {
+        Ok(result) => result,
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    };
+    assert!(result.content_text.contains("approved the new harbour budget"));
+    assert!(result.content_text.contains("Work on the quay walls"));
+    assert!(!result.content_text.contains("<p>"), "{:?}", result.content_text);
+    assert!(result.warnings.iter().any(|w| w.starts_with("Document has no <body>")));
+    assert_eq!(result.metadata.title.as_deref(), Some("Harbour plans"));
+}
+
+#[test]
+fn extraction_status_reflects_validation_outcome() {
+    let paragraph = "<p>The council approved the harbour redevelopment on Tuesday after a long public \
+                     consultation that drew hundreds of responses from residents and businesses.</p>";
+    let article = format!("<html><body><article>{}</article></body></html>", paragraph.repeat(4));
+
+    let status_of = |html: &str, options: &Options| match extract_with_options(html, options) {
+        Ok(result) => result.status,
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    };
+
+    assert_eq!(status_of(&article, &Options::default()), ExtractionStatus::Ok);
+    assert_eq!(status_of("<html><body></body></html>", &Options::default()), ExtractionStatus::Failed);
+    assert_eq!(
+        status_of("<html><body><article><p>Too short.</p></article></body></html>", &Options::default()),
+        ExtractionStatus::Insufficient
+    );
+
+    let capped = Options {
+        max_extracted_len: 300,
+        ..Options::default()
+    };
+    assert_eq!(status_of(&article, &capped), ExtractionStatus::Truncated);
+
+    // App shell with the article only in JSON-LD
+    let body = "The council approved the harbour redevelopment on Tuesday after a long public consultation. ".repeat(6);
+    let json_ld_only = format!(
+        r#"<html><head><script type="application/ld+json">{{"@type": "NewsArticle", "articleBody": "{body}"}}</script></head>
+        <body><div id="app"><p>Loading</p></div></body></html>"#
+    );
+    assert_eq!(status_of(&json_ld_only, &Options::default()), ExtractionStatus::FallbackUsed);
+}
@@ -1,4 +1,7 @@
-use rs_trafilatura::extract;
+use std::sync::Arc;
+
+use dom_query::Selection;
+use rs_trafilatura::{extract, extract_with_options, ContentScorer, Options, ScoringContext};
 
 #[test]
 fn extract_penalizes_link_dense_regions() {
@@ -73,6 +76,29 @@ fn extract_selects_deeply_nested_content_node() {
     }
 }
 
+#[test]
+fn extract_ignores_depth_of_single_child_wrapper_divs() {
+    // Twelve plain wrapper divs used to add 120 points of depth bonus,
+    // enough for the slightly weaker archive note to beat the story
+    let note: String = (0..5)
+        .map(|i| format!("<p>Archive note {i}: this short note from the archive team explains how older posts were migrated into the new publishing system last year, and what changed.</p>"))
+        .collect();
+    let wrapped = (0..12).fold(format!("<div>{note}</div>"), |inner, _| format!("<div>\n{inner}\n</div>"));
+    let story: String = (0..5)
+        .map(|i| format!("<p>Harbour update {i}: the harbour authority confirmed on Tuesday that dredging of the main shipping channel will begin next spring, weather permitting, officials said.</p>"))
+        .collect();
+
+    let html = format!("<html><body><div>{story}</div>{wrapped}</body></html>");
+
+    match extract(&html) {
+        Ok(result) => {
+            assert!(result.content_text.contains("Harbour update 4"));
+            assert!(!result.content_text.contains("Archive note"));
+        }
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+}
+
 #[test]
 fn extract_rewards_sentence_rich_regions() {
     // Current behavior: multiple substantial content regions are both extracted
@@ -201,3 +227,80 @@ fn extract_degrades_gracefully_when_selected_node_filters_to_empty() {
         Err(err) => panic!("expected Ok(_), got Err({err:?})"),
     }
 }
+
+/// Prefers the candidate with `id="preferred"`, ignoring everything else.
+#[derive(Debug)]
+struct PreferIdScorer;
+
+impl ContentScorer for PreferIdScorer {
+    fn score(&self, node: &Selection, _ctx: &ScoringContext) -> i64 {
+        if node.attr("id").as_deref() == Some("preferred") {
+            100_000
+        } else {
+            0
+        }
+    }
+}
+
+#[test]
+fn extract_uses_custom_content_scorer() {
+    let long = "This long region sentence has plenty of meaningful words to score well. ".repeat(12);
+    let short = "This shorter region sentence is the one a custom scorer asks for. ".repeat(16);
+
+    let html = format!(
+        r#"
+        <html><body>
+            <div id="other"><p>DEFAULT_PICK_MARKER</p><p>{long}</p><p>{long}</p></div>
+            <div id="preferred"><p>CUSTOM_PICK_MARKER</p><p>{short}</p></div>
+        </body></html>
+    "#
+    );
+
+    let default = extract(&html).expect("default extraction");
+    assert!(default.content_text.contains("DEFAULT_PICK_MARKER"));
+
+    let options = Options {
+        content_scorer: Some(Arc::new(PreferIdScorer)),
+        ..Options::default()
+    };
+    let custom = extract_with_options(&html, &options).expect("custom extraction");
+    assert!(custom.content_text.contains("CUSTOM_PICK_MARKER"), "{:?}", custom.content_text);
+    assert!(!custom.content_text.contains("DEFAULT_PICK_MARKER"), "{:?}", custom.content_text);
+}
+
+#[test]
+fn extract_bare_section_next_to_large_footer() {
+    let story: String = (0..6)
+        .map(|i| format!("<p>Paragraph {i} of the essay explains how the village rebuilt its mill after the flood, step by step, with the help of volunteers.</p>"))
+        .collect();
+    let footer: String = (0..14)
+        .map(|i| format!("<p>Footer column {i}: our office hours, mailing address, press contacts, careers information and legal notices.</p>"))
+        .collect();
+    let html = format!(r#"<html><body><section>{story}</section><div class="site-footer">{footer}</div></body></html>"#);
+
+    let result = extract(&html).unwrap();
+    assert!(result.content_text.contains("Paragraph 0 of the essay"));
+    assert!(result.content_text.contains("Paragraph 5 of the essay"));
+    assert!(!result.content_text.contains("Footer column"));
+}
+
+#[test]
+fn link_density_is_reported_for_link_heavy_extraction() {
+    let links: String = (1..=30)
+        .map(|i| format!(r#"<li><a href="/section/{i}">Section number {i} overview page</a></li>"#))
+        .collect();
+    let html = format!("<html><body><div class=\"directory\"><ul>{links}</ul><p>Browse all sections.</p></div></body></html>");
+    let options = Options {
+        compute_link_density: true,
+        ..Options::default()
+    };
+    let result = extract_with_options(&html, &options).expect("extraction succeeds");
+    assert!(result.link_density > 0.8, "link density {}", result.link_density);
+
+    let article = "<html><body><article>\
+        <p>The council approved the new harbour plan on Tuesday after months of public consultation and debate.</p>\
+        <p>Work on the sea wall starts in spring, <a href=\"/budget\">according to the budget</a>, and should take two years.</p>\
+        </article></body></html>";
+    let result = extract_with_options(article, &options).expect("extraction succeeds");
+    assert!(result.link_density < 0.2, "link density {}", result.link_density);
+}
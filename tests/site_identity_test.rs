@@ -176,3 +176,38 @@ fn url_is_none_when_no_sources_present() {
         Err(err) => panic!("expected Ok(_), got Err({err:?})"),
     }
 }
+
+#[test]
+fn print_version_link_resolved_against_canonical_url() {
+    let html = r#"
+        <html>
+          <head>
+            <link rel="canonical" href="https://news.example.com/2024/05/harbour-plan" />
+          </head>
+          <body>
+            <article>
+              <p>Body</p>
+              <div class="tools">
+                <a class="article-print-link" href="/print/2024/05/harbour-plan">Print this article</a>
+                <a class="download-pdf" href="harbour-plan.pdf">Download PDF</a>
+              </div>
+            </article>
+          </body>
+        </html>
+    "#;
+
+    let result = extract(html);
+    match result {
+        Ok(result) => {
+            assert_eq!(
+                result.metadata.print_url.as_deref(),
+                Some("https://news.example.com/print/2024/05/harbour-plan")
+            );
+            assert_eq!(
+                result.metadata.pdf_url.as_deref(),
+                Some("https://news.example.com/2024/05/harbour-plan.pdf")
+            );
+        }
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+}
@@ -1,4 +1,4 @@
-use rs_trafilatura::extract;
+use rs_trafilatura::{extract, extract_with_options, Options};
 
 const PADDING: &str = "<p>Additional paragraph to ensure sufficient content for the extraction algorithm to consider this a real article.</p><p>Second padding paragraph with more text to satisfy the minimum content scoring threshold for table extraction.</p>";
 
@@ -140,3 +140,186 @@ fn extract_treats_single_row_table_as_layout() {
         Err(err) => panic!("expected Ok(_), got Err({err:?})"),
     }
 }
+
+#[test]
+fn tables_structured_collects_header_and_row_cells() {
+    let html = format!(r#"
+        <article>
+            <p>Intro text for the article with enough content.</p>
+            {PADDING}
+            <table>
+                <thead><tr><th>City</th><th>Population</th></tr></thead>
+                <tbody>
+                    <tr><td>Dublin</td><td>1.2 million</td></tr>
+                    <tr><td>Cork</td><td>222,000</td></tr>
+                </tbody>
+            </table>
+        </article>
+    "#);
+    let options = Options {
+        tables_structured: true,
+        ..Options::default()
+    };
+
+    let result = extract_with_options(&html, &options);
+    match result {
+        Ok(result) => {
+            assert_eq!(result.tables.len(), 1);
+            let table = &result.tables[0];
+            assert_eq!(table.headers, vec!["City", "Population"]);
+            assert_eq!(
+                table.rows,
+                vec![
+                    vec!["Dublin".to_string(), "1.2 million".to_string()],
+                    vec!["Cork".to_string(), "222,000".to_string()],
+                ]
+            );
+            assert!(result.content_text.contains("Dublin | 1.2 million"));
+        }
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+
+    let result = extract(&html);
+    match result {
+        Ok(result) => assert!(result.tables.is_empty()),
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+}
+
+#[test]
+fn extract_definition_pairs_joins_multiple_descriptions() {
+    let html = format!(r#"
+        <article>
+            <p>Intro text for the product with enough content.</p>
+            {PADDING}
+            <h2>Specifications</h2>
+            <dl>
+                <dt>Weight</dt><dd>1.2 kg</dd>
+                <dt>Connectivity</dt><dd>Wi-Fi 6</dd><dd>Bluetooth 5.3</dd><dd>USB-C</dd>
+                <dt>Warranty</dt><dd>2 years</dd>
+            </dl>
+        </article>
+    "#);
+    let options = Options {
+        extract_definition_pairs: true,
+        ..Options::default()
+    };
+
+    let result = extract_with_options(&html, &options);
+    match result {
+        Ok(result) => assert_eq!(
+            result.definitions,
+            vec![
+                ("Weight".to_string(), "1.2 kg".to_string()),
+                ("Connectivity".to_string(), "Wi-Fi 6; Bluetooth 5.3; USB-C".to_string()),
+                ("Warranty".to_string(), "2 years".to_string()),
+            ]
+        ),
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+
+    let result = extract(&html);
+    match result {
+        Ok(result) => assert!(result.definitions.is_empty()),
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+}
+
+#[test]
+fn extract_clamps_huge_colspan_and_rowspan() {
+    let rows: String = (0..200)
+        .map(|i| format!(r#"<tr><td colspan="1000000" rowspan="1000000">Row {i}</td><td>Value {i}</td></tr>"#))
+        .collect();
+    let html = format!(r#"
+        <article>
+            <p>Intro text for the article with enough content.</p>
+            {PADDING}
+            <table>
+                <tr><th>Name</th><th>Value</th></tr>
+                {rows}
+            </table>
+        </article>
+    "#);
+
+    let start = std::time::Instant::now();
+    let result = extract(&html);
+    let elapsed = start.elapsed();
+
+    match result {
+        Ok(result) => assert!(result.content_text.contains("Row 0"), "{:?}", result.content_text),
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+    assert!(elapsed < std::time::Duration::from_secs(10), "adversarial spans took {elapsed:?}");
+}
+
+#[test]
+fn extract_puts_table_caption_above_rows() {
+    let html = format!(
+        r#"<article>
+            <p>Intro text for the article with enough content.</p>
+            {PADDING}
+            <table>
+                <tr><th>Year</th><th>Population</th></tr>
+                <tr><td>2010</td><td>4.5m</td></tr>
+                <tr><td>2020</td><td>5.0m</td></tr>
+                <caption>Population by census year</caption>
+            </table>
+        </article>"#
+    );
+    let options = Options {
+        output_markdown: true,
+        ..Options::default()
+    };
+
+    let result = match extract_with_options(&html, &options) {
+        Ok(result) => result,
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    };
+
+    assert!(
+        result.content_text.contains("Population by census year\nYear | Population\n2010 | 4.5m"),
+        "{:?}",
+        result.content_text
+    );
+    let content_html = result.content_html.as_deref().expect("content html");
+    assert!(content_html.contains("<table><caption>Population by census year</caption>"), "{content_html}");
+    let markdown = result.content_markdown.as_deref().expect("markdown");
+    let title = markdown.find("**Population by census year**").expect("caption title in markdown");
+    assert!(title < markdown.find("| Year").expect("table in markdown"), "{markdown}");
+}
+
+#[test]
+fn extract_data_values_pairs_display_text_with_value() {
+    let html = format!(r#"
+        <article>
+            <p>The appeal raised <data value="1000000">1M</data> dollars in its first week.</p>
+            {PADDING}
+            <p>Goal reached: <progress value="72" max="100" aria-label="Campaign goal">72%</progress></p>
+        </article>
+    "#);
+    let options = Options {
+        extract_data_values: true,
+        ..Options::default()
+    };
+
+    let result = extract_with_options(&html, &options);
+    match result {
+        Ok(result) => {
+            assert_eq!(
+                result.data_values,
+                vec![
+                    ("1M".to_string(), "1000000".to_string()),
+                    ("Campaign goal".to_string(), "72".to_string()),
+                ]
+            );
+            assert!(result.content_text.contains("raised 1M dollars"));
+        }
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+
+    let result = extract(&html);
+    match result {
+        Ok(result) => assert!(result.data_values.is_empty()),
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+}
@@ -1,4 +1,4 @@
-use rs_trafilatura::extract;
+use rs_trafilatura::{extract, extract_with_options, LineEnding, Options};
 
 const PADDING: &str = "<p>Additional paragraph with enough content to ensure the extraction algorithm finds sufficient text density to extract this article content properly.</p><p>Second padding paragraph adding more sentences to satisfy the minimum scoring threshold required for content extraction to succeed.</p>";
 
@@ -40,6 +40,25 @@ fn extract_strips_inline_elements() {
     }
 }
 
+#[test]
+fn extract_marks_emphasis_when_requested() {
+    let html = format!("<article><p>This is <strong>bold</strong>, <b> heavy </b>and <em>italic</em> with <i>style</i>.</p>{PADDING}</article>");
+    let options = Options {
+        text_emphasis_markers: true,
+        ..Options::default()
+    };
+
+    let result = extract_with_options(&html, &options);
+    match result {
+        Ok(result) => assert!(
+            result.content_text.contains("This is *bold*, *heavy* and _italic_ with _style_."),
+            "{:?}",
+            result.content_text
+        ),
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+}
+
 #[test]
 fn extract_preserves_link_text_without_url() {
     let html = r#"<article><p>Visit <a href="https://example.com">our site</a>.</p></article>"#;
@@ -134,3 +153,341 @@ fn extract_handles_list_items() {
         Err(err) => panic!("expected Ok(_), got Err({err:?})"),
     }
 }
+
+#[test]
+fn extract_uses_crlf_line_endings_when_requested() {
+    let html = format!("<article><h2>Heading</h2><p>First paragraph.</p><ul><li>One</li><li>Two</li></ul>{PADDING}</article>");
+    let options = Options {
+        line_ending: LineEnding::Crlf,
+        output_markdown: true,
+        ..Options::default()
+    };
+
+    let result = extract_with_options(&html, &options);
+    match result {
+        Ok(result) => {
+            assert!(result.content_text.contains("\r\n"), "{:?}", result.content_text);
+            assert!(!result.content_text.replace("\r\n", "").contains('\n'),
+                "bare \\n in content_text: {:?}", result.content_text);
+            let markdown = result.content_markdown.expect("markdown should be generated");
+            assert!(!markdown.replace("\r\n", "").contains('\n'), "bare \\n in markdown: {markdown:?}");
+        }
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+}
+
+#[test]
+fn extract_drops_symbol_only_paragraphs_unless_kept() {
+    let html = format!(
+        "<article><p>Opening paragraph.</p><p>✦ ✦ ✦</p><p>═══════</p><p>🌊🌊🌊</p><p>第三章</p>{PADDING}</article>"
+    );
+
+    let result = extract(&html);
+    match result {
+        Ok(result) => {
+            assert!(!result.content_text.contains('✦'), "{:?}", result.content_text);
+            assert!(!result.content_text.contains('═'));
+            assert!(!result.content_text.contains('🌊'));
+            assert!(result.content_text.contains("第三章"));
+        }
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+
+    let options = Options {
+        keep_symbol_paragraphs: true,
+        ..Options::default()
+    };
+    let result = extract_with_options(&html, &options);
+    match result {
+        Ok(result) => assert!(result.content_text.contains("✦ ✦ ✦"), "{:?}", result.content_text),
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+}
+
+#[test]
+fn extract_treats_double_br_as_paragraph_breaks() {
+    let parts: Vec<String> = (1..=5)
+        .map(|i| {
+            format!(
+                "Part {i} of this old page tells how the mill was built by hand in the valley, \
+                 and how the villagers kept it running for a century.<br>Postscript {i}."
+            )
+        })
+        .collect();
+    let html = format!(
+        "<html><body><div id=\"content\">{}</div></body></html>",
+        parts.join("<br><br>\n")
+    );
+    let options = Options {
+        output_markdown: true,
+        ..Options::default()
+    };
+
+    let result = match extract_with_options(&html, &options) {
+        Ok(result) => result,
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    };
+
+    let paragraphs: Vec<&str> = result.content_text.split("\n\n").collect();
+    assert_eq!(paragraphs.len(), 5);
+    assert!(paragraphs[0].starts_with("Part 1 "));
+    assert!(paragraphs[0].ends_with("Postscript 1."));
+    assert!(paragraphs[4].starts_with("Part 5 "));
+    let content_html = result.content_html.expect("content html");
+    assert_eq!(content_html.matches("<p>").count(), 5);
+    let markdown = result.content_markdown.expect("markdown");
+    assert!(markdown.contains("Part 1 of this old page"));
+    assert!(markdown.contains("Part 5 of this old page"));
+}
+
+#[test]
+fn extract_keeps_inline_spacing_from_source() {
+    let cases = [
+        ("<p><b>A</b><b>B</b></p>", "AB"),
+        ("<p><b>A</b> <b>B</b></p>", "A B"),
+        ("<p>don<em>'</em>t stop</p>", "don't stop"),
+    ];
+
+    for (fragment, expected) in cases {
+        let html = format!("<html><body><article>{fragment}{PADDING}</article></body></html>");
+        match extract(&html) {
+            Ok(result) => {
+                let first_line = result.content_text.lines().next().unwrap_or_default();
+                assert_eq!(first_line, expected, "for {fragment}");
+            }
+            Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+        }
+    }
+}
+
+#[test]
+fn extract_separates_text_after_block_elements() {
+    let html = format!("<html><body><article><div><p>First block</p>trailing text</div>{PADDING}</article></body></html>");
+    match extract(&html) {
+        Ok(result) => {
+            let lines: Vec<&str> = result.content_text.lines().take(2).collect();
+            assert_eq!(lines, vec!["First block", "trailing text"]);
+        }
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+}
+
+#[test]
+fn extract_renders_details_summary_as_quoted_sections_in_markdown() {
+    let html = format!(
+        "<article><h2>Frequently asked questions</h2>\
+         <details><summary>How do I reset my password?</summary>\
+         <p>Use the reset link on the sign-in page.</p>\
+         <details><summary>What if the link expired?</summary>\
+         <p>Request a new link; each one lasts an hour.</p></details>\
+         </details>{PADDING}</article>"
+    );
+    let options = Options {
+        output_markdown: true,
+        ..Options::default()
+    };
+
+    let result = extract_with_options(&html, &options);
+    match result {
+        Ok(result) => {
+            let markdown = result.content_markdown.expect("markdown should be generated");
+            assert!(markdown.contains("> **How do I reset my password?**\n"), "{markdown:?}");
+            assert!(markdown.contains("> Use the reset link on the sign-in page."), "{markdown:?}");
+            assert!(markdown.contains("> > **What if the link expired?**\n"), "{markdown:?}");
+            assert!(markdown.contains("> > Request a new link; each one lasts an hour."), "{markdown:?}");
+
+            let content_html = result.content_html.expect("html should be generated");
+            assert!(content_html.contains("<details><summary>How do I reset my password?</summary>"));
+            assert!(result.content_text.contains("How do I reset my password?\n"));
+        }
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+}
+
+#[test]
+fn extract_removes_configured_boilerplate_phrases() {
+    let html = format!(
+        "<article><p>The council approved the harbour plan on Tuesday.</p>\
+         <p>THIS ARTICLE WAS ORIGINALLY PUBLISHED ON Coastal Press.</p>\
+         <p>Sign up for our newsletter to get the morning briefing</p>\
+         <p>Residents said they would sign up for the consultation.</p>{PADDING}</article>"
+    );
+    let options = Options {
+        boilerplate_phrases: vec![
+            "This article was originally published*".to_string(),
+            "Sign up for our newsletter to get the morning briefing.".to_string(),
+            "Residents said".to_string(),
+        ],
+        ..Options::default()
+    };
+
+    let result = match extract_with_options(&html, &options) {
+        Ok(result) => result,
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    };
+    let text = &result.content_text;
+    assert!(text.starts_with("The council approved the harbour plan on Tuesday.\n"), "{text:?}");
+    assert!(!text.to_lowercase().contains("originally published"), "{text:?}");
+    assert!(!text.contains("newsletter"), "{text:?}");
+    // Not a prefix phrase, so only an exact paragraph match would remove it
+    assert!(text.contains("Residents said they would sign up for the consultation."), "{text:?}");
+    assert!(!text.contains("\n\n\n"));
+}
+
+#[test]
+fn extract_splits_content_into_hr_delimited_sections() {
+    let page = r#"<html><body><article>
+        <h1>Three Winters</h1>
+        <p>The first winter on the island was the hardest, with the ferry cancelled for weeks at a time.</p>
+        <p>We learned to keep the woodshed full and the pantry fuller.</p>
+        <hr>
+        <p>By the second winter the neighbours had become friends, and the long evenings passed in shared kitchens.</p>
+        <hr>
+        <p>The third winter we barely noticed, which is perhaps the truest sign that the island had become home.</p>
+    </article></body></html>"#;
+
+    let options = Options {
+        split_on_hr: true,
+        ..Options::default()
+    };
+    let result = extract_with_options(page, &options).unwrap();
+
+    assert_eq!(result.sections.len(), 3, "sections: {:?}", result.sections);
+    assert!(result.sections[0].starts_with("The first winter"));
+    assert!(result.sections[0].ends_with("the pantry fuller."));
+    assert!(result.sections[1].starts_with("By the second winter"));
+    assert!(result.sections[2].starts_with("The third winter"));
+
+    // The combined text is unchanged apart from the missing markers
+    assert!(result.content_text.contains("the pantry fuller.\n\nBy the second winter"));
+    assert!(!result.content_text.contains("{{"));
+    assert_eq!(result.content_html.unwrap().matches("<hr>").count(), 2);
+
+    let default = extract_with_options(page, &Options::default()).unwrap();
+    assert!(default.sections.is_empty());
+}
+
+#[test]
+fn extract_keeps_svg_titles_as_text_when_requested() {
+    let html = format!(
+        r#"<html><body><article>
+        <p>Installer packages are published for every release of the toolkit.
+        <a href="/releases/latest"><svg viewBox="0 0 16 16"><title>Download</title><path d="M8 1v10"/></svg> the Linux build</a>
+        and verify its checksum before running it.</p>
+        <p>An untitled icon <svg viewBox="0 0 16 16"><path d="M1 1h14"/></svg> adds nothing.</p>
+        {PADDING}
+        </article></body></html>"#
+    );
+
+    let options = Options {
+        svg_titles_as_text: true,
+        ..Options::default()
+    };
+    let result = extract_with_options(&html, &options).unwrap();
+    assert!(result.content_text.contains("Download the Linux build"), "{}", result.content_text);
+    assert!(result.content_text.contains("An untitled icon adds nothing."), "{}", result.content_text);
+
+    let default = extract(&html).unwrap();
+    assert!(!default.content_text.contains("Download"));
+}
+
+#[test]
+fn extract_includes_long_infographic_alt_text_when_requested() {
+    let alt = "Chart of regional rainfall in 2023. The north received 1,240 mm, up 18 percent on the ten-year average. \
+               The south received 610 mm, the lowest total since records began in 1911. \
+               Coastal areas saw the sharpest swings between months.";
+    let html = format!(
+        r#"<html><body><article>
+        <p>Rainfall across the country diverged sharply last year, according to the national weather service.</p>
+        <figure><img src="/rainfall.png" alt="{alt}"><figcaption>Rainfall by region</figcaption></figure>
+        <p><img src="/divider.png" alt="Decorative divider."> Forecasters expect the pattern to continue.</p>
+        {PADDING}
+        </article></body></html>"#
+    );
+
+    let options = Options {
+        include_long_alt: true,
+        ..Options::default()
+    };
+    let result = extract_with_options(&html, &options).unwrap();
+    assert!(result.content_text.contains("The south received 610 mm"), "{}", result.content_text);
+    assert!(!result.content_text.contains("Decorative divider"), "{}", result.content_text);
+
+    let default = extract(&html).unwrap();
+    assert!(!default.content_text.contains("The south received 610 mm"));
+}
+
+#[test]
+fn extract_drops_ruby_annotations_from_base_text() {
+    let html = format!(
+        r#"<html><body><article>
+        <p>今日は<ruby>漢字<rp>(</rp><rt>かんじ</rt><rp>)</rp></ruby>を<ruby>勉強<rt>べんきょう</rt></ruby>しました。</p>
+        {PADDING}
+        </article></body></html>"#
+    );
+
+    let result = extract(&html).unwrap();
+    assert!(result.content_text.contains("今日は漢字を勉強しました。"), "{}", result.content_text);
+    assert!(!result.content_text.contains("かんじ"));
+
+    let options = Options {
+        keep_ruby_annotations: true,
+        ..Options::default()
+    };
+    let annotated = extract_with_options(&html, &options).unwrap();
+    assert!(
+        annotated.content_text.contains("今日は漢字(かんじ)を勉強(べんきょう)しました。"),
+        "{}",
+        annotated.content_text
+    );
+}
+
+#[test]
+fn extract_strips_trailing_taxonomy_lines_when_requested() {
+    let html = format!(
+        r#"<html><body><article>
+        <p>Categories: the three kinds of compost bins are covered below in turn.</p>
+        {PADDING}
+        <p>Tags: <a href="/tag/gardening" rel="tag">gardening</a>, <a href="/tag/compost" rel="tag">compost</a></p>
+        <p>Filed under: Outdoors</p>
+        </article></body></html>"#
+    );
+
+    let options = Options {
+        strip_taxonomy_lines: true,
+        output_markdown: true,
+        ..Options::default()
+    };
+    let result = extract_with_options(&html, &options).unwrap();
+    assert!(result.content_text.ends_with("content extraction to succeed."), "{}", result.content_text);
+    assert!(result.content_text.starts_with("Categories: the three kinds"));
+    let content_html = result.content_html.unwrap();
+    assert!(!content_html.contains("Filed under") && !content_html.contains("Tags:"), "{content_html}");
+    let markdown = result.content_markdown.unwrap();
+    assert!(!markdown.contains("Filed under") && !markdown.contains("Tags:"), "{markdown}");
+    assert!(result.metadata.tags.contains(&"gardening".to_string()), "{:?}", result.metadata.tags);
+
+    let default = extract(&html).unwrap();
+    assert!(default.content_text.contains("Filed under: Outdoors"));
+}
+
+#[test]
+fn extract_tracks_source_offsets_of_paragraphs() {
+    let html = format!(
+        "<html><head><title>Harbour</title></head><body><nav><a href=\"/\">Home</a></nav><article>\n\
+         <p>The harbour <em>reopened</em> on Monday &amp; crews returned.</p>\n{PADDING}\n</article></body></html>"
+    );
+
+    let options = Options {
+        track_offsets: true,
+        ..Options::default()
+    };
+    let result = extract_with_options(&html, &options).unwrap();
+
+    assert_eq!(result.source_spans.len(), result.content_text.lines().filter(|l| !l.trim().is_empty()).count());
+    let (start, end) = result.source_spans[0];
+    assert_eq!(&html[start..end], "The harbour <em>reopened</em> on Monday &amp; crews returned.");
+    assert!(result.source_spans.windows(2).all(|pair| pair[0].1 <= pair[1].0));
+
+    assert!(extract(&html).unwrap().source_spans.is_empty());
+}
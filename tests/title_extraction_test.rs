@@ -1,4 +1,4 @@
-use rs_trafilatura::extract;
+use rs_trafilatura::{extract, extract_with_options, Options};
 
 #[test]
 fn title_from_title_tag() {
@@ -195,3 +195,51 @@ fn title_skips_empty_h1_and_returns_none() {
         Err(err) => panic!("expected Ok(_), got Err({err:?})"),
     }
 }
+
+#[test]
+fn title_falls_back_to_content_region_label() {
+    let html = r#"
+        <html>
+          <body>
+            <main aria-label="Quarterly Report">
+              <p>Revenue grew in every region this quarter, led by strong subscription sales in Europe.</p>
+            </main>
+          </body>
+        </html>
+    "#;
+
+    match extract(html) {
+        Ok(result) => assert_eq!(result.metadata.title.as_deref(), Some("Quarterly Report")),
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+}
+
+#[test]
+fn title_site_suffix_is_stripped_with_default_and_custom_separators() {
+    let page = |title: &str| {
+        format!("<html><head><title>{title}</title></head><body><article><p>Body</p></article></body></html>")
+    };
+
+    let result = extract(&page("Budget talks stall over transit funding \u{00bb} The Daily Ledger"));
+    match result {
+        Ok(result) => assert_eq!(result.metadata.title.as_deref(), Some("Budget talks stall over transit funding")),
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+
+    let html = page("Budget talks stall over transit funding ~ The Daily Ledger");
+    let options = Options {
+        title_separators: vec![" ~ ".to_string()],
+        ..Options::default()
+    };
+    match extract_with_options(&html, &options) {
+        Ok(result) => assert_eq!(result.metadata.title.as_deref(), Some("Budget talks stall over transit funding")),
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+    match extract(&html) {
+        Ok(result) => assert_eq!(
+            result.metadata.title.as_deref(),
+            Some("Budget talks stall over transit funding ~ The Daily Ledger")
+        ),
+        Err(err) => panic!("expected Ok(_), got Err({err:?})"),
+    }
+}